@@ -1,6 +1,6 @@
 use cexplorer_api_rs::{
     init_api, inspect_address, get_address_detail,
-    get_address_utxo, get_address_list, AddressListParams,
+    get_address_utxo, get_address_list, Address, AddressListOrder, AddressListRequest,
 };
 
 #[tokio::main]
@@ -16,6 +16,7 @@ async fn main() {
 
     // Test address (Cardano mainnet)
     let test_address = "addr1q8elqhkuvtyelgcedpup58r893awhg3l87a4rz5d5acatuj9y84nruafrmta2rewd5l46g8zxy4l49ly8kye79ddr3ksqal35g";
+    let address: Address = test_address.parse().expect("valid address");
 
     // Test 1: Inspect address
     println!("\n--- Test inspect_address ---");
@@ -32,7 +33,7 @@ async fn main() {
 
     // Test 2: Get address detail
     println!("\n--- Test get_address_detail ---");
-    match get_address_detail(test_address).await {
+    match get_address_detail(&address).await {
         Ok(response) => {
             println!("✓ Address details retrieved");
             println!("  Count: {}", response.data.count);
@@ -47,7 +48,7 @@ async fn main() {
 
     // Test 3: Get address UTXOs
     println!("\n--- Test get_address_utxo ---");
-    match get_address_utxo(test_address).await {
+    match get_address_utxo(&address).await {
         Ok(response) => {
             println!("✓ UTXOs retrieved");
             println!("  Count: {}", response.data.count);
@@ -61,14 +62,11 @@ async fn main() {
 
     // Test 4: Get address list
     println!("\n--- Test get_address_list ---");
-    let params = AddressListParams {
-        payment_cred: None,
-        view: Some(test_address.to_string()),
-        order: Some("balance".to_string()),
-        watchlist_only: None,
-    };
+    let request = AddressListRequest::new()
+        .view(address)
+        .order(AddressListOrder::Balance);
 
-    match get_address_list(params).await {
+    match get_address_list(request).await {
         Ok(response) => {
             println!("✓ Address list retrieved");
             println!("  Count: {}", response.data.count);
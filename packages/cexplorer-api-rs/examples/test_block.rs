@@ -1,4 +1,4 @@
-use cexplorer_api_rs::{init_api, get_block_detail, get_block_list, BlockListParams};
+use cexplorer_api_rs::{init_api, get_block_detail, get_block_list, BlockHash, BlockListRequest};
 
 #[tokio::main]
 async fn main() {
@@ -12,17 +12,9 @@ async fn main() {
     }
 
     println!("\n--- Test get_block_list ---");
-    let params = BlockListParams {
-        limit: Some(5),
-        offset: Some(0),
-        pool_id: None,
-        epoch_no: None,
-        hash: None,
-        slot_no: None,
-        block_no: None,
-    };
+    let request = BlockListRequest::new().limit(5).offset(0);
 
-    match get_block_list(params).await {
+    match get_block_list(request).await {
         Ok(response) => {
           println!("✓ Got blocks: {}", response.data.count);
           if !response.data.data.is_empty() {
@@ -33,9 +25,11 @@ async fn main() {
     }
 
     println!("\n--- Test get_block_detail ---");
-    let test_hash = "a951eee85659818a54a34a66e9e53e6c658a28a5156df1b6702c44128dc1d15b";
+    let test_hash: BlockHash = "a951eee85659818a54a34a66e9e53e6c658a28a5156df1b6702c44128dc1d15b"
+        .parse()
+        .expect("valid block hash");
 
-    match get_block_detail(test_hash).await {
+    match get_block_detail(&test_hash).await {
       Ok(response) => {
           println!("✓ Block: #{}", response.data.block_no);
           println!("  Epoch: {}", response.data.epoch_no);
@@ -0,0 +1,43 @@
+use cexplorer_api_rs::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    let client = match CexplorerClient::from_credentials("mainnet-stage", "your-api-key-here") {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("✗ Initialization error: {}", e);
+            return;
+        }
+    };
+
+    let policy = CachePolicy::default().ttl("/epoch/stats", Duration::from_secs(300));
+    let cache = Arc::new(ResponseCache::new(MemoryCache::new(500), policy));
+    let client = client.with_cache(cache.clone());
+
+    println!("--- Test cached get_misc_const ---");
+    for i in 0..3 {
+        match client.get_misc_const().await {
+            Ok(_) => println!("✓ Call {} succeeded", i),
+            Err(e) => eprintln!("✗ Error: {}", e),
+        }
+    }
+
+    println!("\n--- Test cached get_epoch_detail_param ---");
+    for _ in 0..2 {
+        match client.get_epoch_detail_param(500).await {
+            Ok(response) => println!("✓ Epoch {:?} params", response.data.epoch_no),
+            Err(e) => eprintln!("✗ Error: {}", e),
+        }
+    }
+
+    let stats = cache.stats();
+    println!(
+        "\n  Hits: {}, misses: {}, hit rate: {:.0}%, tokens saved: {}",
+        stats.hits,
+        stats.misses,
+        stats.hit_rate() * 100.0,
+        stats.tokens_saved
+    );
+}
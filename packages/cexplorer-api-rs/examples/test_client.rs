@@ -0,0 +1,35 @@
+use cexplorer_api_rs::CexplorerClient;
+
+#[tokio::main]
+async fn main() {
+    // One client per network, both usable at the same time
+    let mainnet = match CexplorerClient::from_credentials("mainnet-stage", "your-api-key-here") {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("✗ Initialization error: {}", e);
+            return;
+        }
+    };
+    let preprod = match CexplorerClient::from_credentials("preprod-stage", "your-api-key-here") {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("✗ Initialization error: {}", e);
+            return;
+        }
+    };
+
+    println!("\n--- Test get_epoch_list on two networks ---");
+    let (mainnet_epochs, preprod_epochs) = tokio::join!(
+        mainnet.get_epoch_list(),
+        preprod.get_epoch_list(),
+    );
+
+    match mainnet_epochs {
+        Ok(response) => println!("✓ mainnet epochs: {:?}", response.data.count),
+        Err(e) => eprintln!("✗ mainnet error: {}", e),
+    }
+    match preprod_epochs {
+        Ok(response) => println!("✓ preprod epochs: {:?}", response.data.count),
+        Err(e) => eprintln!("✗ preprod error: {}", e),
+    }
+}
@@ -46,7 +46,7 @@ async fn main() {
     // Test 3: Get epoch stats
     println!("\n--- Test get_epoch_detail_stats ---");
     match get_epoch_detail_stats(epoch_no).await {
-        Ok(response) => {
+        Ok(_) => {
             println!("✓ Epoch {:?} stats retrieved", epoch_no);
         }
         Err(e) => eprintln!("✗ Error: {}", e),
@@ -0,0 +1,58 @@
+use cexplorer_api_rs::prelude::*;
+use futures::{StreamExt, TryStreamExt};
+
+#[tokio::main]
+async fn main() {
+    let client = match CexplorerClient::from_credentials("mainnet-stage", "your-api-key-here") {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("✗ Initialization error: {}", e);
+            return;
+        }
+    };
+
+    println!("--- Test paginated get_pools_list ---");
+    let pools = client
+        .paginate(|client, page| async move {
+            client
+                .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+                .await
+        })
+        .page_size(50)
+        .max_items(200)
+        .concurrency(2)
+        .into_stream()
+        .try_collect::<Vec<PoolData>>()
+        .await;
+
+    match pools {
+        Ok(pools) => println!("✓ Collected {} pools", pools.len()),
+        Err(e) => eprintln!("✗ Error: {}", e),
+    }
+
+    println!("\n--- Test stable-cursor get_drep_list ---");
+    let mut dreps = Box::pin(
+        client
+            .paginate(|client, page| async move {
+                client
+                    .get_drep_list(DrepListRequest::new().limit(page.limit).offset(page.offset))
+                    .await
+            })
+            .page_size(20)
+            .max_items(60)
+            .stable_cursor(|drep: &DrepListData| format!("{:?}", drep.hash))
+            .into_stream(),
+    );
+
+    let mut seen = 0;
+    while let Some(drep) = dreps.next().await {
+        match drep {
+            Ok(_) => seen += 1,
+            Err(e) => {
+                eprintln!("✗ Error after {} DReps: {}", seen, e);
+                return;
+            }
+        }
+    }
+    println!("✓ Streamed {} DReps", seen);
+}
@@ -1,23 +1,11 @@
-use cexplorer_api_rs::{init_api, get_pools_list, get_pool_detail};
+use cexplorer_api_rs::{init_api, get_pools_list, get_pool_detail, PoolDetailRequest, PoolId, PoolListRequest};
 
 #[tokio::main]
 async fn main() {
-    init_api("mainnet-stage", "your-api-key-here");
+    init_api("mainnet-stage", "your-api-key-here").expect("failed to initialize API");
 
     println!("--- Test get_pools_list ---");
-    match get_pools_list(
-        Some(10),
-        Some(20),
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-        None,
-    )
-    .await
-    {
+    match get_pools_list(PoolListRequest::new().limit(10).offset(20)).await {
         Ok(response) => {
             println!("Success! Count: {}", response.data.count);
             println!("First pool: {:?}", response.data.data.first());
@@ -28,12 +16,12 @@ async fn main() {
     }
 
     println!("\n--- Test get_pool_detail ---");
-    match get_pool_detail(
-        Some("pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy"),
-        None,
-    )
-    .await
-    {
+    let pool_id: PoolId = "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy"
+        .parse()
+        .expect("valid pool id");
+    let request = PoolDetailRequest::new().pool_id(pool_id);
+
+    match get_pool_detail(request).await {
         Ok(response) => {
             println!("Success! Pool: {}", response.data.pool_id);
             println!("Delegators: {:?}", response.data.delegators);
@@ -0,0 +1,37 @@
+use cexplorer_api_rs::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    let client = match CexplorerClient::from_credentials("mainnet-stage", "your-api-key-here") {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("✗ Initialization error: {}", e);
+            return;
+        }
+    };
+
+    println!("--- Test with_plan_limits ---");
+    let client = match client.with_plan_limits(ApiPlan::Starter).await {
+        Ok(client) => {
+            println!("✓ Limits: {:?}", client.rate_limiter().map(|limiter| limiter.limit()));
+            client
+        }
+        Err(e) => {
+            eprintln!("✗ Error: {}", e);
+            client.with_rate_limit(RateLimit::per_minute(60.0).with_tokens_per_day(1_000.0))
+        }
+    };
+
+    println!("\n--- Test throttled get_epoch_list ---");
+    let requests = (0..5).map(|_| client.get_epoch_list());
+    for (i, result) in futures::future::join_all(requests).await.into_iter().enumerate() {
+        match result {
+            Ok(response) => println!("✓ Request {}: {} tokens", i, response.tokens),
+            Err(e) => eprintln!("✗ Request {}: {}", i, e),
+        }
+    }
+
+    if let Some(limiter) = client.rate_limiter() {
+        println!("  Usage: {:?}", limiter.usage());
+    }
+}
@@ -0,0 +1,405 @@
+//! Exact integer types for ADA amounts and native token quantities.
+//!
+//! The API sends amounts as JSON numbers, and sometimes as strings when they
+//! come from large sums. Both types accept either form and refuse values with
+//! a fractional part instead of rounding them.
+//!
+//! serde_json hands integers beyond `u64` over as `f64`, already rounded, so
+//! those are rejected rather than decoded to the wrong value. Quantities past
+//! that range are exact when the API sends them as strings, or when
+//! serde_json's `arbitrary_precision` feature is enabled.
+//!
+//! ```
+//! use cexplorer_api_rs::{Lovelace, TokenQuantity};
+//!
+//! let fee: Lovelace = serde_json::from_str("174257")?;
+//! let out: Lovelace = serde_json::from_str("\"10234891002\"")?;
+//! assert_eq!((fee + out).to_string(), "10235.065259 ADA");
+//!
+//! let supply: TokenQuantity = serde_json::from_str("\"18446744073709551616000\"")?;
+//! assert_eq!(supply.to_decimal_string(6), "18446744073709551.616000");
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Key serde_json uses for numbers when `arbitrary_precision` is enabled
+const ARBITRARY_PRECISION_KEY: &str = "$serde_json::private::Number";
+
+/// Largest magnitude up to which every integer has an exact `f64`, 2^53
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+/// An amount of lovelace, the smallest ADA unit (1 ADA = 1,000,000 lovelace).
+///
+/// Signed, since deposits and balance changes can be negative. `Display`
+/// formats the amount in ADA; use [`Lovelace::lovelace`] for the raw value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Lovelace(i64);
+
+impl Lovelace {
+    pub const ZERO: Lovelace = Lovelace(0);
+    pub const PER_ADA: i64 = 1_000_000;
+
+    pub const fn new(lovelace: i64) -> Self {
+        Lovelace(lovelace)
+    }
+
+    /// Whole ADA, saturating at the bounds of `i64` lovelace
+    pub const fn from_ada(ada: i64) -> Self {
+        Lovelace(ada.saturating_mul(Self::PER_ADA))
+    }
+
+    pub const fn lovelace(self) -> i64 {
+        self.0
+    }
+
+    /// Amount in ADA as a float, for display and charts only
+    pub fn to_ada(self) -> f64 {
+        self.0 as f64 / Self::PER_ADA as f64
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn checked_add(self, rhs: Lovelace) -> Option<Lovelace> {
+        self.0.checked_add(rhs.0).map(Lovelace)
+    }
+
+    pub fn checked_sub(self, rhs: Lovelace) -> Option<Lovelace> {
+        self.0.checked_sub(rhs.0).map(Lovelace)
+    }
+
+    pub fn saturating_add(self, rhs: Lovelace) -> Lovelace {
+        Lovelace(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(self, rhs: Lovelace) -> Lovelace {
+        Lovelace(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl fmt::Display for Lovelace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ADA", format_decimal(self.0 as i128, 6))
+    }
+}
+
+impl From<i64> for Lovelace {
+    fn from(lovelace: i64) -> Self {
+        Lovelace(lovelace)
+    }
+}
+
+impl From<u32> for Lovelace {
+    fn from(lovelace: u32) -> Self {
+        Lovelace(lovelace as i64)
+    }
+}
+
+impl TryFrom<u64> for Lovelace {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(lovelace: u64) -> Result<Self, Self::Error> {
+        i64::try_from(lovelace).map(Lovelace)
+    }
+}
+
+impl From<Lovelace> for i64 {
+    fn from(amount: Lovelace) -> Self {
+        amount.0
+    }
+}
+
+impl Add for Lovelace {
+    type Output = Lovelace;
+
+    fn add(self, rhs: Lovelace) -> Lovelace {
+        Lovelace(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Lovelace {
+    type Output = Lovelace;
+
+    fn sub(self, rhs: Lovelace) -> Lovelace {
+        Lovelace(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Lovelace {
+    fn add_assign(&mut self, rhs: Lovelace) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Lovelace {
+    fn sub_assign(&mut self, rhs: Lovelace) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Lovelace {
+    type Output = Lovelace;
+
+    fn neg(self) -> Lovelace {
+        Lovelace(-self.0)
+    }
+}
+
+impl Mul<i64> for Lovelace {
+    type Output = Lovelace;
+
+    fn mul(self, rhs: i64) -> Lovelace {
+        Lovelace(self.0 * rhs)
+    }
+}
+
+impl Sum for Lovelace {
+    fn sum<I: Iterator<Item = Lovelace>>(iter: I) -> Self {
+        iter.fold(Lovelace::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Lovelace> for Lovelace {
+    fn sum<I: Iterator<Item = &'a Lovelace>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Serialize for Lovelace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lovelace {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = deserializer.deserialize_any(IntegerVisitor("lovelace amount"))?;
+        i64::try_from(value)
+            .map(Lovelace)
+            .map_err(|_| de::Error::custom(format!("lovelace amount {} is out of range", value)))
+    }
+}
+
+/// A native token quantity, in the token's base units.
+///
+/// Held as an `i128` so minted supplies past `u64::MAX` and negative (burn)
+/// quantities fit. Use [`TokenQuantity::to_decimal_string`] with the
+/// registry's `decimals` to show the human readable amount.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TokenQuantity(i128);
+
+impl TokenQuantity {
+    pub const ZERO: TokenQuantity = TokenQuantity(0);
+
+    pub const fn new(quantity: i128) -> Self {
+        TokenQuantity(quantity)
+    }
+
+    pub const fn get(self) -> i128 {
+        self.0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// The quantity with `decimals` digits moved behind the decimal point
+    pub fn to_decimal_string(self, decimals: u32) -> String {
+        format_decimal(self.0, decimals)
+    }
+
+    pub fn checked_add(self, rhs: TokenQuantity) -> Option<TokenQuantity> {
+        self.0.checked_add(rhs.0).map(TokenQuantity)
+    }
+
+    pub fn checked_sub(self, rhs: TokenQuantity) -> Option<TokenQuantity> {
+        self.0.checked_sub(rhs.0).map(TokenQuantity)
+    }
+}
+
+impl fmt::Display for TokenQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for TokenQuantity {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(TokenQuantity)
+    }
+}
+
+impl From<i64> for TokenQuantity {
+    fn from(quantity: i64) -> Self {
+        TokenQuantity(quantity as i128)
+    }
+}
+
+impl From<u64> for TokenQuantity {
+    fn from(quantity: u64) -> Self {
+        TokenQuantity(quantity as i128)
+    }
+}
+
+impl From<i128> for TokenQuantity {
+    fn from(quantity: i128) -> Self {
+        TokenQuantity(quantity)
+    }
+}
+
+impl From<TokenQuantity> for i128 {
+    fn from(quantity: TokenQuantity) -> Self {
+        quantity.0
+    }
+}
+
+impl Add for TokenQuantity {
+    type Output = TokenQuantity;
+
+    fn add(self, rhs: TokenQuantity) -> TokenQuantity {
+        TokenQuantity(self.0 + rhs.0)
+    }
+}
+
+impl Sub for TokenQuantity {
+    type Output = TokenQuantity;
+
+    fn sub(self, rhs: TokenQuantity) -> TokenQuantity {
+        TokenQuantity(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for TokenQuantity {
+    fn add_assign(&mut self, rhs: TokenQuantity) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for TokenQuantity {
+    fn sub_assign(&mut self, rhs: TokenQuantity) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for TokenQuantity {
+    type Output = TokenQuantity;
+
+    fn neg(self) -> TokenQuantity {
+        TokenQuantity(-self.0)
+    }
+}
+
+impl Sum for TokenQuantity {
+    fn sum<I: Iterator<Item = TokenQuantity>>(iter: I) -> Self {
+        iter.fold(TokenQuantity::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a TokenQuantity> for TokenQuantity {
+    fn sum<I: Iterator<Item = &'a TokenQuantity>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Serialize for TokenQuantity {
+    /// Serializes as a number while it fits in 64 bits, as a string beyond
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Ok(value) = i64::try_from(self.0) {
+            serializer.serialize_i64(value)
+        } else if let Ok(value) = u64::try_from(self.0) {
+            serializer.serialize_u64(value)
+        } else {
+            serializer.collect_str(&self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenQuantity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(IntegerVisitor("token quantity"))
+            .map(TokenQuantity)
+    }
+}
+
+/// Accepts an integer sent as a JSON number or string
+struct IntegerVisitor(&'static str);
+
+impl<'de> Visitor<'de> for IntegerVisitor {
+    type Value = i128;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer {} as a number or string", self.0)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<i128, E> {
+        Ok(v as i128)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<i128, E> {
+        Ok(v as i128)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<i128, E> {
+        Ok(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<i128, E> {
+        i128::try_from(v).map_err(|_| E::custom(format!("{} {} is out of range", self.0, v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<i128, E> {
+        // serde_json only gets here for integers too large for u64, which
+        // have already been rounded, or numbers with a fraction
+        if !v.is_finite() || v.fract() != 0.0 {
+            Err(E::custom(format!("{} {} is not a whole number", self.0, v)))
+        } else if v.abs() > MAX_EXACT_F64 {
+            Err(E::custom(format!(
+                "{} {} is too large to be exact as a JSON number, send it as a string",
+                self.0, v
+            )))
+        } else {
+            Ok(v as i128)
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<i128, E> {
+        v.trim()
+            .parse()
+            .map_err(|_| E::custom(format!("{} `{}` is not an integer", self.0, v)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<i128, A::Error> {
+        // serde_json with `arbitrary_precision` passes numbers as a one entry map
+        match map.next_key::<String>()? {
+            Some(key) if key == ARBITRARY_PRECISION_KEY => {
+                let number: String = map.next_value()?;
+                self.visit_str(&number)
+            }
+            _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+        }
+    }
+}
+
+/// Format `value` with `decimals` digits after the decimal point
+fn format_decimal(value: i128, decimals: u32) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let digits = value.unsigned_abs().to_string();
+    if decimals == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    format!("{}{}.{}", sign, whole, fraction)
+}
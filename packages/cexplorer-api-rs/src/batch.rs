@@ -0,0 +1,58 @@
+use crate::client::CexplorerClient;
+use crate::endpoints::Endpoint;
+use crate::error::CexplorerError;
+use futures::stream::{self, StreamExt};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Requests a batch keeps in flight unless the client sets its own limit
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// Outcome of each distinct input of a batch, in the order the inputs were first seen
+pub type BatchResults<K, R> = Vec<(K, Result<R, CexplorerError>)>;
+
+impl CexplorerClient {
+    /// Call an endpoint once per distinct key, keeping at most
+    /// [`batch_concurrency`](Self::batch_concurrency) requests in flight.
+    ///
+    /// Each request still goes through the client's rate limiter, retries and
+    /// cache, and fails on its own: an error is returned next to its key
+    /// without stopping the rest of the batch.
+    ///
+    /// ```no_run
+    /// use cexplorer_api_rs::prelude::*;
+    ///
+    /// # async fn run(hashes: Vec<TxHash>) -> Result<(), CexplorerError> {
+    /// let client = CexplorerClient::from_credentials("mainnet", "api-key")?;
+    /// for (hash, result) in client.call_batch(hashes, |hash| TxDetailRequest::new(hash.clone())).await {
+    ///     match result {
+    ///         Ok(tx) => println!("{}: {:?}", hash, tx.data.fee),
+    ///         Err(e) => eprintln!("{}: {}", hash, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_batch<K, E, F>(
+        &self,
+        keys: impl IntoIterator<Item = K>,
+        request: F,
+    ) -> BatchResults<K, E::Response>
+    where
+        K: Clone + Eq + Hash,
+        E: Endpoint,
+        F: Fn(&K) -> E,
+    {
+        let mut seen = HashSet::new();
+        let keys: Vec<K> = keys.into_iter().filter(|key| seen.insert(key.clone())).collect();
+
+        stream::iter(keys)
+            .map(|key| {
+                let request = request(&key);
+                async move { (key, self.call(request).await) }
+            })
+            .buffered(self.batch_concurrency())
+            .collect()
+            .await
+    }
+}
@@ -0,0 +1,381 @@
+use crate::fields;
+use cexplorer_api_rs::endpoints::*;
+use cexplorer_api_rs::{CexplorerClient, CexplorerError, Endpoint, Method};
+use clap::{Arg, ArgMatches, Command};
+use futures::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Subcommands for the endpoints of one module
+pub struct Group {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub commands: &'static [Spec],
+}
+
+impl Group {
+    pub fn command(&self) -> Command {
+        Command::new(self.name)
+            .about(self.about)
+            .subcommand_required(true)
+            .arg_required_else_help(true)
+            .subcommands(self.commands.iter().map(Spec::command))
+    }
+}
+
+type Run = fn(CexplorerClient, String) -> LocalBoxFuture<'static, Result<Value, CexplorerError>>;
+
+/// A subcommand calling one [`Endpoint`]
+pub struct Spec {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub path: &'static str,
+    pub method: Method,
+    /// Required parameters, taken as positional arguments in this order
+    pub args: &'static [&'static str],
+    fields: fn() -> &'static [&'static str],
+    check: fn(&str, &str) -> Result<(), String>,
+    run: Run,
+}
+
+impl Spec {
+    pub fn command(&self) -> Command {
+        let mut command = Command::new(self.name)
+            .about(self.about)
+            .after_help(format!("Endpoint: {} {}", self.method, self.path));
+
+        for arg in self.args {
+            debug_assert!((self.fields)().contains(arg), "`{}` is not a field of {}", arg, self.path);
+            command = command.arg(Arg::new(*arg).value_name(arg.to_uppercase()).required(true));
+        }
+        for option in self.options() {
+            command = command.arg(
+                Arg::new(option)
+                    .long(option.replace('_', "-"))
+                    .value_name("VALUE")
+                    .help_heading("Query parameters"),
+            );
+        }
+
+        command
+    }
+
+    /// Parameters passed as `--name value` options
+    pub fn options(&self) -> impl Iterator<Item = &'static str> + '_ {
+        (self.fields)()
+            .iter()
+            .copied()
+            .filter(|field| !self.args.contains(field))
+    }
+
+    /// Whether the endpoint pages with `limit` and `offset`, so `--all` can walk it
+    pub fn is_list(&self) -> bool {
+        let fields = (self.fields)();
+        fields.contains(&"limit") && fields.contains(&"offset")
+    }
+
+    /// Query parameters given on the command line, positional arguments first.
+    ///
+    /// Each value is checked against the request type on its own, so an
+    /// invalid one is reported with the argument or option it came from.
+    pub fn query(&self, matches: &ArgMatches) -> Result<Vec<(&'static str, String)>, CexplorerError> {
+        let mut query = Vec::new();
+        for name in self.args.iter().copied().chain(self.options()) {
+            let Some(value) = matches.get_one::<String>(name) else {
+                continue;
+            };
+            (self.check)(name, value).map_err(|e| {
+                let arg = if self.args.contains(&name) {
+                    name.to_uppercase()
+                } else {
+                    format!("--{}", name.replace('_', "-"))
+                };
+                CexplorerError::InvalidQuery(format!("{}: {}", arg, e))
+            })?;
+            query.push((name, value.clone()));
+        }
+        Ok(query)
+    }
+
+    pub async fn call(&self, client: CexplorerClient, query: &[(&str, String)]) -> Result<Value, CexplorerError> {
+        let query = serde_urlencoded::to_string(query).map_err(|e| CexplorerError::InvalidQuery(e.to_string()))?;
+        (self.run)(client, query).await
+    }
+}
+
+/// Decode the request from `query`, so invalid values are rejected before anything is sent
+fn run<E>(client: CexplorerClient, query: String) -> LocalBoxFuture<'static, Result<Value, CexplorerError>>
+where
+    E: Endpoint + DeserializeOwned + 'static,
+    E::Response: Serialize,
+{
+    Box::pin(async move {
+        // Requests without parameters are unit structs, which only deserialize from a unit
+        let request: E = if fields::of::<E>().is_empty() {
+            serde_json::from_value(Value::Null)?
+        } else {
+            serde_urlencoded::from_str(&query).map_err(|e| CexplorerError::InvalidQuery(e.to_string()))?
+        };
+        Ok(serde_json::to_value(client.call(request).await?)?)
+    })
+}
+
+macro_rules! spec {
+    ($name:literal => $request:ty, [$($arg:literal),*], $about:literal) => {
+        Spec {
+            name: $name,
+            about: $about,
+            path: <$request as Endpoint>::PATH,
+            method: <$request as Endpoint>::METHOD,
+            args: &[$($arg),*],
+            fields: fields::of::<$request>,
+            check: fields::check::<$request>,
+            run: run::<$request>,
+        }
+    };
+}
+
+pub fn find(group: &str, name: &str) -> Option<&'static Spec> {
+    GROUPS
+        .iter()
+        .find(|g| g.name == group)?
+        .commands
+        .iter()
+        .find(|spec| spec.name == name)
+}
+
+pub static GROUPS: &[Group] = &[
+    Group {
+        name: "account",
+        about: "Stake account rewards, withdrawals and votes",
+        commands: &[
+            spec!("rewards" => AccountRewardsRequest, ["view"], "Rewards earned by a stake address"),
+            spec!("has-delegation" => CheckDelegationRequest, [], "Whether a stake address is delegated"),
+            spec!("withdrawals" => WithdrawalsRequest, ["view"], "Reward withdrawals of a stake address"),
+            spec!("delegation-votes" => DelegationVoteRequest, [], "Vote delegations to DReps"),
+        ],
+    },
+    Group {
+        name: "address",
+        about: "Addresses and their UTxOs",
+        commands: &[
+            spec!("detail" => AddressDetailRequest, ["view"], "Balance and assets of an address"),
+            spec!("list" => AddressListRequest, [], "List addresses"),
+            spec!("utxo" => AddressUtxoRequest, ["view"], "Unspent outputs of an address"),
+            spec!("inspect" => InspectAddressRequest, ["view"], "Decode the parts of an address"),
+        ],
+    },
+    Group {
+        name: "analytics",
+        about: "Chain-wide statistics",
+        commands: &[
+            spec!("hardforks" => HardforksRequest, [], "Hard fork history"),
+            spec!("epoch" => EpochAnalyticsRequest, [], "Per-epoch transaction and block statistics"),
+            spec!("rate" => AnalyticsRateRequest, [], "Daily transaction and block statistics"),
+            spec!("pool-blocks" => AnalyticsPoolBlockRequest, ["epoch_no"], "Blocks minted per pool in an epoch"),
+            spec!("top-accounts" => TopStakingAccountsRequest, [], "Largest staking accounts"),
+            spec!("top-addresses" => TopAddressesRequest, [], "Largest addresses"),
+            spec!("wealth" => WealthCompositionRequest, [], "Distribution of ADA across holders"),
+            spec!("ada-pots" => AdaPotsRequest, [], "Reserves, treasury and rewards pots"),
+            spec!("groups" => GroupListRequest, [], "Known pool and account groups"),
+            spec!("group" => GroupDetailRequest, ["id"], "Detail of a group"),
+            spec!("avg-pool" => AveragePoolRequest, [], "Average delegators per pool"),
+            spec!("genesis-addresses" => GenesisAddrRequest, [], "Genesis addresses"),
+        ],
+    },
+    Group {
+        name: "article",
+        about: "Explorer articles and pages",
+        commands: &[
+            spec!("detail" => ArticleDetailRequest, ["lng", "type", "url"], "An article by language, type and URL"),
+            spec!("list" => ArticleListRequest, ["lng", "type"], "List articles"),
+        ],
+    },
+    Group {
+        name: "asset",
+        about: "Native assets and NFTs",
+        commands: &[
+            spec!("list" => AssetListRequest, [], "List assets"),
+            spec!("detail" => AssetDetailRequest, ["fingerprint"], "Detail of an asset"),
+            spec!("owners" => AssetOwnersRequest, ["assetname"], "Current holders of an asset"),
+            spec!("owner-history" => AssetOwnerHistoryRequest, ["assetname"], "Past holders of an NFT"),
+            spec!("metadata" => AssetMetadataRequest, ["assetname"], "Metadata of an asset"),
+            spec!("mint" => AssetMintRequest, ["assetname"], "Mint and burn transactions of an asset"),
+            spec!("stats" => AssetStatsRequest, [], "Statistics of an asset"),
+        ],
+    },
+    Group {
+        name: "block",
+        about: "Blocks",
+        commands: &[
+            spec!("list" => BlockListRequest, [], "List blocks, newest first"),
+            spec!("detail" => BlockDetailRequest, ["hash"], "Detail of a block"),
+        ],
+    },
+    Group {
+        name: "datum",
+        about: "Plutus datums",
+        commands: &[spec!("detail" => DatumDetailRequest, ["hash"], "A datum by hash")],
+    },
+    Group {
+        name: "delegation",
+        about: "Stake delegations",
+        commands: &[
+            spec!("list" => StakeDelegationsRequest, ["view"], "Delegation history of a stake address"),
+            spec!("to-retired" => DelegationsToRetiredRequest, [], "Stake still delegated to retired pools or DReps"),
+            spec!("state" => DelegationsStateRequest, ["view"], "Current delegation of a stake address"),
+        ],
+    },
+    Group {
+        name: "drep",
+        about: "Delegated representatives",
+        commands: &[
+            spec!("list" => DrepListRequest, [], "List DReps"),
+            spec!("detail" => DrepDetailRequest, ["view"], "Detail of a DRep"),
+            spec!("votes" => GovVoteRequest, ["drep_voter"], "Votes cast by a DRep"),
+            spec!("votes-by-role" => DrepVoteRequest, ["voter_role"], "Votes cast by one voter role"),
+            spec!("delegators" => DrepDelegatorRequest, ["view"], "Delegators of a DRep"),
+            spec!("delegator-stats" => DrepDelegatorStatsRequest, ["view"], "Delegator statistics of a DRep"),
+            spec!("stats" => DrepStatRequest, [], "DRep totals"),
+            spec!("analytics" => DrepAnalyticsRequest, [], "DRep activity over time"),
+            spec!("stake-retired" => StakeDrepRetiredRequest, [], "Stake delegated to retired DReps"),
+            spec!("avg" => AverageDrepRequest, [], "Average delegators per DRep"),
+            spec!("spo-same-time" => DrepSpoSameTimeRequest, [], "Voting power of DReps that also run a pool"),
+            spec!("stake-is-spo-drep" => StakeIsSpoDrepRequest, [], "Stake of accounts that are both SPO and DRep"),
+            spec!("not-spo-same-time" => DrepNotSpoSameTimeRequest, [], "Voting power of DReps that run no pool"),
+            spec!("deleg-epoch-changes" => DelegEpochChangesRequest, [], "DRep delegation changes per epoch"),
+        ],
+    },
+    Group {
+        name: "epoch",
+        about: "Epochs",
+        commands: &[
+            spec!("list" => EpochListRequest, [], "List epochs"),
+            spec!("params" => EpochParamRequest, ["no"], "Protocol parameters of an epoch"),
+            spec!("stats" => EpochStatsRequest, ["no"], "Statistics of an epoch"),
+        ],
+    },
+    Group {
+        name: "gov",
+        about: "Governance actions, votes and the committee",
+        commands: &[
+            spec!("proposals" => GovActionProposalListRequest, [], "List governance action proposals"),
+            spec!("proposal" => GovActionProposalDetailRequest, ["id"], "Detail of a governance action proposal"),
+            spec!("votes" => GovVoteRequest, [], "Votes cast on governance actions"),
+            spec!("votes-not" => GovVoteNotRequest, [], "Voters that have not voted"),
+            spec!("drep-votes" => DrepListVoteRequest, ["gov_action"], "How DReps voted on an action"),
+            spec!("committees" => CommitteeListRequest, [], "Constitutional committees"),
+            spec!("committee" => CommitteeDetailRequest, [], "Detail of the current or a given committee"),
+            spec!("committee-member" => CommitteeMemberRequest, ["ident"], "Detail of a committee member"),
+            spec!("constitutions" => ConstitutionListRequest, [], "Constitutions"),
+            spec!("thresholds" => ThresholdsRequest, [], "Voting thresholds"),
+        ],
+    },
+    Group {
+        name: "metadata",
+        about: "Transaction metadata",
+        commands: &[spec!("list" => MetadataTxListRequest, [], "Transactions carrying metadata")],
+    },
+    Group {
+        name: "misc",
+        about: "Search, prices and API status",
+        commands: &[
+            spec!("market" => MiscMarketRequest, [], "ADA market data"),
+            spec!("search" => MiscSearchRequest, [], "Search the explorer"),
+            spec!("validate" => MiscValidateRequest, ["ident"], "Check what an identifier refers to"),
+            spec!("api" => MiscApiRequest, [], "Plan and usage of the API key"),
+            spec!("basic" => MiscBasicRequest, [], "Chain tip and headline numbers"),
+            spec!("rate" => MiscRateRequest, [], "Exchange rates"),
+            spec!("const" => MiscConstRequest, [], "Network constants"),
+            spec!("polls" => PollListRequest, [], "Governance polls"),
+            spec!("health" => MiscHealthRequest, [], "API health"),
+            spec!("protocol-parameters" => MiscProtocolParametersRequest, [], "Current protocol parameters"),
+        ],
+    },
+    Group {
+        name: "policy",
+        about: "Minting policies",
+        commands: &[
+            spec!("detail" => PolicyDetailRequest, ["id"], "Detail of a policy"),
+            spec!("stats" => PolicyStatsRequest, ["id"], "Statistics of a policy"),
+            spec!("owners" => PolicyOwnerRequest, ["id"], "Holders of a policy's assets"),
+        ],
+    },
+    Group {
+        name: "pool",
+        about: "Stake pools",
+        commands: &[
+            spec!("list" => PoolListRequest, [], "List stake pools"),
+            spec!("detail" => PoolDetailRequest, [], "Detail of a pool, by --pool-id or --hash-raw"),
+            spec!("blocks" => PoolBlocksRequest, ["pool_id"], "Blocks minted by a pool"),
+            spec!("delegators" => PoolDelegatorsRequest, ["pool_id", "type"], "Delegators of a pool"),
+            spec!("rewards" => PoolRewardsRequest, [], "Rewards per epoch"),
+            spec!("birthdays" => PoolBirthdaysRequest, ["pool_id"], "Registration anniversaries of a pool"),
+            spec!("updates" => PoolUpdateRequest, ["pool_id"], "Certificate updates of a pool"),
+            spec!("awards" => PoolAwardsRequest, ["pool_id"], "Awards won by a pool"),
+            spec!("all-awards" => GlobalPoolAwardsRequest, [], "Awards won by any pool"),
+            spec!("delegator-stats" => PoolDelegatorStatsRequest, ["pool_id"], "Delegator statistics of a pool"),
+            spec!("about" => PoolAboutRequest, ["pool_id"], "Description of a pool"),
+            spec!("retire" => PoolRetireRequest, ["pool_id"], "Retirement of a pool"),
+            spec!("retired" => RetiredPoolsRequest, [], "Retired pools"),
+            spec!("top-margins" => TopMarginsRequest, ["type"], "Pools ranked by margin"),
+            spec!("top-multi-delegators" => TopMultiDelegatorsRequest, [], "Accounts delegating through many addresses"),
+            spec!("deleg-epoch-registered" => DelegEpochRegisteredRequest, [], "Pool delegations registered per epoch"),
+            spec!("stake-dreps-not-spo" => StakeDrepsNotSpoRequest, [], "Stake of DReps that run no pool"),
+        ],
+    },
+    Group {
+        name: "script",
+        about: "Plutus and native scripts",
+        commands: &[
+            spec!("list" => ScriptListRequest, [], "List scripts"),
+            spec!("detail" => ScriptDetailRequest, ["hash"], "Detail of a script"),
+            spec!("redeemers" => ScriptRedeemerRequest, ["hash"], "Redeemers that ran a script"),
+        ],
+    },
+    Group {
+        name: "stake",
+        about: "Stake addresses",
+        commands: &[spec!("detail" => StakeDetailRequest, ["view"], "Detail of a stake address")],
+    },
+    Group {
+        name: "token",
+        about: "DeFi tokens and DEX orders",
+        commands: &[
+            spec!("list" => DeFiTokenListRequest, [], "Tokens traded on DEXes"),
+            spec!("orders" => DeFiOrderRequest, [], "DEX orders"),
+            spec!("stats" => DeFiTokenStatRequest, [], "DEX totals"),
+        ],
+    },
+    Group {
+        name: "tool",
+        about: "Reporting tools",
+        commands: &[spec!("tx-sent" => TxSentRequest, ["id", "type", "campaign"], "Report a delegation or donation transaction")],
+    },
+    Group {
+        name: "treasury",
+        about: "Treasury",
+        commands: &[spec!("donations" => TreasuryDonationStatsRequest, [], "Treasury donation statistics")],
+    },
+    Group {
+        name: "tx",
+        about: "Transactions",
+        commands: &[
+            spec!("list" => TxListRequest, [], "List transactions, newest first"),
+            spec!("detail" => TxDetailRequest, ["hash"], "Detail of a transaction"),
+            spec!("drep-registrations" => DrepRegistrationsRequest, [], "DRep registration certificates"),
+            spec!("drep-deregistrations" => DrepDeregistrationsRequest, [], "DRep deregistration certificates"),
+            spec!("drep-updates" => DrepUpdatesRequest, [], "DRep update certificates"),
+            spec!("pool-registrations" => PoolRegistrationsRequest, [], "Pool registration certificates"),
+            spec!("pool-deregistrations" => PoolDeregistrationsRequest, [], "Pool retirement certificates"),
+            spec!("stake-registrations" => StakeRegistrationsRequest, [], "Stake key registrations"),
+            spec!("contracts" => ContractTransactionsRequest, [], "Transactions that ran a contract"),
+        ],
+    },
+    Group {
+        name: "wallet",
+        about: "Wallets",
+        commands: &[spec!("compare" => CompareWalletsRequest, [], "Comparison of Cardano wallets")],
+    },
+];
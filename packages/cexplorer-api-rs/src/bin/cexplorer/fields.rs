@@ -0,0 +1,132 @@
+use serde::de::{self, value, DeserializeOwned, DeserializeSeed, Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Query parameter names of a request type, as its `Deserialize` impl spells them.
+///
+/// Reading the names from serde keeps `rename`s such as `type` in step with
+/// the library without repeating every field here.
+pub fn of<T: DeserializeOwned>() -> &'static [&'static str] {
+    let mut fields: &'static [&'static str] = &[];
+    // Always fails: FieldNames records the struct's fields and stops there
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("fields recorded"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+/// Check `value` as the query parameter `name` of `T` on its own, so the
+/// error can name the parameter. Other parameters `T` requires are not needed.
+pub fn check<T: DeserializeOwned>(name: &str, value: &str) -> Result<(), String> {
+    let query = serde_urlencoded::to_string([(name, value)]).map_err(|e| e.to_string())?;
+    match serde_urlencoded::from_str::<Probe<T>>(&query) {
+        Ok(Probe(Some(error), _)) => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// Deserializes `T`, keeping the first error raised by a field value and
+/// ignoring the rest, such as fields that are missing
+struct Probe<T>(Option<String>, PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Probe<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut error = None;
+        let _ = T::deserialize(ValueErrors {
+            inner: deserializer,
+            error: &mut error,
+        });
+        Ok(Probe(error, PhantomData))
+    }
+}
+
+/// Wraps a deserializer and the maps it visits to record value errors
+struct ValueErrors<'a, T> {
+    inner: T,
+    error: &'a mut Option<String>,
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for ValueErrors<'_, D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.inner.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = ValueErrors {
+            inner: visitor,
+            error: self.error,
+        };
+        self.inner.deserialize_struct(name, fields, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for ValueErrors<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(ValueErrors {
+            inner: map,
+            error: self.error,
+        })
+    }
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for ValueErrors<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Self::Error> {
+        self.inner.next_value_seed(seed).inspect_err(|e| {
+            self.error.get_or_insert_with(|| e.to_string());
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
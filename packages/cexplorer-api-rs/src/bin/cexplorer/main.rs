@@ -0,0 +1,225 @@
+//! `cexplorer`: every API endpoint as a subcommand.
+//!
+//! ```text
+//! cexplorer block detail <hash>
+//! cexplorer pool list --order live_stake --sort desc --output table
+//! cexplorer drep votes <drep-id> --all --output ndjson
+//! ```
+//!
+//! The API key and network come from `--api-key`/`--network`, then
+//! `CEXPLORER_API_KEY`/`CEXPLORER_NETWORK`, then the selected profile of
+//! `~/.config/cexplorer/config.toml`.
+
+mod commands;
+mod fields;
+mod output;
+mod profile;
+
+use cexplorer_api_rs::{CexplorerClient, CexplorerError, ListPage};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use commands::Spec;
+use futures::TryStreamExt;
+use output::{Format, Output};
+use serde_json::Value;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Page size of `--all` unless `--limit` sets one
+const DEFAULT_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Api(#[from] CexplorerError),
+
+    #[error("{0}")]
+    Usage(String),
+
+    #[error("cannot read {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+
+    #[error("invalid profile file {}: {source}", path.display())]
+    Profile { path: PathBuf, source: toml::de::Error },
+
+    #[error("cannot write output: {0}")]
+    Output(#[from] io::Error),
+}
+
+fn cli() -> Command {
+    Command::new("cexplorer")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Query the Cexplorer API from the command line")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg(
+            Arg::new("api-key")
+                .long("api-key")
+                .env("CEXPLORER_API_KEY")
+                .hide_env_values(true)
+                .global(true)
+                .help("API key, overriding the profile"),
+        )
+        .arg(
+            Arg::new("network")
+                .long("network")
+                .short('n')
+                .env("CEXPLORER_NETWORK")
+                .global(true)
+                .help("mainnet, preprod or preview, overriding the profile [default: mainnet]"),
+        )
+        .arg(
+            Arg::new("base-url")
+                .long("base-url")
+                .env("CEXPLORER_BASE_URL")
+                .global(true)
+                .help("Send requests to this URL instead of the network's"),
+        )
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .short('p')
+                .env("CEXPLORER_PROFILE")
+                .global(true)
+                .help("Profile to read from the config file [default: default]"),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .env("CEXPLORER_CONFIG")
+                .value_parser(value_parser!(PathBuf))
+                .global(true)
+                .help("Profile file [default: ~/.config/cexplorer/config.toml]"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_parser(Format::NAMES)
+                .default_value("json")
+                .global(true)
+                .help("Output format"),
+        )
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .action(ArgAction::SetTrue)
+                .global(true)
+                .help("Fetch every page of a list; --limit sets the page size"),
+        )
+        .arg(
+            Arg::new("max-items")
+                .long("max-items")
+                .value_parser(value_parser!(u64))
+                .global(true)
+                .help("Stop --all after this many items"),
+        )
+        .subcommands(commands::GROUPS.iter().map(|group| group.command()))
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let matches = cli().get_matches();
+    match run(&matches).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(matches: &ArgMatches) -> Result<(), CliError> {
+    let (group, matches) = matches.subcommand().expect("a group is required");
+    let (name, matches) = matches.subcommand().expect("a command is required");
+    let spec = commands::find(group, name).expect("clap only accepts known commands");
+
+    let format = matches
+        .get_one::<String>("output")
+        .and_then(|name| Format::from_name(name))
+        .unwrap_or(Format::Json);
+    let query = spec.query(matches)?;
+    let client = profile::client(matches)?;
+
+    let output = if matches.get_flag("all") {
+        Output::Items(fetch_all(&client, spec, query, matches).await?)
+    } else {
+        Output::Response(spec.call(client, &query).await?)
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    match output::write(&mut out, format, output).and_then(|()| out.flush()) {
+        // The reader went away, e.g. `| head`
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Walk a list endpoint from `--offset` (or the start) to the end
+async fn fetch_all(
+    client: &CexplorerClient,
+    spec: &'static Spec,
+    query: Vec<(&'static str, String)>,
+    matches: &ArgMatches,
+) -> Result<Vec<Value>, CliError> {
+    if !spec.is_list() {
+        return Err(CliError::Usage(format!(
+            "--all needs a list that takes --limit and --offset, which `{}` does not",
+            spec.name
+        )));
+    }
+
+    let number = |name: &str| -> Result<Option<u64>, CliError> {
+        query
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| {
+                value
+                    .parse()
+                    .map_err(|_| CliError::Usage(format!("--{} must be a number, got `{}`", name, value)))
+            })
+            .transpose()
+    };
+    let page_size = number("limit")?.unwrap_or(DEFAULT_PAGE_SIZE);
+    let start_offset = number("offset")?.unwrap_or(0);
+
+    let query: Vec<_> = query
+        .iter()
+        .filter(|(key, _)| *key != "limit" && *key != "offset")
+        .cloned()
+        .collect();
+
+    let mut paginator = client
+        .paginate(|client, page| {
+            let mut query = query.clone();
+            query.push(("limit", page.limit.to_string()));
+            query.push(("offset", page.offset.to_string()));
+            async move { spec.call(client, &query).await.map(JsonPage) }
+        })
+        .page_size(page_size)
+        .start_offset(start_offset);
+    if let Some(max_items) = matches.get_one::<u64>("max-items") {
+        paginator = paginator.max_items(*max_items);
+    }
+
+    Ok(paginator.into_stream().try_collect().await?)
+}
+
+/// A page of any `{ count, data }` list, kept as JSON
+struct JsonPage(Value);
+
+impl ListPage for JsonPage {
+    type Item = Value;
+
+    fn total(&self) -> Option<u64> {
+        let count = self.0.pointer("/data/count")?;
+        count.as_u64().or_else(|| count.as_f64().map(|count| count as u64))
+    }
+
+    fn into_items(mut self) -> Vec<Value> {
+        match self.0.pointer_mut("/data/data").map(Value::take) {
+            Some(Value::Array(items)) => items,
+            _ => Vec::new(),
+        }
+    }
+}
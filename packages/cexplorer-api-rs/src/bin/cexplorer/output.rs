@@ -0,0 +1,169 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+/// Widest a table cell gets before it is cut short
+const MAX_CELL_WIDTH: usize = 48;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Ndjson,
+    Csv,
+    Table,
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 4] = ["json", "ndjson", "csv", "table"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Format::Json),
+            "ndjson" => Some(Format::Ndjson),
+            "csv" => Some(Format::Csv),
+            "table" => Some(Format::Table),
+            _ => None,
+        }
+    }
+}
+
+/// What a command produced: one response, or the items of every page with `--all`
+pub enum Output {
+    Response(Value),
+    Items(Vec<Value>),
+}
+
+impl Output {
+    /// The records to print one per line or row: the items of a list, or the
+    /// `data` of any other response
+    fn into_rows(self) -> Vec<Value> {
+        let mut response = match self {
+            Output::Items(items) => return items,
+            Output::Response(response) => response,
+        };
+
+        if let Some(Value::Array(items)) = response.pointer_mut("/data/data").map(Value::take) {
+            return items;
+        }
+        match response.get_mut("data").map(Value::take) {
+            Some(Value::Array(items)) => items,
+            Some(data) => vec![data],
+            None => vec![response],
+        }
+    }
+}
+
+pub fn write(out: &mut impl Write, format: Format, output: Output) -> io::Result<()> {
+    match (format, output) {
+        (Format::Json, Output::Response(response)) => writeln!(out, "{:#}", response),
+        (Format::Json, Output::Items(items)) => writeln!(out, "{:#}", Value::Array(items)),
+        (Format::Ndjson, output) => {
+            for row in output.into_rows() {
+                writeln!(out, "{}", row)?;
+            }
+            Ok(())
+        }
+        (Format::Csv, output) => write_csv(out, &output.into_rows()),
+        (Format::Table, output) => write_table(out, &output.into_rows()),
+    }
+}
+
+fn write_csv(out: &mut impl Write, rows: &[Value]) -> io::Result<()> {
+    let columns = columns(rows);
+    let header: Vec<String> = columns.iter().map(|column| csv_field(column)).collect();
+    writeln!(out, "{}", header.join(","))?;
+
+    for row in rows {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| csv_field(&cell(row, column)))
+            .collect();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+fn write_table(out: &mut impl Write, rows: &[Value]) -> io::Result<()> {
+    let columns = columns(rows);
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| columns.iter().map(|column| table_cell(&cell(row, column))).collect())
+        .collect();
+
+    let mut widths: Vec<usize> = columns.iter().map(|column| column.chars().count()).collect();
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header: Vec<String> = columns.iter().map(|column| column.to_uppercase()).collect();
+    write_table_row(out, &header, &widths)?;
+    for row in &cells {
+        write_table_row(out, row, &widths)?;
+    }
+    Ok(())
+}
+
+fn write_table_row(out: &mut impl Write, cells: &[String], widths: &[usize]) -> io::Result<()> {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect();
+    writeln!(out, "{}", line.join("  ").trim_end())
+}
+
+/// Keys of the object rows in sorted order, or a single `value` column for scalars
+fn columns(rows: &[Value]) -> Vec<String> {
+    let mut keys = BTreeSet::new();
+    let mut scalars = false;
+    for row in rows {
+        match row {
+            Value::Object(map) => keys.extend(map.keys().cloned()),
+            _ => scalars = true,
+        }
+    }
+
+    let mut columns: Vec<String> = keys.into_iter().collect();
+    if scalars && !columns.iter().any(|column| column == "value") {
+        columns.insert(0, "value".to_string());
+    }
+    columns
+}
+
+/// Text of one field: strings as-is, nested values as compact JSON, nulls empty
+fn cell(row: &Value, column: &str) -> String {
+    let value = match row {
+        Value::Object(map) => map.get(column),
+        _ if column == "value" => Some(row),
+        _ => None,
+    };
+
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn table_cell(text: &str) -> String {
+    let line: String = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    if line.chars().count() <= MAX_CELL_WIDTH {
+        return line;
+    }
+    let mut short: String = line.chars().take(MAX_CELL_WIDTH - 1).collect();
+    short.push('…');
+    short
+}
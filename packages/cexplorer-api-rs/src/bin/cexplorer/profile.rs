@@ -0,0 +1,97 @@
+use crate::CliError;
+use cexplorer_api_rs::{CexplorerClient, CexplorerConfig, HttpClientOptions, RateLimit, RetryPolicy};
+use clap::ArgMatches;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_PROFILE: &str = "default";
+
+/// A named table of the profile file.
+///
+/// ```toml
+/// [default]
+/// network = "mainnet"
+/// api_key = "..."
+///
+/// [preprod]
+/// network = "preprod"
+/// api_key = "..."
+/// rate_limit = { requests_per_minute = 60 }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub network: Option<String>,
+    pub api_key: Option<String>,
+    pub base_url: Option<String>,
+    pub http: Option<HttpClientOptions>,
+    pub retry: Option<RetryPolicy>,
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// `$XDG_CONFIG_HOME/cexplorer/config.toml`, falling back to `~/.config`
+fn default_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("cexplorer").join("config.toml"))
+}
+
+/// Read the selected profile. A missing file or `default` profile is not an
+/// error unless it was asked for explicitly.
+fn load(matches: &ArgMatches) -> Result<Profile, CliError> {
+    let explicit_path = matches.get_one::<PathBuf>("config");
+    let explicit_name = matches.get_one::<String>("profile");
+    let name = explicit_name.map(String::as_str).unwrap_or(DEFAULT_PROFILE);
+
+    let path = match explicit_path.cloned().or_else(default_path) {
+        Some(path) => path,
+        None => return Ok(Profile::default()),
+    };
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(_) if explicit_path.is_none() && explicit_name.is_none() => return Ok(Profile::default()),
+        Err(source) => return Err(CliError::Io { path, source }),
+    };
+
+    let mut profiles: HashMap<String, Profile> =
+        toml::from_str(&text).map_err(|source| CliError::Profile { path: path.clone(), source })?;
+    match profiles.remove(name) {
+        Some(profile) => Ok(profile),
+        None if explicit_name.is_none() => Ok(Profile::default()),
+        None => Err(CliError::Usage(format!("no profile `{}` in {}", name, path.display()))),
+    }
+}
+
+/// Build the client from flags and environment variables, falling back to the profile
+pub fn client(matches: &ArgMatches) -> Result<CexplorerClient, CliError> {
+    let profile = load(matches)?;
+    let flag = |name: &str| matches.get_one::<String>(name).cloned();
+
+    let network = flag("network")
+        .or(profile.network)
+        .unwrap_or_else(|| "mainnet".to_string());
+    let api_key = flag("api-key").or(profile.api_key).ok_or_else(|| {
+        CliError::Usage("no API key: pass --api-key, set CEXPLORER_API_KEY or add api_key to a profile".to_string())
+    })?;
+
+    let mut config = CexplorerConfig::new(&network, &api_key)?;
+    if let Some(base_url) = flag("base-url").or(profile.base_url) {
+        config = config.with_base_url(&base_url);
+    }
+    if let Some(http) = profile.http {
+        config = config.with_http_options(http)?;
+    }
+    if let Some(retry) = profile.retry {
+        config = config.with_retry_policy(retry);
+    }
+    if let Some(rate_limit) = profile.rate_limit {
+        config = config.with_rate_limit(rate_limit);
+    }
+
+    Ok(CexplorerClient::new(config))
+}
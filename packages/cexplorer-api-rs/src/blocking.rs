@@ -0,0 +1,465 @@
+//! Synchronous client, enabled by the `blocking` feature.
+//!
+//! [`CexplorerClient`] mirrors every endpoint method of the async
+//! [`crate::CexplorerClient`] and runs it to completion on a runtime of its
+//! own, so it can be used from code that has no async runtime. As with
+//! `reqwest::blocking`, calling it from within an async runtime panics.
+//!
+//! ```no_run
+//! use cexplorer_api_rs::blocking::CexplorerClient;
+//!
+//! let client = CexplorerClient::from_credentials("mainnet", "api-key")?;
+//! let stats = client.get_drep_stat()?;
+//! println!("{:?}", stats.data);
+//! # Ok::<(), cexplorer_api_rs::CexplorerError>(())
+//! ```
+
+use crate::prelude::*;
+use futures::{Stream, StreamExt};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking counterpart of [`crate::CexplorerClient`].
+///
+/// Clones share the runtime, so one instance can be handed to several threads.
+#[derive(Debug, Clone)]
+pub struct CexplorerClient {
+    inner: crate::CexplorerClient,
+    runtime: Arc<Runtime>,
+}
+
+/// Blocking wrappers with the same arguments as the async methods, each
+/// documented with a link to the method it runs
+macro_rules! blocking_methods {
+    ($($(#[$meta:meta])* fn $name:ident($($arg:ident: $ty:ty),*) -> $response:ty;)*) => {
+        impl CexplorerClient {
+            $(
+                $(#[$meta])*
+                ///
+                #[doc = concat!("See [`crate::CexplorerClient::", stringify!($name), "`]")]
+                pub fn $name(&self, $($arg: $ty),*) -> Result<$response, CexplorerError> {
+                    self.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+impl CexplorerClient {
+    pub fn new(config: CexplorerConfig) -> Result<Self, CexplorerError> {
+        Self::from_async(crate::CexplorerClient::new(config))
+    }
+
+    /// Validate the credentials and build a client in one step
+    pub fn from_credentials(network: &str, api_key: &str) -> Result<Self, CexplorerError> {
+        Self::from_async(crate::CexplorerClient::from_credentials(network, api_key)?)
+    }
+
+    /// Blocking client that sends its requests through `client`, keeping its
+    /// retry policy, rate limiter, cache and transport
+    pub fn from_async(client: crate::CexplorerClient) -> Result<Self, CexplorerError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| CexplorerError::NetworkError(format!("failed to start runtime: {}", e)))?;
+        Ok(CexplorerClient {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this one drives
+    pub fn inner(&self) -> &crate::CexplorerClient {
+        &self.inner
+    }
+
+    pub fn config(&self) -> &CexplorerConfig {
+        self.inner.config()
+    }
+
+    /// Run `future` to completion on this client's runtime
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Walk a list endpoint page by page, see [`crate::CexplorerClient::paginate`].
+    ///
+    /// The fetch closure is async and receives the async client, since it
+    /// runs on this client's runtime.
+    ///
+    /// ```no_run
+    /// use cexplorer_api_rs::blocking::CexplorerClient;
+    /// use cexplorer_api_rs::prelude::{PoolData, PoolListRequest};
+    ///
+    /// let client = CexplorerClient::from_credentials("mainnet", "api-key")?;
+    /// let pools = client
+    ///     .paginate(|client, page| async move {
+    ///         client
+    ///             .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+    ///             .await
+    ///     })
+    ///     .max_items(500);
+    /// for pool in pools {
+    ///     let pool: PoolData = pool?;
+    ///     println!("{}", pool.pool_id);
+    /// }
+    /// # Ok::<(), cexplorer_api_rs::CexplorerError>(())
+    /// ```
+    pub fn paginate<R, F, Fut>(&self, fetch: F) -> Paginator<R, impl Fn(PageRequest) -> Fut>
+    where
+        R: ListPage,
+        F: Fn(crate::CexplorerClient, PageRequest) -> Fut,
+        Fut: Future<Output = Result<R, CexplorerError>>,
+    {
+        Paginator {
+            inner: self.inner.paginate(fetch),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    fn map(&self, f: impl FnOnce(&crate::CexplorerClient) -> crate::CexplorerClient) -> Self {
+        CexplorerClient {
+            inner: f(&self.inner),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// See [`crate::CexplorerClient::with_retry_policy`]
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Self {
+        self.map(|client| client.with_retry_policy(policy))
+    }
+
+    /// See [`crate::CexplorerClient::with_rate_limit`]
+    pub fn with_rate_limit(&self, limit: RateLimit) -> Self {
+        self.map(|client| client.with_rate_limit(limit))
+    }
+
+    /// See [`crate::CexplorerClient::with_rate_limiter`]
+    pub fn with_rate_limiter(&self, limiter: Arc<RateLimiter>) -> Self {
+        self.map(|client| client.with_rate_limiter(limiter))
+    }
+
+    /// See [`crate::CexplorerClient::with_plan_limits`]
+    pub fn with_plan_limits(&self, plan: ApiPlan) -> Result<Self, CexplorerError> {
+        let inner = self.block_on(self.inner.with_plan_limits(plan))?;
+        Ok(self.map(|_| inner))
+    }
+
+    /// See [`crate::CexplorerClient::with_cache`]
+    pub fn with_cache(&self, cache: Arc<ResponseCache>) -> Self {
+        self.map(|client| client.with_cache(cache))
+    }
+
+    /// See [`crate::CexplorerClient::with_transport`]
+    pub fn with_transport(&self, transport: Arc<dyn Transport>) -> Self {
+        self.map(|client| client.with_transport(transport))
+    }
+
+    /// See [`crate::CexplorerClient::with_timeout`]
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        self.map(|client| client.with_timeout(timeout))
+    }
+
+    /// See [`crate::CexplorerClient::with_cancellation`]
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        self.map(|client| client.with_cancellation(token))
+    }
+
+    /// See [`crate::CexplorerClient::with_batch_concurrency`]; batches default
+    /// to [`DEFAULT_BATCH_CONCURRENCY`](crate::DEFAULT_BATCH_CONCURRENCY) requests in flight
+    pub fn with_batch_concurrency(&self, concurrency: usize) -> Self {
+        self.map(|client| client.with_batch_concurrency(concurrency))
+    }
+
+    /// See [`crate::CexplorerClient::call`]
+    pub fn call<E: Endpoint>(&self, request: E) -> Result<E::Response, CexplorerError> {
+        self.block_on(self.inner.call(request))
+    }
+
+    /// See [`crate::CexplorerClient::call_batch`]
+    pub fn call_batch<K, E, F>(&self, keys: impl IntoIterator<Item = K>, request: F) -> BatchResults<K, E::Response>
+    where
+        K: Clone + Eq + Hash,
+        E: Endpoint,
+        F: Fn(&K) -> E,
+    {
+        self.block_on(self.inner.call_batch(keys, request))
+    }
+
+    /// See [`crate::CexplorerClient::get_tx_details`]
+    pub fn get_tx_details(&self, hashes: &[TxHash]) -> BatchResults<TxHash, TxDetailResponse> {
+        self.block_on(self.inner.get_tx_details(hashes))
+    }
+
+    /// See [`crate::CexplorerClient::get_block_details`]
+    pub fn get_block_details(&self, hashes: &[BlockHash]) -> BatchResults<BlockHash, BlockDetailResponse> {
+        self.block_on(self.inner.get_block_details(hashes))
+    }
+
+    /// See [`crate::CexplorerClient::get_address_details`]
+    pub fn get_address_details(&self, views: &[Address]) -> BatchResults<Address, AddressDetailResponse> {
+        self.block_on(self.inner.get_address_details(views))
+    }
+
+    /// See [`crate::CexplorerClient::get_pool_details`]
+    pub fn get_pool_details(&self, pool_ids: &[PoolId]) -> BatchResults<PoolId, PoolDetailResponse> {
+        self.block_on(self.inner.get_pool_details(pool_ids))
+    }
+
+    /// See [`crate::CexplorerClient::get_asset_details`]
+    pub fn get_asset_details(
+        &self,
+        fingerprints: &[AssetFingerprint],
+    ) -> BatchResults<AssetFingerprint, AssetDetailResponse> {
+        self.block_on(self.inner.get_asset_details(fingerprints))
+    }
+
+    /// See [`crate::CexplorerClient::get_drep_details`]
+    pub fn get_drep_details(&self, ids: &[DRepId]) -> BatchResults<DRepId, DrepDetailResponse> {
+        self.block_on(self.inner.get_drep_details(ids))
+    }
+}
+
+/// Blocking counterpart of [`crate::Paginator`], iterated with a `for` loop.
+///
+/// Each call to `next` fetches pages as needed, stopping under the same
+/// conditions as the async paginator.
+pub struct Paginator<R: ListPage, F> {
+    inner: crate::Paginator<R, F>,
+    runtime: Arc<Runtime>,
+}
+
+impl<R, F, Fut> Paginator<R, F>
+where
+    R: ListPage,
+    F: Fn(PageRequest) -> Fut,
+    Fut: Future<Output = Result<R, CexplorerError>>,
+{
+    fn map(self, f: impl FnOnce(crate::Paginator<R, F>) -> crate::Paginator<R, F>) -> Self {
+        Paginator {
+            inner: f(self.inner),
+            runtime: self.runtime,
+        }
+    }
+
+    /// See [`crate::Paginator::page_size`]
+    pub fn page_size(self, page_size: u64) -> Self {
+        self.map(|inner| inner.page_size(page_size))
+    }
+
+    /// See [`crate::Paginator::start_offset`]
+    pub fn start_offset(self, offset: u64) -> Self {
+        self.map(|inner| inner.start_offset(offset))
+    }
+
+    /// See [`crate::Paginator::max_items`]
+    pub fn max_items(self, max_items: u64) -> Self {
+        self.map(|inner| inner.max_items(max_items))
+    }
+
+    /// See [`crate::Paginator::concurrency`]
+    pub fn concurrency(self, concurrency: usize) -> Self {
+        self.map(|inner| inner.concurrency(concurrency))
+    }
+
+    /// See [`crate::Paginator::stable_cursor`]
+    pub fn stable_cursor<K>(self, key: K) -> Self
+    where
+        K: Fn(&R::Item) -> String + Send + Sync + 'static,
+    {
+        self.map(|inner| inner.stable_cursor(key))
+    }
+
+    /// See [`crate::Paginator::overlap`]
+    pub fn overlap(self, overlap: u64) -> Self {
+        self.map(|inner| inner.overlap(overlap))
+    }
+}
+
+impl<R, F, Fut> IntoIterator for Paginator<R, F>
+where
+    R: ListPage + 'static,
+    F: Fn(PageRequest) -> Fut + 'static,
+    Fut: Future<Output = Result<R, CexplorerError>> + 'static,
+{
+    type Item = Result<R::Item, CexplorerError>;
+    type IntoIter = PageIter<R::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PageIter {
+            stream: Box::pin(self.inner.into_stream()),
+            runtime: self.runtime,
+        }
+    }
+}
+
+/// Items of a blocking [`Paginator`]
+pub struct PageIter<T> {
+    stream: Pin<Box<dyn Stream<Item = Result<T, CexplorerError>>>>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for PageIter<T> {
+    type Item = Result<T, CexplorerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+blocking_methods! {
+    fn get_block_list(request: BlockListRequest) -> BlocksListResponse;
+    fn get_block_detail(hash: &BlockHash) -> BlockDetailResponse;
+
+    /// Get detailed information for a specific address
+    fn get_address_detail(view: &Address) -> AddressDetailResponse;
+    /// Get a list of addresses based on filters
+    fn get_address_list(request: AddressListRequest) -> AddressListResponse;
+    /// Get UTXOs (Unspent Transaction Outputs) for a given address
+    fn get_address_utxo(view: &Address) -> AddressDetailUTXOResponse;
+    /// Inspect and extract metadata from a Cardano address
+    fn inspect_address(view: &str) -> AddressInspectorResponse;
+
+    /// Get list of all epochs
+    fn get_epoch_list() -> EpochListResponse;
+    /// Get protocol parameters for a specific epoch
+    fn get_epoch_detail_param(no: u64) -> EpochDetailParamResponse;
+    /// Get statistics for a specific epoch
+    fn get_epoch_detail_stats(no: u64) -> EpochDetailStatsResponse;
+
+    fn get_pool_blocks(pool_id: &PoolId) -> PoolBlocksResponse;
+    fn get_pool_delegators(request: PoolDelegatorsRequest) -> PoolDelegatorsResponse;
+    fn get_pool_reward(request: PoolRewardsRequest) -> PoolRewardsResponse;
+    fn get_pool_detail(request: PoolDetailRequest) -> PoolDetailResponse;
+    fn get_pools_list(request: PoolListRequest) -> PoolsListResponse;
+    fn get_pools_birthdays(pool_id: &PoolId) -> PoolBirthdaysResponse;
+    fn get_pool_update(pool_id: &PoolId) -> PoolUpdateResponse;
+    fn get_pool_awards(pool_id: &PoolId) -> PoolAwardsResponse;
+    fn get_pool_delegators_stats(pool_id: &PoolId) -> PoolDelegatorStatsResponse;
+    fn get_global_pool_awards(request: GlobalPoolAwardsRequest) -> PoolAwardsResponse;
+    fn get_pool_about(pool_id: &PoolId) -> PoolAboutResponse;
+    fn get_top_margins_with_delegators(request: TopMarginsRequest) -> TopMarginsWithDelegatorsResponse;
+    fn get_retired_pools(request: RetiredPoolsRequest) -> RetiredPoolsResponse;
+    fn get_top_multi_delegators(request: TopMultiDelegatorsRequest) -> TopMultiDelegatorsResponse;
+    fn get_deleg_epoch_registered() -> DelegEpochRegisteredResponse;
+    fn get_stake_dreps_not_spo() -> StakeDrepsNotSpoResponse;
+    fn get_pool_retire(pool_id: &PoolId) -> PoolRetireResponse;
+
+    fn get_hardforks() -> HardforkResponse;
+    fn get_epoch_analytics() -> EpochAnalyticsResponse;
+    fn get_analytics_rate() -> AnalyticsRateResponse;
+    fn get_analytics_pool_block(epoch_no: u64) -> AnalyticsPoolBlockResponse;
+    fn get_analytics_staking_accounts(request: TopStakingAccountsRequest) -> AnalyticsTopStakingAccountsResponse;
+    fn get_analytics_top_addresses(request: TopAddressesRequest) -> AnalyticsTopAddressesResponse;
+    fn get_wealth_composition() -> WealthCompositionResponse;
+    fn get_ada_pots() -> AnalyticsAdaPotsResponse;
+    fn get_group_list() -> GroupsListResponse;
+    fn get_group_detail(id: &str) -> GroupDetailResponse;
+    fn get_average_pool() -> AveragePoolResponse;
+    fn get_genesis_addr() -> GenesisAddrResponse;
+
+    fn get_account_rewards(request: AccountRewardsRequest) -> AccountRewardResponse;
+    fn check_user_delegation(view: Option<&StakeAddress>) -> CheckDelegationResponse;
+    fn get_withdrawals(request: WithdrawalsRequest) -> WithdrawalsResponse;
+    fn get_delegation_vote(request: DelegationVoteRequest) -> DrepDelegationResponse;
+
+    fn get_article_detail(request: ArticleDetailRequest) -> ArticleDetailResponse;
+    fn get_article_list(request: ArticleListRequest) -> ArticleListResponse;
+
+    fn get_asset_list(request: AssetListRequest) -> AssetListResponse;
+    fn get_asset_detail(fingerprint: &AssetFingerprint) -> AssetDetailResponse;
+    fn get_asset_owners(request: AssetOwnersRequest) -> AssetOwnersNftResponse;
+    fn get_nft_asset_owners(request: AssetOwnerHistoryRequest) -> AssetOwnersNftResponse;
+    fn get_asset_metadata(assetname: &str) -> AssetMetadataResponse;
+    fn get_asset_mint(request: AssetMintRequest) -> AssetMintResponse;
+    fn get_asset_stats(request: AssetStatsRequest) -> AssetStatsResponse;
+
+    fn get_datum_detail(hash: &DatumHash) -> DatumDetailResponse;
+
+    fn get_delegations_state(view: &StakeAddress) -> DelegationStateResponse;
+    fn get_stake_delegations(request: StakeDelegationsRequest) -> DelegationResponse;
+    fn get_delegations_to_retired(request: DelegationsToRetiredRequest) -> DelegationToRetiredResponse;
+
+    fn get_drep_stat() -> DrepStatResponse;
+    fn get_drep_analytics() -> DrepAnalyticsResponse;
+    fn get_stake_drep_retired() -> StakeDrepRetiredResponse;
+    fn get_drep_list(request: DrepListRequest) -> DrepListResponse;
+    fn get_drep_detail(hash: &DRepId) -> DrepDetailResponse;
+    fn get_drep_vote(request: DrepVoteRequest) -> DrepVoteResponse;
+    fn get_drep_delegator(request: DrepDelegatorRequest) -> DrepDelegatorResponse;
+    fn get_drep_delegator_stats(view: &DRepId) -> PoolDelegatorStatsResponse;
+    fn get_average_drep() -> AverageDrepResponse;
+    fn get_drep_spo_same_time() -> DrepSpoSameTimeResponse;
+    fn get_stake_is_spo_drep() -> StakeIsSpoDrepResponse;
+    fn get_drep_not_spo_same_time() -> DrepNotSpoSameTimeResponse;
+    fn get_deleg_epoch_changes() -> DelegEpochChangesResponse;
+
+    fn get_metadata_tx_list(request: MetadataTxListRequest) -> MetadataTxListResponse;
+
+    fn get_policy_detail(id: &PolicyId) -> PolicyDetailResponse;
+    fn get_policy_stats(id: &PolicyId) -> PolicyStatsResponse;
+    fn get_policy_owner(request: PolicyOwnerRequest) -> PolicyOwnerResponse;
+
+    fn get_script_detail(hash: &ScriptHash) -> ScriptDetailResponse;
+    fn get_script_detail_redeemer(request: ScriptRedeemerRequest) -> ScriptDetailRedeemerResponse;
+    fn get_script_list(request: ScriptListRequest) -> ScriptListResponse;
+
+    fn get_misc_api() -> MiscApiResponse;
+    fn get_misc_basic() -> MiscBasicResponse;
+    fn get_misc_rate() -> MiscRateResponse;
+    fn get_misc_const() -> MiscConstResponse;
+    fn get_misc_market(request: MiscMarketRequest) -> MiscMarketResponse;
+    fn get_misc_search(request: MiscSearchRequest) -> MiscSearchResponse;
+    fn get_poll_list() -> PollListResponse;
+    fn misc_validate(request: MiscValidateRequest) -> MiscValidateResponse;
+    fn get_misc_health() -> MiscHealthResponse;
+    fn get_misc_protocol_parameters() -> MiscProtocolParametersResponse;
+
+    fn get_stake_detail(view: &StakeAddress) -> StakeDetailResponse;
+
+    fn get_treasury_donation_stats() -> TreasuryDonationStatsResponse;
+
+    fn get_defi_token_list(request: DeFiTokenListRequest) -> DeFiTokenListResponse;
+    fn get_defi_token_stat() -> DeFiTokenStatResponse;
+    fn get_defi_order(request: DeFiOrderRequest) -> DeFiOrderListResponse;
+
+    fn compare_wallets() -> CompareWalletsResponse;
+
+    fn get_tx_detail(hash: &TxHash) -> TxDetailResponse;
+    fn get_tx_list(request: TxListRequest) -> TxListResponse;
+    fn get_drep_registrations(request: TxFilterRequest) -> DrepRegistrationsResponse;
+    fn get_drep_deregistrations(request: TxFilterRequest) -> DrepRegistrationsResponse;
+    fn get_drep_updates(request: TxFilterRequest) -> DrepRegistrationsResponse;
+    fn get_pool_registrations(request: TxFilterRequest) -> PoolRegistrationsResponse;
+    fn get_pool_deregistrations(request: TxFilterRequest) -> PoolRegistrationsResponse;
+    fn get_stake_registrations(request: TxFilterRequest) -> StakeRegistrationsResponse;
+    fn get_contract_transactions(request: TxFilterRequest) -> ContractInteractionsResponse;
+
+    /// Get governance action proposal list
+    fn get_gov_action_proposal_list(request: GovActionProposalListRequest) -> GovernanceActionListResponse;
+    /// Get governance action proposal detail
+    fn get_gov_action_proposal_detail(id: &GovActionId) -> GovernanceActionDetailResponse;
+    /// Get governance votes
+    fn get_gov_vote(request: GovVoteRequest) -> GovVoteResponse;
+    /// Get governance votes NOT voted
+    fn get_gov_vote_not(request: GovVoteNotRequest) -> GovVoteResponse;
+    /// Get committee list
+    fn get_committee_list() -> CommitteeListResponse;
+    /// Get committee detail
+    fn get_committee_detail(id: Option<u32>) -> CommitteeDetailResponse;
+    /// Get committee member detail
+    fn get_committee_member(ident: &CommitteeMemberId) -> CCMemberDetailResponse;
+    /// Get constitution list
+    fn get_constitution_list(limit: Option<u32>) -> ConstitutionListResponse;
+    /// Get governance thresholds
+    fn get_thresholds() -> ThresholdResponse;
+    /// Get drep list vote
+    fn get_drep_list_vote(request: DrepListVoteRequest) -> DrepListVoteResponse;
+
+    /// Send delegation or donation transaction information
+    fn send_tx_sent(hash: &TxHash, campaign: &str, tx_type: TxSentType) -> TxSentResponse;
+}
@@ -0,0 +1,388 @@
+use crate::network::Network;
+use crate::runtime;
+use crate::time::{self, SlotConfig};
+use async_trait::async_trait;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How far behind the tip a block must be before it can no longer be rolled back.
+///
+/// Cardano's security parameter is 2160 blocks, about 12 hours at one block
+/// every 20 seconds.
+pub const FINALITY_WINDOW: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// Raw response body as kept by a [`CacheStore`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub body: String,
+    /// API tokens the original request cost
+    pub tokens: f64,
+    /// `None` for responses that never change
+    pub expires_at: Option<SystemTime>,
+}
+
+impl CachedResponse {
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Storage backend for cached responses.
+///
+/// [`MemoryCache`] is the built-in implementation. Shared backends such as
+/// Redis or a disk store only need to persist [`CachedResponse`] by key;
+/// expiry is checked by the caller.
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    async fn put(&self, key: &str, response: CachedResponse);
+
+    async fn remove(&self, key: &str);
+
+    async fn clear(&self);
+}
+
+/// In-process LRU store
+pub struct MemoryCache {
+    entries: Mutex<LruCache<String, CachedResponse>>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        MemoryCache {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new(1_000)
+    }
+}
+
+impl fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self.entries.lock().unwrap();
+        f.debug_struct("MemoryCache")
+            .field("len", &entries.len())
+            .field("capacity", &entries.cap())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl CacheStore for MemoryCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        self.entries.lock().unwrap().put(key.to_string(), response);
+    }
+
+    async fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().pop(key);
+    }
+
+    async fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+type FinalizedFn = Arc<dyn Fn(&Value) -> bool + Send + Sync>;
+
+/// How long responses from one endpoint are kept
+#[derive(Clone)]
+pub enum CacheRule {
+    /// Never cache
+    Skip,
+    Ttl(Duration),
+    /// Cache forever, for data that cannot change once it exists
+    Immutable,
+    /// Cache forever once `finalized` holds for the response body, for `ttl` until then
+    ImmutableWhen { ttl: Duration, finalized: FinalizedFn },
+}
+
+impl CacheRule {
+    /// Immutable once the timestamp at JSON `pointer` is older than [`FINALITY_WINDOW`]
+    pub fn finalized_after(ttl: Duration, pointer: &'static str) -> Self {
+        CacheRule::ImmutableWhen {
+            ttl,
+            finalized: Arc::new(move |body| {
+                body.pointer(pointer)
+                    .and_then(Value::as_str)
+                    .and_then(parse_timestamp)
+                    .and_then(|time| runtime::now().duration_since(time).ok())
+                    .is_some_and(|age| age >= FINALITY_WINDOW)
+            }),
+        }
+    }
+
+    /// Immutable once the epoch number at JSON `pointer` is before the epoch
+    /// in progress on the chain timed by `slots`
+    pub fn past_epoch(ttl: Duration, pointer: &'static str, slots: SlotConfig) -> Self {
+        CacheRule::ImmutableWhen {
+            ttl,
+            finalized: Arc::new(move |body| {
+                let current = runtime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .ok()
+                    .and_then(|now| i64::try_from(now.as_secs()).ok())
+                    .and_then(|now| slots.unix_to_epoch(now));
+                match (body.pointer(pointer).and_then(Value::as_u64), current) {
+                    (Some(epoch), Some(current)) => epoch < current,
+                    _ => false,
+                }
+            }),
+        }
+    }
+
+    fn expires_at(&self, body: &str, now: SystemTime) -> Option<Option<SystemTime>> {
+        match self {
+            CacheRule::Skip => None,
+            CacheRule::Ttl(ttl) => Some(Some(now + *ttl)),
+            CacheRule::Immutable => Some(None),
+            CacheRule::ImmutableWhen { ttl, finalized } => {
+                let finalized = serde_json::from_str::<Value>(body)
+                    .map(|body| finalized(&body))
+                    .unwrap_or(false);
+                Some(if finalized { None } else { Some(now + *ttl) })
+            }
+        }
+    }
+}
+
+impl fmt::Debug for CacheRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheRule::Skip => f.write_str("Skip"),
+            CacheRule::Ttl(ttl) => f.debug_tuple("Ttl").field(ttl).finish(),
+            CacheRule::Immutable => f.write_str("Immutable"),
+            CacheRule::ImmutableWhen { ttl, .. } => f
+                .debug_struct("ImmutableWhen")
+                .field("ttl", ttl)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Cache rules keyed by endpoint path, e.g. `"/misc/const"`
+///
+/// The default policy only caches endpoints known to change slowly; every
+/// other endpoint goes to the network unless `default_ttl` is set. Use
+/// [`CachePolicy::for_network`] so epoch parameters are kept for good as soon
+/// as their epoch is over on that network.
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    pub default_ttl: Option<Duration>,
+    rules: HashMap<String, CacheRule>,
+}
+
+impl Default for CachePolicy {
+    /// Rules for an unknown network. Preprod has the lowest epoch numbers of
+    /// the public networks, so epochs it counts as past are past on all of them.
+    fn default() -> Self {
+        CachePolicy::for_network(&Network::Preprod)
+    }
+}
+
+impl CachePolicy {
+    /// Default rules, with epoch parameters final once their epoch is over on `network`
+    pub fn for_network(network: &Network) -> Self {
+        const MINUTE: Duration = Duration::from_secs(60);
+        const HOUR: Duration = Duration::from_secs(60 * 60);
+
+        let epoch_param = match network.slot_config() {
+            Some(slots) => CacheRule::past_epoch(10 * MINUTE, "/data/epoch_no", slots),
+            None => CacheRule::Ttl(10 * MINUTE),
+        };
+
+        CachePolicy::empty()
+            .rule("/misc/const", CacheRule::Ttl(HOUR))
+            .rule("/misc/api", CacheRule::Ttl(HOUR))
+            .rule("/misc/protocol_parameters", CacheRule::Ttl(10 * MINUTE))
+            .rule("/analytics/hardforks", CacheRule::Ttl(HOUR))
+            .rule("/analytics/genesis_addr", CacheRule::Immutable)
+            .rule("/epoch/param", epoch_param)
+            .rule("/epoch/list", CacheRule::Ttl(5 * MINUTE))
+            .rule("/pool/about", CacheRule::Ttl(HOUR))
+            .rule("/asset/metadata", CacheRule::Ttl(HOUR))
+            .rule("/block/detail", CacheRule::finalized_after(MINUTE, "/data/time"))
+            .rule("/tx/detail", CacheRule::finalized_after(MINUTE, "/data/block/time"))
+    }
+
+    /// Policy without any rules
+    pub fn empty() -> Self {
+        CachePolicy {
+            default_ttl: None,
+            rules: HashMap::new(),
+        }
+    }
+
+    pub fn rule(mut self, endpoint: &str, rule: CacheRule) -> Self {
+        self.rules.insert(endpoint.to_string(), rule);
+        self
+    }
+
+    pub fn ttl(self, endpoint: &str, ttl: Duration) -> Self {
+        self.rule(endpoint, CacheRule::Ttl(ttl))
+    }
+
+    pub fn skip(self, endpoint: &str) -> Self {
+        self.rule(endpoint, CacheRule::Skip)
+    }
+
+    /// TTL for endpoints without a rule
+    pub fn with_default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = Some(ttl);
+        self
+    }
+
+    /// Rule for `endpoint`, which may still carry an inline query string
+    pub fn rule_for(&self, endpoint: &str) -> CacheRule {
+        let path = endpoint.split('?').next().unwrap_or(endpoint);
+        match (self.rules.get(path), self.default_ttl) {
+            (Some(rule), _) => rule.clone(),
+            (None, Some(ttl)) => CacheRule::Ttl(ttl),
+            (None, None) => CacheRule::Skip,
+        }
+    }
+}
+
+/// Hit and miss counts of a [`ResponseCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub stores: u64,
+    /// API tokens not spent thanks to cache hits
+    pub tokens_saved: f64,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Response cache shared by every clone of a client
+///
+/// Entries are keyed by base URL, endpoint and serialized query parameters.
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    policy: CachePolicy,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    stores: AtomicU64,
+    /// Tokens saved, in thousandths so it fits an atomic integer
+    tokens_saved_milli: AtomicU64,
+}
+
+impl ResponseCache {
+    pub fn new(store: impl CacheStore + 'static, policy: CachePolicy) -> Self {
+        ResponseCache {
+            store: Arc::new(store),
+            policy,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            stores: AtomicU64::new(0),
+            tokens_saved_milli: AtomicU64::new(0),
+        }
+    }
+
+    /// LRU cache holding up to `capacity` responses, with the default policy
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::new(MemoryCache::new(capacity), CachePolicy::default())
+    }
+
+    pub fn policy(&self) -> &CachePolicy {
+        &self.policy
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            stores: self.stores.load(Ordering::Relaxed),
+            tokens_saved: self.tokens_saved_milli.load(Ordering::Relaxed) as f64 / 1000.0,
+        }
+    }
+
+    pub async fn clear(&self) {
+        self.store.clear().await;
+    }
+
+    /// Cache key for a request, or `None` if the endpoint is not cached
+    pub(crate) fn key(&self, base_url: &str, endpoint: &str, query: &str) -> Option<String> {
+        if matches!(self.policy.rule_for(endpoint), CacheRule::Skip) {
+            return None;
+        }
+        Some(format!("{}{}?{}", base_url, endpoint, query))
+    }
+
+    /// Fresh body stored under `key`, counting the lookup as a hit or miss
+    pub(crate) async fn lookup(&self, key: &str) -> Option<String> {
+        let entry = match self.store.get(key).await {
+            Some(entry) if !entry.is_expired(runtime::now()) => entry,
+            Some(_) => {
+                self.store.remove(key).await;
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+        };
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.tokens_saved_milli
+            .fetch_add((entry.tokens.max(0.0) * 1000.0) as u64, Ordering::Relaxed);
+        Some(entry.body)
+    }
+
+    pub(crate) async fn store(&self, key: &str, endpoint: &str, body: &str, tokens: f64) {
+        let now = runtime::now();
+        let Some(expires_at) = self.policy.rule_for(endpoint).expires_at(body, now) else {
+            return;
+        };
+
+        let entry = CachedResponse {
+            body: body.to_string(),
+            tokens,
+            expires_at,
+        };
+        self.store.put(key, entry).await;
+        self.stores.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("policy", &self.policy)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Time a response timestamp refers to, as UTC unless it carries an offset
+fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let seconds = u64::try_from(time::unix_seconds(value)?).ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
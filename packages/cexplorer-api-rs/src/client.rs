@@ -1,53 +1,431 @@
-use crate::config::get_config;
+use crate::batch::DEFAULT_BATCH_CONCURRENCY;
+use crate::cache::ResponseCache;
+use crate::config::CexplorerConfig;
+use crate::endpoints::Endpoint;
 use crate::error::CexplorerError;
-use reqwest::Client;
+use crate::rate_limit::{ApiPlan, RateLimit, RateLimiter};
+use crate::retry::{parse_retry_after, RetryPolicy};
+use crate::runtime;
+use crate::transport::{HttpRequest, Transport};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
-pub async fn fetch<T: DeserializeOwned>(endpoint: &str) -> Result<T, CexplorerError> {
-    fetch_with_params::<T, ()>(endpoint, None).await
+/// Longest body excerpt kept in an error
+const SNIPPET_LEN: usize = 500;
+
+/// Client bound to a single network and API key.
+///
+/// Every endpoint in `endpoints::*` is available as a method. The client is
+/// cheap to clone, so one instance per network can be shared across tasks.
+#[derive(Debug, Clone)]
+pub struct CexplorerClient {
+    config: Arc<CexplorerConfig>,
+    retry: RetryPolicy,
+    limiter: Option<Arc<RateLimiter>>,
+    cache: Option<Arc<ResponseCache>>,
+    transport: Option<Arc<dyn Transport>>,
+    timeout: Option<Duration>,
+    cancel: Option<CancellationToken>,
+    batch_concurrency: usize,
 }
 
-pub async fn fetch_with_params<T: DeserializeOwned, P: Serialize>(
-    endpoint: &str,
-    params: Option<&P>,
-) -> Result<T, CexplorerError> {
-    let config = get_config()?;
+impl CexplorerClient {
+    pub fn new(mut config: CexplorerConfig) -> Self {
+        config.ensure_http_client();
+        CexplorerClient {
+            retry: config.retry.clone(),
+            transport: config.transport().ok(),
+            timeout: None,
+            cancel: None,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+            limiter: config.rate_limit.clone().map(|limit| Arc::new(RateLimiter::new(limit))),
+            cache: None,
+            config: Arc::new(config),
+        }
+    }
+
+    /// Validate the credentials and build a client in one step
+    pub fn from_credentials(network: &str, api_key: &str) -> Result<Self, CexplorerError> {
+        Ok(Self::new(CexplorerConfig::new(network, api_key)?))
+    }
+
+    pub fn config(&self) -> &CexplorerConfig {
+        &self.config
+    }
+
+    /// Copy of this client that retries with `policy`, for tuning a single call:
+    /// `client.with_retry_policy(RetryPolicy::none()).get_tx_detail(hash)`
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Self {
+        CexplorerClient {
+            retry: policy,
+            ..self.clone()
+        }
+    }
 
-    let base_url = format!("https://api-{}.cexplorer.io/v1", config.network);
-    let url = format!("{}{}", base_url, endpoint);
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
 
-    let client = Client::builder()
-        .timeout(Duration::from_secs(30))
-        .build()?;
+    /// Copy of this client throttled by a fresh limiter for `limit`
+    pub fn with_rate_limit(&self, limit: RateLimit) -> Self {
+        self.with_rate_limiter(Arc::new(RateLimiter::new(limit)))
+    }
 
-    let mut request = client
-        .get(&url)
-        .header("api-key", &config.api_key);
+    /// Copy of this client sharing `limiter`, e.g. with clients for other networks
+    /// that draw on the same API key
+    pub fn with_rate_limiter(&self, limiter: Arc<RateLimiter>) -> Self {
+        CexplorerClient {
+            limiter: Some(limiter),
+            ..self.clone()
+        }
+    }
 
-    if let Some(p) = params {
-        request = request.query(p);
+    /// Copy of this client throttled to the allowances of `plan`, as published by `/misc/api`
+    pub async fn with_plan_limits(&self, plan: ApiPlan) -> Result<Self, CexplorerError> {
+        let plans = self.get_misc_api().await?;
+        Ok(self.with_rate_limit(RateLimit::from(plan.tier(&plans.data.plans))))
     }
 
-    let response = request.send().await?;
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.limiter.as_ref()
+    }
 
-    if !response.status().is_success() {
-        return Err(CexplorerError::NetworkError(
-            format!("Status: {}", response.status())
-        ));
+    /// Copy of this client that serves cacheable endpoints from `cache`
+    pub fn with_cache(&self, cache: Arc<ResponseCache>) -> Self {
+        CexplorerClient {
+            cache: Some(cache),
+            ..self.clone()
+        }
     }
 
+    pub fn cache(&self) -> Option<&Arc<ResponseCache>> {
+        self.cache.as_ref()
+    }
 
-    let text = response.text().await?;
+    /// Copy of this client that sends its requests through `transport`
+    pub fn with_transport(&self, transport: Arc<dyn Transport>) -> Self {
+        CexplorerClient {
+            transport: Some(transport),
+            ..self.clone()
+        }
+    }
 
-  
-    match serde_json::from_str::<T>(&text) {
-        Ok(data) => Ok(data),
-        Err(e) => {
-            eprintln!("JSON parsing error: {}", e);
-            eprintln!("Response body (first 5000 chars):\n{}", &text.chars().take(5000).collect::<String>());
-            Err(CexplorerError::JsonError(e))
+    /// Copy of this client whose requests time out after `timeout` instead of the
+    /// configured total, for slow endpoints:
+    /// `client.with_timeout(Duration::from_secs(120)).get_epoch_analytics()`
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        CexplorerClient {
+            timeout: Some(timeout),
+            ..self.clone()
         }
     }
-}
\ No newline at end of file
+
+    /// Copy of this client whose requests fail with `CexplorerError::Cancelled`
+    /// once `token` is cancelled, including requests waiting on a retry or the
+    /// rate limiter. Paginators and batches built from it stop at the next request.
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        CexplorerClient {
+            cancel: Some(token),
+            ..self.clone()
+        }
+    }
+
+    /// Copy of this client whose batches keep up to `concurrency` requests in flight
+    pub fn with_batch_concurrency(&self, concurrency: usize) -> Self {
+        CexplorerClient {
+            batch_concurrency: concurrency.max(1),
+            ..self.clone()
+        }
+    }
+
+    pub fn batch_concurrency(&self) -> usize {
+        self.batch_concurrency
+    }
+
+    /// Send `request` to its [`Endpoint`] and decode the response.
+    ///
+    /// Every endpoint method goes through here, so caching, retries, rate
+    /// limiting and cancellation apply the same way to all of them.
+    pub async fn call<E: Endpoint>(&self, request: E) -> Result<E::Response, CexplorerError> {
+        let query = endpoint_query(&request)?;
+        let path = E::PATH;
+
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::field::Empty;
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "cexplorer_request",
+                endpoint = path,
+                network = %self.config.network,
+                status = Empty,
+                latency_ms = Empty,
+                tokens = Empty,
+                ex = Empty,
+            );
+            self.execute(E::METHOD, path, &query).instrument(span).await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.execute(E::METHOD, path, &query).await
+    }
+
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+    ) -> Result<T, CexplorerError> {
+        match &self.cancel {
+            Some(token) => tokio::select! {
+                biased;
+                _ = token.cancelled() => Err(CexplorerError::Cancelled),
+                result = self.send_request(method, endpoint, query) => result,
+            },
+            None => self.send_request(method, endpoint, query).await,
+        }
+    }
+
+    async fn send_request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+    ) -> Result<T, CexplorerError> {
+        let config = &self.config;
+        #[cfg(feature = "tracing")]
+        let started = crate::runtime::Instant::now();
+
+        let base_url = config.base_url();
+        let mut url = format!("{}{}", base_url, endpoint);
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(query);
+        }
+
+        let cache_key = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.key(&base_url, endpoint, query));
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(body) = cache.lookup(key).await {
+                if let Ok(data) = serde_json::from_str::<T>(&body) {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("served from cache");
+                    return Ok(data);
+                }
+            }
+        }
+
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => config.transport()?,
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "api-key",
+            HeaderValue::from_str(&config.api_key).map_err(|_| CexplorerError::InvalidApiKey)?,
+        );
+
+        let attempts = self.retry.attempts_for(&method);
+        let mut attempt = 0;
+
+        let response = loop {
+            attempt += 1;
+
+            if let Some(limiter) = &self.limiter {
+                limiter.acquire().await?;
+            }
+
+            let request = HttpRequest {
+                method: method.clone(),
+                url: url.clone(),
+                headers: headers.clone(),
+                timeout: self.timeout,
+            };
+
+            let sent = match self.timeout {
+                Some(timeout) => runtime::timeout(timeout, transport.send(request))
+                    .await
+                    .unwrap_or(Err(CexplorerError::Timeout)),
+                None => transport.send(request).await,
+            };
+
+            match sent {
+                Ok(response) => {
+                    let status = response.status;
+                    if attempt < attempts && RetryPolicy::is_retryable_status(status) {
+                        let delay = self.retry
+                            .retry_after(status, &response.headers)
+                            .unwrap_or_else(|| self.retry.backoff(attempt));
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(attempt, status = status.as_u16(), ?delay, "retrying request");
+                        runtime::sleep(delay).await;
+                        continue;
+                    }
+                    break response;
+                }
+                Err(e) => {
+                    if attempt < attempts && RetryPolicy::is_retryable_error(&e) {
+                        let delay = self.retry.backoff(attempt);
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(attempt, error = %e, ?delay, "retrying request");
+                        runtime::sleep(delay).await;
+                        continue;
+                    }
+                    return Err(e);
+                }
+            }
+        };
+
+        let status = response.status;
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("status", status.as_u16());
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+        }
+
+        if !status.is_success() {
+            let retry_after = parse_retry_after(&response.headers);
+            let body = response.text();
+            #[cfg(feature = "tracing")]
+            tracing::debug!(body = %snippet(&body), "error response body");
+            return Err(status_error(endpoint, status, retry_after, &body));
+        }
+
+        let text = response.text();
+
+        let envelope = serde_json::from_str::<ApiStatus>(&text).ok();
+        #[cfg(feature = "tracing")]
+        if let Some(envelope) = &envelope {
+            let span = tracing::Span::current();
+            if let Some(tokens) = envelope.tokens.as_ref().and_then(Value::as_f64) {
+                span.record("tokens", tokens);
+            }
+            if let Some(ex) = envelope.ex.as_ref().and_then(Value::as_f64) {
+                span.record("ex", ex);
+            }
+        }
+
+        let tokens = envelope
+            .as_ref()
+            .and_then(|envelope| envelope.tokens.as_ref())
+            .and_then(Value::as_f64);
+
+        if let (Some(limiter), Some(tokens)) = (&self.limiter, tokens) {
+            limiter.record_tokens(tokens);
+        }
+
+        if let Some(envelope) = envelope {
+            envelope.check(endpoint)?;
+        }
+
+        match serde_json::from_str::<T>(&text) {
+            Ok(data) => {
+                if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+                    cache.store(key, endpoint, &text, tokens.unwrap_or_default()).await;
+                }
+                Ok(data)
+            }
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                {
+                    tracing::warn!(error = %e, "failed to deserialize response");
+                    tracing::debug!(body = %snippet(&text), "response body");
+                }
+                Err(CexplorerError::Deserialize {
+                    endpoint: endpoint.to_string(),
+                    snippet: snippet(&text),
+                    source: e,
+                })
+            }
+        }
+    }
+}
+
+/// Status, message and cost fields shared by every response envelope
+#[derive(Deserialize)]
+struct ApiStatus {
+    #[serde(default)]
+    code: Option<Value>,
+    #[serde(default)]
+    msg: Option<String>,
+    #[serde(default)]
+    tokens: Option<Value>,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    #[serde(default)]
+    ex: Option<Value>,
+}
+
+impl ApiStatus {
+    /// Reject 200 responses whose envelope carries an error code.
+    ///
+    /// The API reports success as `code: 200` (some endpoints send `0`).
+    fn check(self, endpoint: &str) -> Result<(), CexplorerError> {
+        let Some(code) = self.code.as_ref().and_then(Value::as_f64).map(|c| c as i64) else {
+            return Ok(());
+        };
+
+        if code == 0 || (200..300).contains(&code) {
+            return Ok(());
+        }
+
+        Err(CexplorerError::Api {
+            endpoint: endpoint.to_string(),
+            code,
+            message: self.msg,
+        })
+    }
+}
+
+/// Query string of `request`: its own fields, then the endpoint's fixed pairs
+fn endpoint_query<E: Endpoint>(request: &E) -> Result<String, CexplorerError> {
+    let invalid = |e: serde_urlencoded::ser::Error| CexplorerError::InvalidQuery(e.to_string());
+    let mut query = serde_urlencoded::to_string(request).map_err(invalid)?;
+    if !E::QUERY.is_empty() {
+        if !query.is_empty() {
+            query.push('&');
+        }
+        query.push_str(&serde_urlencoded::to_string(E::QUERY).map_err(invalid)?);
+    }
+    Ok(query)
+}
+
+fn snippet(text: &str) -> String {
+    text.chars().take(SNIPPET_LEN).collect()
+}
+
+fn status_error(
+    endpoint: &str,
+    status: StatusCode,
+    retry_after: Option<Duration>,
+    body: &str,
+) -> CexplorerError {
+    let body = snippet(body);
+    match status {
+        StatusCode::UNAUTHORIZED => CexplorerError::Unauthorized { body },
+        StatusCode::FORBIDDEN => CexplorerError::Forbidden { body },
+        StatusCode::NOT_FOUND => CexplorerError::NotFound {
+            endpoint: endpoint.to_string(),
+        },
+        StatusCode::TOO_MANY_REQUESTS => CexplorerError::RateLimited { retry_after },
+        s if s.is_server_error() => CexplorerError::ServerError {
+            status: s.as_u16(),
+            body,
+        },
+        s => CexplorerError::HttpStatus {
+            status: s.as_u16(),
+            body,
+        },
+    }
+}
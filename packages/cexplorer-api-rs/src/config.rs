@@ -1,37 +1,247 @@
 use once_cell::sync::Lazy;
-use std::sync::Mutex;
+use std::sync::{Arc, RwLock};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
 use serde::{Deserialize, Serialize};
+use crate::client::CexplorerClient;
 use crate::error::CexplorerError;
+use crate::network::Network;
+use crate::rate_limit::RateLimit;
+use crate::retry::RetryPolicy;
+#[cfg(target_arch = "wasm32")]
+use crate::fetch::FetchTransport;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::transport::ReqwestTransport;
+use crate::transport::Transport;
+
+/// Settings for the shared HTTP client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpClientOptions {
+    /// Total time allowed for a request, from connecting until the body is read
+    pub timeout_secs: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    /// Longest pause allowed between reads of the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_max_idle_per_host: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_idle_timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub http2_prior_knowledge: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl Default for HttpClientOptions {
+    fn default() -> Self {
+        HttpClientOptions {
+            timeout_secs: 30,
+            connect_timeout_secs: None,
+            read_timeout_secs: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout_secs: None,
+            http2_prior_knowledge: false,
+            proxy: None,
+            user_agent: Some(concat!("cexplorer-api-rs/", env!("CARGO_PKG_VERSION")).to_string()),
+        }
+    }
+}
+
+impl HttpClientOptions {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn build(&self) -> Result<Client, CexplorerError> {
+        let mut builder = Client::builder().timeout(Duration::from_secs(self.timeout_secs));
+
+        if let Some(connect_timeout) = self.connect_timeout_secs {
+            builder = builder.connect_timeout(Duration::from_secs(connect_timeout));
+        }
+
+        if let Some(read_timeout) = self.read_timeout_secs {
+            builder = builder.read_timeout(Duration::from_secs(read_timeout));
+        }
+
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+
+        if let Some(idle_timeout) = self.pool_idle_timeout_secs {
+            builder = builder.pool_idle_timeout(Duration::from_secs(idle_timeout));
+        }
+
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// The browser owns timeouts, pooling and proxies, so the options are ignored on `wasm32`
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(&self) -> Result<Client, CexplorerError> {
+        Ok(Client::builder().build()?)
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CexplorerConfig {
-    pub network: String,
+    pub network: Network,
     pub api_key: String,
+    /// Overrides the URL derived from `network`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub http: HttpClientOptions,
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Client-side throttling, usually taken from the account's API plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
+    /// Pooled client shared by every request made with this config
+    #[serde(skip)]
+    http_client: Option<Client>,
+    /// Replaces the reqwest transport built from `http_client`
+    #[serde(skip)]
+    transport: Option<Arc<dyn Transport>>,
 }
 
-static CONFIG: Lazy<Mutex<Option<CexplorerConfig>>> = Lazy::new(|| Mutex::new(None));
+impl CexplorerConfig {
+    pub fn new(network: &str, api_key: &str) -> Result<Self, CexplorerError> {
+        Self::from_network(network.parse()?, api_key)
+    }
 
-pub fn init_api(network: &str, api_key: &str) -> Result<(), CexplorerError> {
-    if network.is_empty() {
-        return Err(CexplorerError::MissingField("network".to_string()));
+    pub fn from_network(network: Network, api_key: &str) -> Result<Self, CexplorerError> {
+        if api_key.is_empty() {
+            return Err(CexplorerError::MissingField("api_key".to_string()));
+        }
+
+        let http = HttpClientOptions::default();
+        let http_client = http.build()?;
+
+        Ok(CexplorerConfig {
+            network,
+            api_key: api_key.to_string(),
+            base_url: None,
+            http,
+            retry: RetryPolicy::default(),
+            rate_limit: None,
+            http_client: Some(http_client),
+            transport: None,
+        })
     }
 
-    if api_key.is_empty() {
-        return Err(CexplorerError::MissingField("api_key".to_string()));
+    /// Send requests to `base_url` (e.g. a proxy or mock server) instead of the network's URL
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.trim_end_matches('/').to_string());
+        self
     }
 
-    let config = CexplorerConfig {
-        network: network.to_string(),
-        api_key: api_key.to_string(),
-    };
+    pub fn base_url(&self) -> String {
+        match &self.base_url {
+            Some(url) => url.clone(),
+            None => self.network.base_url(),
+        }
+    }
 
-    let mut cfg = CONFIG.lock().unwrap();
-    *cfg = Some(config);
+    /// Rebuild the shared HTTP client with the given options
+    pub fn with_http_options(mut self, http: HttpClientOptions) -> Result<Self, CexplorerError> {
+        self.http_client = Some(http.build()?);
+        self.http = http;
+        Ok(self)
+    }
 
+    /// Use an existing `reqwest::Client` instead of building one from `http`
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Send requests through `transport` instead of reqwest; `http` options no longer apply
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// The shared HTTP client, built from `http` if the config was deserialized
+    pub fn http_client(&self) -> Result<Client, CexplorerError> {
+        match &self.http_client {
+            Some(client) => Ok(client.clone()),
+            None => self.http.build(),
+        }
+    }
+
+    /// The custom transport if one was set, otherwise reqwest over the shared HTTP client
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn transport(&self) -> Result<Arc<dyn Transport>, CexplorerError> {
+        match &self.transport {
+            Some(transport) => Ok(transport.clone()),
+            None => Ok(Arc::new(ReqwestTransport::new(self.http_client()?))),
+        }
+    }
+
+    /// The custom transport if one was set, otherwise the browser's `fetch`
+    #[cfg(target_arch = "wasm32")]
+    pub fn transport(&self) -> Result<Arc<dyn Transport>, CexplorerError> {
+        match &self.transport {
+            Some(transport) => Ok(transport.clone()),
+            None => Ok(Arc::new(FetchTransport::new())),
+        }
+    }
+
+    pub(crate) fn ensure_http_client(&mut self) {
+        if self.http_client.is_none() {
+            self.http_client = self.http.build().ok();
+        }
+    }
+}
+
+/// Client used by the free endpoint functions, set by `init_api`
+static DEFAULT_CLIENT: Lazy<RwLock<Option<CexplorerClient>>> = Lazy::new(|| RwLock::new(None));
+
+pub fn init_api(network: &str, api_key: &str) -> Result<(), CexplorerError> {
+    init_api_with_config(CexplorerConfig::new(network, api_key)?);
     Ok(())
 }
 
+pub fn init_api_with_config(config: CexplorerConfig) {
+    init_api_with_client(CexplorerClient::new(config));
+}
+
+/// Use a preconfigured client (cache, rate limiter, ...) for the free endpoint functions
+pub fn init_api_with_client(client: CexplorerClient) {
+    let mut default = DEFAULT_CLIENT.write().unwrap();
+    *default = Some(client);
+}
+
 pub fn get_config() -> Result<CexplorerConfig, CexplorerError> {
-    let cfg = CONFIG.lock().unwrap();
-    cfg.clone().ok_or(CexplorerError::NotInitialized)
-}
\ No newline at end of file
+    default_client().map(|client| client.config().clone())
+}
+
+pub(crate) fn default_client() -> Result<CexplorerClient, CexplorerError> {
+    let default = DEFAULT_CLIENT.read().unwrap();
+    default.clone().ok_or(CexplorerError::NotInitialized)
+}
@@ -1,86 +1,137 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::account_types::*;
+use crate::ids::StakeAddress;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AccountRewardsParams {
-    pub view: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountRewardsRequest {
+    pub view: StakeAddress,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ViewParams {
+impl AccountRewardsRequest {
+    pub fn new(view: StakeAddress) -> Self {
+        AccountRewardsRequest {
+            view,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(AccountRewardsRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckDelegationRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub view: Option<String>,
+    pub view: Option<StakeAddress>,
+}
+
+impl CheckDelegationRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct WithdrawalParams {
-    pub view: String,
+request_setters!(CheckDelegationRequest { view: StakeAddress });
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalsRequest {
+    pub view: StakeAddress,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DelegationVoteParams {
+impl WithdrawalsRequest {
+    pub fn new(view: StakeAddress) -> Self {
+        WithdrawalsRequest {
+            view,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(WithdrawalsRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DelegationVoteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
+impl DelegationVoteRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(DelegationVoteRequest { limit: u64, offset: u64 });
+
+endpoint!(AccountRewardsRequest => AccountRewardResponse, "/account/reward");
+endpoint!(CheckDelegationRequest => CheckDelegationResponse, "/account/has_delegation");
+endpoint!(WithdrawalsRequest => WithdrawalsResponse, "/account/withdrawal");
+endpoint!(DelegationVoteRequest => DrepDelegationResponse, "/account/delegation_vote");
+
+impl CexplorerClient {
+    pub async fn get_account_rewards(
+        &self,
+        request: AccountRewardsRequest,
+    ) -> Result<AccountRewardResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn check_user_delegation(
+        &self,
+        view: Option<&StakeAddress>,
+    ) -> Result<CheckDelegationResponse, CexplorerError> {
+        self.call(CheckDelegationRequest { view: view.cloned() }).await
+    }
+
+    pub async fn get_withdrawals(
+        &self,
+        request: WithdrawalsRequest,
+    ) -> Result<WithdrawalsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_delegation_vote(
+        &self,
+        request: DelegationVoteRequest,
+    ) -> Result<DrepDelegationResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
 pub async fn get_account_rewards(
-    view: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: AccountRewardsRequest,
 ) -> Result<AccountRewardResponse, CexplorerError> {
-    let endpoint = "/account/reward";
-    let params = AccountRewardsParams {
-        view: view.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<AccountRewardResponse, AccountRewardsParams>(endpoint, Some(&params)).await
+    default_client()?.get_account_rewards(request).await
 }
 
 pub async fn check_user_delegation(
-    view: Option<&str>,
+    view: Option<&StakeAddress>,
 ) -> Result<CheckDelegationResponse, CexplorerError> {
-    let endpoint = "/account/has_delegation";
-    let params = ViewParams {
-        view: view.map(|s| s.to_string()),
-    };
-    fetch_with_params::<CheckDelegationResponse, ViewParams>(endpoint, Some(&params)).await
+    default_client()?.check_user_delegation(view).await
 }
 
 pub async fn get_withdrawals(
-    view: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: WithdrawalsRequest,
 ) -> Result<WithdrawalsResponse, CexplorerError> {
-    let endpoint = "/account/withdrawal";
-    let params = WithdrawalParams {
-        view: view.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<WithdrawalsResponse, WithdrawalParams>(endpoint, Some(&params)).await
+    default_client()?.get_withdrawals(request).await
 }
 
 pub async fn get_delegation_vote(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: DelegationVoteRequest,
 ) -> Result<DrepDelegationResponse, CexplorerError> {
-    let endpoint = "/account/delegation_vote";
-    let params = DelegationVoteParams {
-        limit,
-        offset,
-    };
-    fetch_with_params::<DrepDelegationResponse, DelegationVoteParams>(endpoint, Some(&params)).await
+    default_client()?.get_delegation_vote(request).await
 }
@@ -1,43 +1,97 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::{
     AddressDetailResponse, AddressDetailUTXOResponse,
     AddressListResponse, AddressInspectorResponse,
 };
-use serde::Serialize;
+use crate::ids::Address;
+use crate::params::AddressListOrder;
+use serde::{Deserialize, Serialize};
 
-/// Get detailed information for a specific address
-pub async fn get_address_detail(view: &str) -> Result<AddressDetailResponse, CexplorerError> {
-    let endpoint = format!("/address/detail?view={}", view);
-    fetch::<AddressDetailResponse>(&endpoint).await
-}
-
-#[derive(Debug, Serialize)]
-pub struct AddressListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddressListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_cred: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub view: Option<String>,
+    pub view: Option<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<AddressListOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub watchlist_only: Option<String>,
 }
 
+/// Former name of [`AddressListRequest`]
+#[deprecated(note = "renamed to `AddressListRequest`")]
+pub type AddressListParams = AddressListRequest;
+
+impl AddressListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(AddressListRequest {
+    payment_cred: String,
+    view: Address,
+    order: AddressListOrder,
+    watchlist_only: String,
+});
+
+endpoint!(pub struct AddressDetailRequest { view: Address } => AddressDetailResponse, "/address/detail");
+endpoint!(AddressListRequest => AddressListResponse, "/address/list");
+endpoint!(pub struct AddressUtxoRequest { view: Address } => AddressDetailUTXOResponse, "/address/utxo");
+endpoint!(pub struct InspectAddressRequest { view: String } => AddressInspectorResponse, "/address/extract");
+
+impl CexplorerClient {
+    /// Get detailed information for a specific address
+    pub async fn get_address_detail(&self, view: &Address) -> Result<AddressDetailResponse, CexplorerError> {
+        self.call(AddressDetailRequest::new(view.clone())).await
+    }
+
+    /// Details of every distinct address in `views`; see [`call_batch`](Self::call_batch)
+    pub async fn get_address_details(&self, views: &[Address]) -> BatchResults<Address, AddressDetailResponse> {
+        self.call_batch(views.iter().cloned(), |view| AddressDetailRequest::new(view.clone())).await
+    }
+
+    /// Get a list of addresses based on filters
+    pub async fn get_address_list(&self, request: AddressListRequest) -> Result<AddressListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    /// Get UTXOs (Unspent Transaction Outputs) for a given address
+    pub async fn get_address_utxo(&self, view: &Address) -> Result<AddressDetailUTXOResponse, CexplorerError> {
+        self.call(AddressUtxoRequest::new(view.clone())).await
+    }
+
+    /// Inspect and extract metadata from a Cardano address
+    pub async fn inspect_address(&self, view: &str) -> Result<AddressInspectorResponse, CexplorerError> {
+        self.call(InspectAddressRequest::new(view.to_string())).await
+    }
+}
+
+/// Get detailed information for a specific address
+pub async fn get_address_detail(view: &Address) -> Result<AddressDetailResponse, CexplorerError> {
+    default_client()?.get_address_detail(view).await
+}
+
+/// Details of every distinct address in `views`, fetched concurrently
+pub async fn get_address_details(views: &[Address]) -> Result<BatchResults<Address, AddressDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_address_details(views).await)
+}
+
 /// Get a list of addresses based on filters
-pub async fn get_address_list(params: AddressListParams) -> Result<AddressListResponse, CexplorerError> {
-    let endpoint = "/address/list";
-    fetch_with_params::<AddressListResponse, AddressListParams>(endpoint, Some(&params)).await
+pub async fn get_address_list(request: AddressListRequest) -> Result<AddressListResponse, CexplorerError> {
+    default_client()?.get_address_list(request).await
 }
 
 /// Get UTXOs (Unspent Transaction Outputs) for a given address
-pub async fn get_address_utxo(view: &str) -> Result<AddressDetailUTXOResponse, CexplorerError> {
-    let endpoint = format!("/address/utxo?view={}", view);
-    fetch::<AddressDetailUTXOResponse>(&endpoint).await
+pub async fn get_address_utxo(view: &Address) -> Result<AddressDetailUTXOResponse, CexplorerError> {
+    default_client()?.get_address_utxo(view).await
 }
 
 /// Inspect and extract metadata from a Cardano address
 pub async fn inspect_address(view: &str) -> Result<AddressInspectorResponse, CexplorerError> {
-    let endpoint = format!("/address/extract?view={}", view);
-    fetch::<AddressInspectorResponse>(&endpoint).await
+    default_client()?.inspect_address(view).await
 }
@@ -1,15 +1,36 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::analytics_types::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct EpochNoParams {
-    pub epoch_no: u64,
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TopStakingAccountsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drep_only: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_only: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AnalyticsAccountParams {
+impl TopStakingAccountsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(TopStakingAccountsRequest {
+    limit: u64,
+    offset: u64,
+    drep_only: flag,
+    pool_only: flag,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TopAddressesRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,85 +41,145 @@ pub struct AnalyticsAccountParams {
     pub pool_only: Option<u64>,
 }
 
+impl TopAddressesRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(TopAddressesRequest {
+    limit: u64,
+    offset: u64,
+    drep_only: flag,
+    pool_only: flag,
+});
+
+endpoint!(pub struct HardforksRequest => HardforkResponse, "/analytics/hardforks");
+endpoint!(
+    pub struct EpochAnalyticsRequest => EpochAnalyticsResponse,
+    "/analytics/epoch",
+    "display" = "sum_fee,count_tx,avg_tx_fee,block_version,tx_composition,max_block_tx_count,count_block,\
+                 count_tx_out,avg_block_size,max_block_size,count_tx_out_address,count_tx_out_stake,\
+                 count_tx_out_address_not_yesterday,count_tx_out_stake_not_yesterday",
+);
+endpoint!(
+    pub struct AnalyticsRateRequest => AnalyticsRateResponse,
+    "/analytics/rate",
+    "display" = "sum_fee,count_tx,avg_tx_fee,block_version,tx_composition,max_block_tx_count,count_tx_out,\
+                 count_block,avg_block_size,max_block_size,count_tx_out_address,count_tx_out_stake,\
+                 count_tx_out_address_not_yesterday,count_tx_out_stake_not_yesterday,count_pool_relay_uniq,\
+                 count_pool",
+);
+endpoint!(pub struct AnalyticsPoolBlockRequest { epoch_no: u64 } => AnalyticsPoolBlockResponse, "/analytics/pool_block");
+endpoint!(TopStakingAccountsRequest => AnalyticsTopStakingAccountsResponse, "/analytics/top_account");
+endpoint!(TopAddressesRequest => AnalyticsTopAddressesResponse, "/analytics/top_address");
+endpoint!(pub struct WealthCompositionRequest => WealthCompositionResponse, "/analytics/wealth");
+endpoint!(pub struct AdaPotsRequest => AnalyticsAdaPotsResponse, "/analytics/ada_pot");
+endpoint!(pub struct GroupListRequest => GroupsListResponse, "/analytics/group_list");
+endpoint!(pub struct GroupDetailRequest { id: String } => GroupDetailResponse, "/analytics/group_detail");
+endpoint!(pub struct AveragePoolRequest => AveragePoolResponse, "/analytics/avg_pool", "type" = "avg_num_per_pool");
+endpoint!(pub struct GenesisAddrRequest => GenesisAddrResponse, "/analytics/genesis_addr");
+
+impl CexplorerClient {
+    pub async fn get_hardforks(&self) -> Result<HardforkResponse, CexplorerError> {
+        self.call(HardforksRequest).await
+    }
+
+    pub async fn get_epoch_analytics(&self) -> Result<EpochAnalyticsResponse, CexplorerError> {
+        self.call(EpochAnalyticsRequest).await
+    }
+
+    pub async fn get_analytics_rate(&self) -> Result<AnalyticsRateResponse, CexplorerError> {
+        self.call(AnalyticsRateRequest).await
+    }
+
+    pub async fn get_analytics_pool_block(&self, epoch_no: u64) -> Result<AnalyticsPoolBlockResponse, CexplorerError> {
+        self.call(AnalyticsPoolBlockRequest::new(epoch_no)).await
+    }
+
+    pub async fn get_analytics_staking_accounts(
+        &self,
+        request: TopStakingAccountsRequest,
+    ) -> Result<AnalyticsTopStakingAccountsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_analytics_top_addresses(
+        &self,
+        request: TopAddressesRequest,
+    ) -> Result<AnalyticsTopAddressesResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_wealth_composition(&self) -> Result<WealthCompositionResponse, CexplorerError> {
+        self.call(WealthCompositionRequest).await
+    }
+
+    pub async fn get_ada_pots(&self) -> Result<AnalyticsAdaPotsResponse, CexplorerError> {
+        self.call(AdaPotsRequest).await
+    }
+
+    pub async fn get_group_list(&self) -> Result<GroupsListResponse, CexplorerError> {
+        self.call(GroupListRequest).await
+    }
+
+    pub async fn get_group_detail(&self, id: &str) -> Result<GroupDetailResponse, CexplorerError> {
+        self.call(GroupDetailRequest::new(id.to_string())).await
+    }
+
+    pub async fn get_average_pool(&self) -> Result<AveragePoolResponse, CexplorerError> {
+        self.call(AveragePoolRequest).await
+    }
+
+    pub async fn get_genesis_addr(&self) -> Result<GenesisAddrResponse, CexplorerError> {
+        self.call(GenesisAddrRequest).await
+    }
+}
+
 pub async fn get_hardforks() -> Result<HardforkResponse, CexplorerError> {
-    let endpoint = "/analytics/hardforks";
-    fetch::<HardforkResponse>(endpoint).await
+    default_client()?.get_hardforks().await
 }
 
 pub async fn get_epoch_analytics() -> Result<EpochAnalyticsResponse, CexplorerError> {
-    let endpoint = "/analytics/epoch?display=sum_fee,count_tx,avg_tx_fee,block_version,tx_composition,max_block_tx_count,count_block,count_tx_out,avg_block_size,max_block_size,count_tx_out_address,count_tx_out_stake,count_tx_out_address_not_yesterday,count_tx_out_stake_not_yesterday";
-    fetch::<EpochAnalyticsResponse>(endpoint).await
+    default_client()?.get_epoch_analytics().await
 }
 
 pub async fn get_analytics_rate() -> Result<AnalyticsRateResponse, CexplorerError> {
-    let endpoint = "/analytics/rate?display=sum_fee,count_tx,avg_tx_fee,block_version,tx_composition,max_block_tx_count,count_tx_out,count_block,avg_block_size,max_block_size,count_tx_out_address,count_tx_out_stake,count_tx_out_address_not_yesterday,count_tx_out_stake_not_yesterday,count_pool_relay_uniq,count_pool";
-    fetch::<AnalyticsRateResponse>(endpoint).await
+    default_client()?.get_analytics_rate().await
 }
 
 pub async fn get_analytics_pool_block(epoch_no: u64) -> Result<AnalyticsPoolBlockResponse, CexplorerError> {
-    let endpoint = "/analytics/pool_block";
-    let params = EpochNoParams { epoch_no };
-    fetch_with_params::<AnalyticsPoolBlockResponse, EpochNoParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_analytics_staking_accounts(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    drep_only: Option<u64>,
-    pool_only: Option<u64>,
-) -> Result<AnalyticsTopStakingAccountsResponse, CexplorerError> {
-    let endpoint = "/analytics/top_account";
-    let params = AnalyticsAccountParams {
-        limit,
-        offset,
-        drep_only,
-        pool_only,
-    };
-    fetch_with_params::<AnalyticsTopStakingAccountsResponse, AnalyticsAccountParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_analytics_top_addresses(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    drep_only: Option<u64>,
-    pool_only: Option<u64>,
-) -> Result<AnalyticsTopAddressesResponse, CexplorerError> {
-    let endpoint = "/analytics/top_address";
-    let params = AnalyticsAccountParams {
-        limit,
-        offset,
-        drep_only,
-        pool_only,
-    };
-    fetch_with_params::<AnalyticsTopAddressesResponse, AnalyticsAccountParams>(endpoint, Some(&params)).await
+    default_client()?.get_analytics_pool_block(epoch_no).await
+}
+
+pub async fn get_analytics_staking_accounts(request: TopStakingAccountsRequest) -> Result<AnalyticsTopStakingAccountsResponse, CexplorerError> {
+    default_client()?.get_analytics_staking_accounts(request).await
+}
+
+pub async fn get_analytics_top_addresses(request: TopAddressesRequest) -> Result<AnalyticsTopAddressesResponse, CexplorerError> {
+    default_client()?.get_analytics_top_addresses(request).await
 }
 
 pub async fn get_wealth_composition() -> Result<WealthCompositionResponse, CexplorerError> {
-    let endpoint = "/analytics/wealth";
-    fetch::<WealthCompositionResponse>(endpoint).await
+    default_client()?.get_wealth_composition().await
 }
 
 pub async fn get_ada_pots() -> Result<AnalyticsAdaPotsResponse, CexplorerError> {
-    let endpoint = "/analytics/ada_pot";
-    fetch::<AnalyticsAdaPotsResponse>(endpoint).await
+    default_client()?.get_ada_pots().await
 }
 
 pub async fn get_group_list() -> Result<GroupsListResponse, CexplorerError> {
-    let endpoint = "/analytics/group_list";
-    fetch::<GroupsListResponse>(endpoint).await
+    default_client()?.get_group_list().await
 }
 
 pub async fn get_group_detail(id: &str) -> Result<GroupDetailResponse, CexplorerError> {
-    let endpoint = format!("/analytics/group_detail?id={}", id);
-    fetch::<GroupDetailResponse>(&endpoint).await
+    default_client()?.get_group_detail(id).await
 }
 
 pub async fn get_average_pool() -> Result<AveragePoolResponse, CexplorerError> {
-    let endpoint = "/analytics/avg_pool?type=avg_num_per_pool";
-    fetch::<AveragePoolResponse>(endpoint).await
+    default_client()?.get_average_pool().await
 }
 
 pub async fn get_genesis_addr() -> Result<GenesisAddrResponse, CexplorerError> {
-    let endpoint = "/analytics/genesis_addr";
-    fetch::<GenesisAddrResponse>(endpoint).await
+    default_client()?.get_genesis_addr().await
 }
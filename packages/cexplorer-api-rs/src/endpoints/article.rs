@@ -1,54 +1,76 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::article_types::*;
+use crate::params::ArticleType;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ArticleDetailParams {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleDetailRequest {
     pub lng: String,
     #[serde(rename = "type")]
-    pub article_type: String,
+    pub article_type: ArticleType,
     pub url: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ArticleListParams {
+impl ArticleDetailRequest {
+    pub fn new(lng: impl Into<String>, article_type: ArticleType, url: impl Into<String>) -> Self {
+        ArticleDetailRequest {
+            lng: lng.into(),
+            article_type,
+            url: url.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleListRequest {
     pub lng: String,
     #[serde(rename = "type")]
-    pub article_type: String,
+    pub article_type: ArticleType,
     pub limit: u64,
     pub offset: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
 }
 
-pub async fn get_article_detail(
-    lng: &str,
-    article_type: &str,
-    url: &str,
-) -> Result<ArticleDetailResponse, CexplorerError> {
-    let endpoint = "/article/detail";
-    let params = ArticleDetailParams {
-        lng: lng.to_string(),
-        article_type: article_type.to_string(),
-        url: url.to_string(),
-    };
-    fetch_with_params::<ArticleDetailResponse, ArticleDetailParams>(endpoint, Some(&params)).await
+impl ArticleListRequest {
+    pub fn new(lng: impl Into<String>, limit: u64, offset: u64) -> Self {
+        ArticleListRequest {
+            lng: lng.into(),
+            article_type: ArticleType::Article,
+            limit,
+            offset,
+            category: None,
+        }
+    }
+}
+
+request_setters!(ArticleListRequest { category: String });
+
+endpoint!(ArticleDetailRequest => ArticleDetailResponse, "/article/detail");
+endpoint!(ArticleListRequest => ArticleListResponse, "/article/list");
+
+impl CexplorerClient {
+    pub async fn get_article_detail(
+        &self,
+        request: ArticleDetailRequest,
+    ) -> Result<ArticleDetailResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_article_list(
+        &self,
+        request: ArticleListRequest,
+    ) -> Result<ArticleListResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_article_detail(request: ArticleDetailRequest) -> Result<ArticleDetailResponse, CexplorerError> {
+    default_client()?.get_article_detail(request).await
 }
 
-pub async fn get_article_list(
-    lng: &str,
-    offset: u64,
-    limit: u64,
-    category: Option<&str>,
-) -> Result<ArticleListResponse, CexplorerError> {
-    let endpoint = "/article/list";
-    let params = ArticleListParams {
-        lng: lng.to_string(),
-        article_type: "article".to_string(),
-        limit,
-        offset,
-        category: category.map(|s| s.to_string()),
-    };
-    fetch_with_params::<ArticleListResponse, ArticleListParams>(endpoint, Some(&params)).await
+pub async fn get_article_list(request: ArticleListRequest) -> Result<ArticleListResponse, CexplorerError> {
+    default_client()?.get_article_list(request).await
 }
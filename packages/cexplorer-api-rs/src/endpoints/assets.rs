@@ -1,148 +1,205 @@
-use crate::client::fetch_with_params;
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::assets_types::*;
+use crate::ids::{AssetFingerprint, PolicyId};
+use crate::params::{AssetFilter, AssetListOrder, SortDirection};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<AssetListOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy: Option<String>,
+    pub policy: Option<PolicyId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<String>,
+    pub filter: Option<AssetFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub watchlist_only: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetDetailParams {
-    pub fingerprint: String,
+impl AssetListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetOwnerParams {
+request_setters!(AssetListRequest {
+    limit: u64,
+    offset: u64,
+    sort: SortDirection,
+    order: AssetListOrder,
+    policy: PolicyId,
+    name: String,
+    filter: AssetFilter,
+    watchlist_only: String,
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetOwnersRequest {
     pub assetname: String,
     pub offset: u64,
     pub limit: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetMetadataParams {
+impl AssetOwnersRequest {
+    pub fn new(assetname: impl Into<String>, limit: u64, offset: u64) -> Self {
+        AssetOwnersRequest {
+            assetname: assetname.into(),
+            offset,
+            limit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetOwnerHistoryRequest {
     pub assetname: String,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl AssetOwnerHistoryRequest {
+    pub fn new(assetname: impl Into<String>, limit: u64, offset: u64) -> Self {
+        AssetOwnerHistoryRequest {
+            assetname: assetname.into(),
+            offset,
+            limit,
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetMintParams {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetMintRequest {
     pub assetname: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AssetStatsParams {
+impl AssetMintRequest {
+    pub fn new(assetname: impl Into<String>) -> Self {
+        AssetMintRequest {
+            assetname: assetname.into(),
+            id: None,
+        }
+    }
+}
+
+request_setters!(AssetMintRequest { id: String });
+
+/// Looks an asset up by either its hex asset name or its fingerprint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetStatsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assetname: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fingerprint: Option<String>,
-}
-
-pub async fn get_asset_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    filter: Option<&str>,
-    name: Option<&str>,
-    order: Option<&str>,
-    policy: Option<&str>,
-    sort: Option<&str>,
-    watchlist: Option<&str>,
-) -> Result<AssetListResponse, CexplorerError> {
-    let endpoint = "/asset/list";
-    let params = AssetListParams {
-        limit,
-        offset,
-        sort: sort.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-        policy: policy.map(|s| s.to_string()),
-        name: name.map(|s| s.to_string()),
-        filter: filter.map(|s| s.to_string()),
-        watchlist_only: watchlist.map(|s| s.to_string()),
-    };
-    fetch_with_params::<AssetListResponse, AssetListParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_asset_detail(fingerprint: &str) -> Result<AssetDetailResponse, CexplorerError> {
-    let endpoint = "/asset/detail";
-    let params = AssetDetailParams {
-        fingerprint: fingerprint.to_string(),
-    };
-    fetch_with_params::<AssetDetailResponse, AssetDetailParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_asset_owners(
-    assetname: &str,
-    offset: u64,
-    limit: u64,
-) -> Result<AssetOwnersNftResponse, CexplorerError> {
-    let endpoint = "/asset/owner";
-    let params = AssetOwnerParams {
-        assetname: assetname.to_string(),
-        offset,
-        limit,
-    };
-    fetch_with_params::<AssetOwnersNftResponse, AssetOwnerParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_nft_asset_owners(
-    assetname: &str,
-    offset: u64,
-    limit: u64,
-) -> Result<AssetOwnersNftResponse, CexplorerError> {
-    let endpoint = "/asset/owner_history";
-    let params = AssetOwnerParams {
-        assetname: assetname.to_string(),
-        offset,
-        limit,
-    };
-    fetch_with_params::<AssetOwnersNftResponse, AssetOwnerParams>(endpoint, Some(&params)).await
+    pub fingerprint: Option<AssetFingerprint>,
+}
+
+impl AssetStatsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(AssetStatsRequest { assetname: String, fingerprint: AssetFingerprint });
+
+endpoint!(AssetListRequest => AssetListResponse, "/asset/list");
+endpoint!(pub struct AssetDetailRequest { fingerprint: AssetFingerprint } => AssetDetailResponse, "/asset/detail");
+endpoint!(AssetOwnersRequest => AssetOwnersNftResponse, "/asset/owner");
+endpoint!(AssetOwnerHistoryRequest => AssetOwnersNftResponse, "/asset/owner_history");
+endpoint!(pub struct AssetMetadataRequest { assetname: String } => AssetMetadataResponse, "/asset/metadata");
+endpoint!(AssetMintRequest => AssetMintResponse, "/policy/mint");
+endpoint!(AssetStatsRequest => AssetStatsResponse, "/asset/stat");
+
+impl CexplorerClient {
+    pub async fn get_asset_list(
+        &self,
+        request: AssetListRequest,
+    ) -> Result<AssetListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_asset_detail(&self, fingerprint: &AssetFingerprint) -> Result<AssetDetailResponse, CexplorerError> {
+        self.call(AssetDetailRequest::new(fingerprint.clone())).await
+    }
+
+    /// Details of every distinct asset in `fingerprints`; see [`call_batch`](Self::call_batch)
+    pub async fn get_asset_details(&self, fingerprints: &[AssetFingerprint]) -> BatchResults<AssetFingerprint, AssetDetailResponse> {
+        self.call_batch(fingerprints.iter().cloned(), |fingerprint| AssetDetailRequest::new(fingerprint.clone())).await
+    }
+
+    pub async fn get_asset_owners(
+        &self,
+        request: AssetOwnersRequest,
+    ) -> Result<AssetOwnersNftResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_nft_asset_owners(
+        &self,
+        request: AssetOwnerHistoryRequest,
+    ) -> Result<AssetOwnersNftResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_asset_metadata(&self, assetname: &str) -> Result<AssetMetadataResponse, CexplorerError> {
+        self.call(AssetMetadataRequest::new(assetname.to_string())).await
+    }
+
+    pub async fn get_asset_mint(
+        &self,
+        request: AssetMintRequest,
+    ) -> Result<AssetMintResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_asset_stats(
+        &self,
+        request: AssetStatsRequest,
+    ) -> Result<AssetStatsResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_asset_list(request: AssetListRequest) -> Result<AssetListResponse, CexplorerError> {
+    default_client()?.get_asset_list(request).await
+}
+
+pub async fn get_asset_detail(fingerprint: &AssetFingerprint) -> Result<AssetDetailResponse, CexplorerError> {
+    default_client()?.get_asset_detail(fingerprint).await
+}
+
+/// Details of every distinct asset in `fingerprints`, fetched concurrently
+pub async fn get_asset_details(fingerprints: &[AssetFingerprint]) -> Result<BatchResults<AssetFingerprint, AssetDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_asset_details(fingerprints).await)
+}
+
+pub async fn get_asset_owners(request: AssetOwnersRequest) -> Result<AssetOwnersNftResponse, CexplorerError> {
+    default_client()?.get_asset_owners(request).await
+}
+
+pub async fn get_nft_asset_owners(request: AssetOwnerHistoryRequest) -> Result<AssetOwnersNftResponse, CexplorerError> {
+    default_client()?.get_nft_asset_owners(request).await
 }
 
 pub async fn get_asset_metadata(assetname: &str) -> Result<AssetMetadataResponse, CexplorerError> {
-    let endpoint = "/asset/metadata";
-    let params = AssetMetadataParams {
-        assetname: assetname.to_string(),
-    };
-    fetch_with_params::<AssetMetadataResponse, AssetMetadataParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_asset_mint(
-    assetname: &str,
-    id: Option<&str>,
-) -> Result<AssetMintResponse, CexplorerError> {
-    let endpoint = "/policy/mint";
-    let params = AssetMintParams {
-        assetname: assetname.to_string(),
-        id: id.map(|s| s.to_string()),
-    };
-    fetch_with_params::<AssetMintResponse, AssetMintParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_asset_stats(
-    assetname: Option<&str>,
-    fingerprint: Option<&str>,
-) -> Result<AssetStatsResponse, CexplorerError> {
-    let endpoint = "/asset/stat";
-    let params = AssetStatsParams {
-        assetname: assetname.map(|s| s.to_string()),
-        fingerprint: fingerprint.map(|s| s.to_string()),
-    };
-    fetch_with_params::<AssetStatsResponse, AssetStatsParams>(endpoint, Some(&params)).await
+    default_client()?.get_asset_metadata(assetname).await
+}
+
+pub async fn get_asset_mint(request: AssetMintRequest) -> Result<AssetMintResponse, CexplorerError> {
+    default_client()?.get_asset_mint(request).await
+}
+
+pub async fn get_asset_stats(request: AssetStatsRequest) -> Result<AssetStatsResponse, CexplorerError> {
+    default_client()?.get_asset_stats(request).await
 }
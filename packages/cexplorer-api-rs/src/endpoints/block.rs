@@ -1,32 +1,76 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::{BlockDetailResponse, BlocksListResponse};
-use serde::Serialize;
+use crate::ids::{BlockHash, PoolId};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
-pub struct BlockListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BlockListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pool_id: Option<String>,
+    pub pool_id: Option<PoolId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub epoch_no: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash: Option<String>,
+    pub hash: Option<BlockHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slot_no: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub block_no: Option<u64>,
 }
 
-pub async fn get_block_list(params: BlockListParams) -> Result<BlocksListResponse, CexplorerError> {
-    let endpoint = "/block/list";
-    fetch_with_params::<BlocksListResponse, BlockListParams>(endpoint, Some(&params)).await
+/// Former name of [`BlockListRequest`]
+#[deprecated(note = "renamed to `BlockListRequest`")]
+pub type BlockListParams = BlockListRequest;
+
+impl BlockListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(BlockListRequest {
+    limit: u64,
+    offset: u64,
+    pool_id: PoolId,
+    epoch_no: u64,
+    hash: BlockHash,
+    slot_no: u64,
+    block_no: u64,
+});
+
+endpoint!(BlockListRequest => BlocksListResponse, "/block/list");
+endpoint!(pub struct BlockDetailRequest { hash: BlockHash } => BlockDetailResponse, "/block/detail");
+
+impl CexplorerClient {
+    pub async fn get_block_list(&self, request: BlockListRequest) -> Result<BlocksListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_block_detail(&self, hash: &BlockHash) -> Result<BlockDetailResponse, CexplorerError> {
+        self.call(BlockDetailRequest::new(hash.clone())).await
+    }
+
+    /// Details of every distinct block in `hashes`; see [`call_batch`](Self::call_batch)
+    pub async fn get_block_details(&self, hashes: &[BlockHash]) -> BatchResults<BlockHash, BlockDetailResponse> {
+        self.call_batch(hashes.iter().cloned(), |hash| BlockDetailRequest::new(hash.clone())).await
+    }
+}
+
+pub async fn get_block_list(request: BlockListRequest) -> Result<BlocksListResponse, CexplorerError> {
+    default_client()?.get_block_list(request).await
 }
 
-pub async fn get_block_detail(hash: &str) -> Result<BlockDetailResponse, CexplorerError> {
-    let endpoint = format!("/block/detail?hash={}", hash);
-    fetch::<BlockDetailResponse>(&endpoint).await
-}
\ No newline at end of file
+pub async fn get_block_detail(hash: &BlockHash) -> Result<BlockDetailResponse, CexplorerError> {
+    default_client()?.get_block_detail(hash).await
+}
+
+/// Details of every distinct block in `hashes`, fetched concurrently
+pub async fn get_block_details(hashes: &[BlockHash]) -> Result<BatchResults<BlockHash, BlockDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_block_details(hashes).await)
+}
@@ -1,17 +1,17 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::datum_types::*;
-use serde::{Deserialize, Serialize};
+use crate::ids::DatumHash;
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DatumDetailParams {
-    pub hash: String,
+endpoint!(pub struct DatumDetailRequest { hash: DatumHash } => DatumDetailResponse, "/datum/detail");
+
+impl CexplorerClient {
+    pub async fn get_datum_detail(&self, hash: &DatumHash) -> Result<DatumDetailResponse, CexplorerError> {
+        self.call(DatumDetailRequest::new(hash.clone())).await
+    }
 }
 
-pub async fn get_datum_detail(hash: &str) -> Result<DatumDetailResponse, CexplorerError> {
-    let endpoint = "/datum/detail";
-    let params = DatumDetailParams {
-        hash: hash.to_string(),
-    };
-    fetch_with_params::<DatumDetailResponse, DatumDetailParams>(endpoint, Some(&params)).await
+pub async fn get_datum_detail(hash: &DatumHash) -> Result<DatumDetailResponse, CexplorerError> {
+    default_client()?.get_datum_detail(hash).await
 }
@@ -1,60 +1,90 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::delegation_types::*;
+use crate::ids::StakeAddress;
+use crate::params::{RetiredOrder, StakeSnapshot};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DelegationParams {
-    pub view: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakeDelegationsRequest {
+    pub view: StakeAddress,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DelegationToRetiredParams {
+impl StakeDelegationsRequest {
+    pub fn new(view: StakeAddress) -> Self {
+        StakeDelegationsRequest {
+            view,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(StakeDelegationsRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DelegationsToRetiredRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<String>,
+    #[serde(rename = "type")]
+    pub delegation_type: Option<StakeSnapshot>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
-}
-
-pub async fn get_delegations_state(view: &str) -> Result<DelegationStateResponse, CexplorerError> {
-    let endpoint = format!("/account/delegation_state?view={}", view);
-    fetch::<DelegationStateResponse>(&endpoint).await
-}
-
-pub async fn get_stake_delegations(
-    view: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<DelegationResponse, CexplorerError> {
-    let endpoint = "/account/delegation";
-    let params = DelegationParams {
-        view: view.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<DelegationResponse, DelegationParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_delegations_to_retired(
-    delegation_type: Option<&str>,
-    limit: Option<u64>,
-    offset: Option<u64>,
-    order: Option<&str>,
-) -> Result<DelegationToRetiredResponse, CexplorerError> {
-    let endpoint = "/account/delegation_to_retired";
-    let params = DelegationToRetiredParams {
-        r#type: delegation_type.map(|s| s.to_string()),
-        limit,
-        offset,
-        order: order.map(|s| s.to_string()),
-    };
-    fetch_with_params::<DelegationToRetiredResponse, DelegationToRetiredParams>(endpoint, Some(&params)).await
+    pub order: Option<RetiredOrder>,
+}
+
+impl DelegationsToRetiredRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(DelegationsToRetiredRequest {
+    delegation_type: StakeSnapshot,
+    limit: u64,
+    offset: u64,
+    order: RetiredOrder,
+});
+
+endpoint!(StakeDelegationsRequest => DelegationResponse, "/account/delegation");
+endpoint!(DelegationsToRetiredRequest => DelegationToRetiredResponse, "/account/delegation_to_retired");
+endpoint!(pub struct DelegationsStateRequest { view: StakeAddress } => DelegationStateResponse, "/account/delegation_state");
+
+impl CexplorerClient {
+    pub async fn get_delegations_state(&self, view: &StakeAddress) -> Result<DelegationStateResponse, CexplorerError> {
+        self.call(DelegationsStateRequest::new(view.clone())).await
+    }
+
+    pub async fn get_stake_delegations(
+        &self,
+        request: StakeDelegationsRequest,
+    ) -> Result<DelegationResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_delegations_to_retired(
+        &self,
+        request: DelegationsToRetiredRequest,
+    ) -> Result<DelegationToRetiredResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_delegations_state(view: &StakeAddress) -> Result<DelegationStateResponse, CexplorerError> {
+    default_client()?.get_delegations_state(view).await
+}
+
+pub async fn get_stake_delegations(request: StakeDelegationsRequest) -> Result<DelegationResponse, CexplorerError> {
+    default_client()?.get_stake_delegations(request).await
+}
+
+pub async fn get_delegations_to_retired(request: DelegationsToRetiredRequest) -> Result<DelegationToRetiredResponse, CexplorerError> {
+    default_client()?.get_delegations_to_retired(request).await
 }
@@ -1,11 +1,15 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::drep_types::*;
 use crate::types::pool_types::{PoolDelegatorStatsResponse, DrepNotSpoSameTimeResponse};
+use crate::ids::DRepId;
+use crate::params::{DelegatorOrder, DrepDelegatorFilter, DrepListOrder, SortDirection, VoterRole};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DrepListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DrepListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -15,9 +19,9 @@ pub struct DrepListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub watchlist_only: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<DrepListOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gov_action: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,140 +30,212 @@ pub struct DrepListParams {
     pub is_not_spo: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DrepVoteParams {
-    pub voter_role: String,
+impl DrepListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(DrepListRequest {
+    limit: u64,
+    offset: u64,
+    view: String,
+    watchlist_only: String,
+    sort: SortDirection,
+    order: DrepListOrder,
+    gov_action: String,
+    is_spo: flag,
+    is_not_spo: flag,
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepVoteRequest {
+    pub voter_role: VoterRole,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DrepDelegatorParams {
-    pub view: String,
+impl DrepVoteRequest {
+    pub fn new(voter_role: VoterRole) -> Self {
+        DrepVoteRequest {
+            voter_role,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(DrepVoteRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepDelegatorRequest {
+    pub view: DRepId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<DelegatorOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<String>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ViewParams {
-    pub view: String,
+    pub filter: Option<DrepDelegatorFilter>,
+}
+
+impl DrepDelegatorRequest {
+    pub fn new(view: DRepId) -> Self {
+        DrepDelegatorRequest {
+            view,
+            limit: None,
+            offset: None,
+            order: None,
+            filter: None,
+        }
+    }
+}
+
+request_setters!(DrepDelegatorRequest {
+    limit: u64,
+    offset: u64,
+    order: DelegatorOrder,
+    filter: DrepDelegatorFilter,
+});
+
+endpoint!(pub struct DrepStatRequest => DrepStatResponse, "/gov/stat");
+endpoint!(pub struct DrepAnalyticsRequest => DrepAnalyticsResponse, "/gov/drep_analytics");
+endpoint!(pub struct StakeDrepRetiredRequest => StakeDrepRetiredResponse, "/analytics/stake", "type" = "stake_drep_retired");
+endpoint!(DrepListRequest => DrepListResponse, "/gov/drep_list");
+endpoint!(pub struct DrepDetailRequest { view: DRepId } => DrepDetailResponse, "/gov/drep_detail");
+endpoint!(DrepVoteRequest => DrepVoteResponse, "/gov/vote");
+endpoint!(DrepDelegatorRequest => DrepDelegatorResponse, "/gov/drep_delegator");
+endpoint!(pub struct DrepDelegatorStatsRequest { view: DRepId } => PoolDelegatorStatsResponse, "/gov/drep_delegator_stats");
+endpoint!(pub struct AverageDrepRequest => AverageDrepResponse, "/analytics/avg_drep", "type" = "avg_num_per_drep");
+endpoint!(pub struct DrepSpoSameTimeRequest => DrepSpoSameTimeResponse, "/analytics/drep_spo", "type" = "power_drep_spo_same_time");
+endpoint!(pub struct StakeIsSpoDrepRequest => StakeIsSpoDrepResponse, "/analytics/stake", "type" = "stake_is_spo_drep");
+endpoint!(pub struct DrepNotSpoSameTimeRequest => DrepNotSpoSameTimeResponse, "/analytics/drep_spo", "type" = "power_drep_not_spo");
+endpoint!(pub struct DelegEpochChangesRequest => DelegEpochChangesResponse, "/analytics/deleg", "type" = "deleg_epoch_changes");
+
+impl CexplorerClient {
+    pub async fn get_drep_stat(&self) -> Result<DrepStatResponse, CexplorerError> {
+        self.call(DrepStatRequest).await
+    }
+
+    pub async fn get_drep_analytics(&self) -> Result<DrepAnalyticsResponse, CexplorerError> {
+        self.call(DrepAnalyticsRequest).await
+    }
+
+    pub async fn get_stake_drep_retired(&self) -> Result<StakeDrepRetiredResponse, CexplorerError> {
+        self.call(StakeDrepRetiredRequest).await
+    }
+
+    pub async fn get_drep_list(
+        &self,
+        request: DrepListRequest,
+    ) -> Result<DrepListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_drep_detail(&self, hash: &DRepId) -> Result<DrepDetailResponse, CexplorerError> {
+        self.call(DrepDetailRequest::new(hash.clone())).await
+    }
+
+    /// Details of every distinct DRep in `ids`; see [`call_batch`](Self::call_batch)
+    pub async fn get_drep_details(&self, ids: &[DRepId]) -> BatchResults<DRepId, DrepDetailResponse> {
+        self.call_batch(ids.iter().cloned(), |id| DrepDetailRequest::new(id.clone())).await
+    }
+
+    pub async fn get_drep_vote(
+        &self,
+        request: DrepVoteRequest,
+    ) -> Result<DrepVoteResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_drep_delegator(
+        &self,
+        request: DrepDelegatorRequest,
+    ) -> Result<DrepDelegatorResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_drep_delegator_stats(&self, view: &DRepId) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
+        self.call(DrepDelegatorStatsRequest::new(view.clone())).await
+    }
+
+    pub async fn get_average_drep(&self) -> Result<AverageDrepResponse, CexplorerError> {
+        self.call(AverageDrepRequest).await
+    }
+
+    pub async fn get_drep_spo_same_time(&self) -> Result<DrepSpoSameTimeResponse, CexplorerError> {
+        self.call(DrepSpoSameTimeRequest).await
+    }
+
+    pub async fn get_stake_is_spo_drep(&self) -> Result<StakeIsSpoDrepResponse, CexplorerError> {
+        self.call(StakeIsSpoDrepRequest).await
+    }
+
+    pub async fn get_drep_not_spo_same_time(&self) -> Result<DrepNotSpoSameTimeResponse, CexplorerError> {
+        self.call(DrepNotSpoSameTimeRequest).await
+    }
+
+    pub async fn get_deleg_epoch_changes(&self) -> Result<DelegEpochChangesResponse, CexplorerError> {
+        self.call(DelegEpochChangesRequest).await
+    }
 }
 
 pub async fn get_drep_stat() -> Result<DrepStatResponse, CexplorerError> {
-    let endpoint = "/gov/stat";
-    fetch::<DrepStatResponse>(endpoint).await
+    default_client()?.get_drep_stat().await
 }
 
 pub async fn get_drep_analytics() -> Result<DrepAnalyticsResponse, CexplorerError> {
-    let endpoint = "/gov/drep_analytics";
-    fetch::<DrepAnalyticsResponse>(endpoint).await
+    default_client()?.get_drep_analytics().await
 }
 
 pub async fn get_stake_drep_retired() -> Result<StakeDrepRetiredResponse, CexplorerError> {
-    let endpoint = "/analytics/stake?type=stake_drep_retired";
-    fetch::<StakeDrepRetiredResponse>(endpoint).await
-}
-
-pub async fn get_drep_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    sort: Option<&str>,
-    order: Option<&str>,
-    view: Option<&str>,
-    watchlist_only: Option<&str>,
-    gov_action: Option<&str>,
-    is_spo: Option<u64>,
-    is_not_spo: Option<u64>,
-) -> Result<DrepListResponse, CexplorerError> {
-    let endpoint = "/gov/drep_list";
-    let params = DrepListParams {
-        limit,
-        offset,
-        view: view.map(|s| s.to_string()),
-        watchlist_only: watchlist_only.map(|s| s.to_string()),
-        sort: sort.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-        gov_action: gov_action.map(|s| s.to_string()),
-        is_spo,
-        is_not_spo,
-    };
-    fetch_with_params::<DrepListResponse, DrepListParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_drep_detail(hash: &str) -> Result<DrepDetailResponse, CexplorerError> {
-    let endpoint = format!("/gov/drep_detail?view={}", hash);
-    fetch::<DrepDetailResponse>(&endpoint).await
-}
-
-pub async fn get_drep_vote(
-    voter_role: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<DrepVoteResponse, CexplorerError> {
-    let endpoint = "/gov/vote";
-    let params = DrepVoteParams {
-        voter_role: voter_role.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<DrepVoteResponse, DrepVoteParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_drep_delegator(
-    view: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-    filter: Option<&str>,
-    order: Option<&str>,
-) -> Result<DrepDelegatorResponse, CexplorerError> {
-    let endpoint = "/gov/drep_delegator";
-    let params = DrepDelegatorParams {
-        view: view.to_string(),
-        limit,
-        offset,
-        order: order.map(|s| s.to_string()),
-        filter: filter.map(|s| s.to_string()),
-    };
-    fetch_with_params::<DrepDelegatorResponse, DrepDelegatorParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_drep_delegator_stats(view: &str) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
-    let endpoint = "/gov/drep_delegator_stats";
-    let params = ViewParams {
-        view: view.to_string(),
-    };
-    fetch_with_params::<PoolDelegatorStatsResponse, ViewParams>(endpoint, Some(&params)).await
+    default_client()?.get_stake_drep_retired().await
+}
+
+pub async fn get_drep_list(request: DrepListRequest) -> Result<DrepListResponse, CexplorerError> {
+    default_client()?.get_drep_list(request).await
+}
+
+pub async fn get_drep_detail(hash: &DRepId) -> Result<DrepDetailResponse, CexplorerError> {
+    default_client()?.get_drep_detail(hash).await
+}
+
+/// Details of every distinct DRep in `ids`, fetched concurrently
+pub async fn get_drep_details(ids: &[DRepId]) -> Result<BatchResults<DRepId, DrepDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_drep_details(ids).await)
+}
+
+pub async fn get_drep_vote(request: DrepVoteRequest) -> Result<DrepVoteResponse, CexplorerError> {
+    default_client()?.get_drep_vote(request).await
+}
+
+pub async fn get_drep_delegator(request: DrepDelegatorRequest) -> Result<DrepDelegatorResponse, CexplorerError> {
+    default_client()?.get_drep_delegator(request).await
+}
+
+pub async fn get_drep_delegator_stats(view: &DRepId) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
+    default_client()?.get_drep_delegator_stats(view).await
 }
 
 pub async fn get_average_drep() -> Result<AverageDrepResponse, CexplorerError> {
-    let endpoint = "/analytics/avg_drep?type=avg_num_per_drep";
-    fetch::<AverageDrepResponse>(endpoint).await
+    default_client()?.get_average_drep().await
 }
 
 pub async fn get_drep_spo_same_time() -> Result<DrepSpoSameTimeResponse, CexplorerError> {
-    let endpoint = "/analytics/drep_spo?type=power_drep_spo_same_time";
-    fetch::<DrepSpoSameTimeResponse>(endpoint).await
+    default_client()?.get_drep_spo_same_time().await
 }
 
 pub async fn get_stake_is_spo_drep() -> Result<StakeIsSpoDrepResponse, CexplorerError> {
-    let endpoint = "/analytics/stake?type=stake_is_spo_drep";
-    fetch::<StakeIsSpoDrepResponse>(endpoint).await
+    default_client()?.get_stake_is_spo_drep().await
 }
 
 pub async fn get_drep_not_spo_same_time() -> Result<DrepNotSpoSameTimeResponse, CexplorerError> {
-    let endpoint = "/analytics/drep_spo?type=power_drep_not_spo";
-    fetch::<DrepNotSpoSameTimeResponse>(endpoint).await
+    default_client()?.get_drep_not_spo_same_time().await
 }
 
 pub async fn get_deleg_epoch_changes() -> Result<DelegEpochChangesResponse, CexplorerError> {
-    let endpoint = "/analytics/deleg?type=deleg_epoch_changes";
-    fetch::<DelegEpochChangesResponse>(endpoint).await
+    default_client()?.get_deleg_epoch_changes().await
 }
@@ -1,31 +1,42 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::epoch_types::{
     EpochListResponse, EpochDetailParamResponse, EpochDetailStatsResponse,
 };
-use serde::Serialize;
 
-/// Get list of all epochs
-pub async fn get_epoch_list() -> Result<EpochListResponse, CexplorerError> {
-    let endpoint = "/epoch/list";
-    fetch::<EpochListResponse>(endpoint).await
+endpoint!(pub struct EpochListRequest => EpochListResponse, "/epoch/list");
+endpoint!(pub struct EpochParamRequest { no: u64 } => EpochDetailParamResponse, "/epoch/param");
+endpoint!(pub struct EpochStatsRequest { no: u64 } => EpochDetailStatsResponse, "/epoch/stats");
+
+impl CexplorerClient {
+    /// Get list of all epochs
+    pub async fn get_epoch_list(&self) -> Result<EpochListResponse, CexplorerError> {
+        self.call(EpochListRequest).await
+    }
+
+    /// Get protocol parameters for a specific epoch
+    pub async fn get_epoch_detail_param(&self, no: u64) -> Result<EpochDetailParamResponse, CexplorerError> {
+        self.call(EpochParamRequest::new(no)).await
+    }
+
+    /// Get statistics for a specific epoch
+    pub async fn get_epoch_detail_stats(&self, no: u64) -> Result<EpochDetailStatsResponse, CexplorerError> {
+        self.call(EpochStatsRequest::new(no)).await
+    }
 }
 
-#[derive(Debug, Serialize)]
-struct EpochNoParams {
-    no: u64,
+/// Get list of all epochs
+pub async fn get_epoch_list() -> Result<EpochListResponse, CexplorerError> {
+    default_client()?.get_epoch_list().await
 }
 
 /// Get protocol parameters for a specific epoch
 pub async fn get_epoch_detail_param(no: u64) -> Result<EpochDetailParamResponse, CexplorerError> {
-    let endpoint = "/epoch/param";
-    let params = EpochNoParams { no };
-    fetch_with_params::<EpochDetailParamResponse, EpochNoParams>(endpoint, Some(&params)).await
+    default_client()?.get_epoch_detail_param(no).await
 }
 
 /// Get statistics for a specific epoch
 pub async fn get_epoch_detail_stats(no: u64) -> Result<EpochDetailStatsResponse, CexplorerError> {
-    let endpoint = "/epoch/stats";
-    let params = EpochNoParams { no };
-    fetch_with_params::<EpochDetailStatsResponse, EpochNoParams>(endpoint, Some(&params)).await
-}
\ No newline at end of file
+    default_client()?.get_epoch_detail_stats(no).await
+}
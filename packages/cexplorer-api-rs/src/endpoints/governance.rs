@@ -1,235 +1,312 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::governance_types::*;
+use crate::ids::{CommitteeMemberId, DRepId, GovActionId, PoolId, TxHash};
+use crate::params::{GovActionState, GovActionType, SortDirection, Vote, VoterRole};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct GovActionProposalListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovActionProposalListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<GovActionState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<GovActionType>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct GovActionProposalDetailParams {
-    pub id: String,
+impl GovActionProposalListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct GovVoteParams {
+request_setters!(GovActionProposalListRequest {
+    limit: u32,
+    offset: u32,
+    state: GovActionState,
+    search: String,
+    action_type: GovActionType,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovVoteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gov_action_proposal: Option<String>,
+    pub gov_action_proposal: Option<GovActionId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voter_role: Option<String>,
+    pub voter_role: Option<VoterRole>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vote: Option<String>,
+    pub vote: Option<Vote>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tx: Option<String>,
+    pub tx: Option<TxHash>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drep_voter: Option<DRepId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_voter: Option<PoolId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committee_voter: Option<CommitteeMemberId>,
+}
+
+impl GovVoteRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(GovVoteRequest {
+    limit: u32,
+    offset: u32,
+    gov_action_proposal: GovActionId,
+    voter_role: VoterRole,
+    order: String,
+    sort: SortDirection,
+    vote: Vote,
+    search: String,
+    tx: TxHash,
+    drep_voter: DRepId,
+    pool_voter: PoolId,
+    committee_voter: CommitteeMemberId,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovVoteNotRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub drep_voter: Option<String>,
+    pub gov_action_proposal: Option<GovActionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voter_role: Option<VoterRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pool_voter: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub committee_voter: Option<String>,
+    pub search: Option<String>,
+}
+
+impl GovVoteNotRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CommitteeDetailParams {
+request_setters!(GovVoteNotRequest {
+    limit: u32,
+    offset: u32,
+    gov_action_proposal: GovActionId,
+    voter_role: VoterRole,
+    order: String,
+    sort: SortDirection,
+    search: String,
+});
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommitteeDetailRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CommitteeMemberParams {
-    pub ident: String,
+impl CommitteeDetailRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ConstitutionListParams {
+request_setters!(CommitteeDetailRequest { id: u32 });
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConstitutionListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DrepListVoteParams {
+impl ConstitutionListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(ConstitutionListRequest { limit: u32 });
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepListVoteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
-    pub gov_action: String,
+    pub gov_action: GovActionId,
+}
+
+impl DrepListVoteRequest {
+    pub fn new(gov_action: GovActionId) -> Self {
+        DrepListVoteRequest {
+            limit: None,
+            offset: None,
+            gov_action,
+        }
+    }
+}
+
+request_setters!(DrepListVoteRequest { limit: u32, offset: u32 });
+
+endpoint!(GovActionProposalListRequest => GovernanceActionListResponse, "/gov/gov_action_proposal_list");
+endpoint!(pub struct GovActionProposalDetailRequest { id: GovActionId } => GovernanceActionDetailResponse, "/gov/gov_action_proposal_detail");
+endpoint!(GovVoteRequest => GovVoteResponse, "/gov/vote");
+endpoint!(GovVoteNotRequest => GovVoteResponse, "/gov/vote_not");
+endpoint!(DrepListVoteRequest => DrepListVoteResponse, "/gov/drep_list_vote");
+endpoint!(pub struct CommitteeListRequest => CommitteeListResponse, "/gov/committee_list/");
+endpoint!(CommitteeDetailRequest => CommitteeDetailResponse, "/gov/committee_detail");
+endpoint!(pub struct CommitteeMemberRequest { ident: CommitteeMemberId } => CCMemberDetailResponse, "/gov/committee_member");
+endpoint!(ConstitutionListRequest => ConstitutionListResponse, "/gov/constitution_list");
+endpoint!(pub struct ThresholdsRequest => ThresholdResponse, "/gov/thresholds");
+
+impl CexplorerClient {
+    /// Get governance action proposal list
+    pub async fn get_gov_action_proposal_list(
+        &self,
+        request: GovActionProposalListRequest,
+    ) -> Result<GovernanceActionListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    /// Get governance action proposal detail
+    pub async fn get_gov_action_proposal_detail(
+        &self,
+        id: &GovActionId,
+    ) -> Result<GovernanceActionDetailResponse, CexplorerError> {
+        self.call(GovActionProposalDetailRequest::new(id.clone())).await
+    }
+
+    /// Get governance votes
+    pub async fn get_gov_vote(
+        &self,
+        request: GovVoteRequest,
+    ) -> Result<GovVoteResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    /// Get governance votes NOT voted
+    pub async fn get_gov_vote_not(
+        &self,
+        request: GovVoteNotRequest,
+    ) -> Result<GovVoteResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    /// Get committee list
+    pub async fn get_committee_list(&self) -> Result<CommitteeListResponse, CexplorerError> {
+        self.call(CommitteeListRequest).await
+    }
+
+    /// Get committee detail
+    pub async fn get_committee_detail(
+        &self,
+        id: Option<u32>,
+    ) -> Result<CommitteeDetailResponse, CexplorerError> {
+        self.call(CommitteeDetailRequest { id }).await
+    }
+
+    /// Get committee member detail
+    pub async fn get_committee_member(
+        &self,
+        ident: &CommitteeMemberId,
+    ) -> Result<CCMemberDetailResponse, CexplorerError> {
+        self.call(CommitteeMemberRequest::new(ident.clone())).await
+    }
+
+    /// Get constitution list
+    pub async fn get_constitution_list(
+        &self,
+        limit: Option<u32>,
+    ) -> Result<ConstitutionListResponse, CexplorerError> {
+        self.call(ConstitutionListRequest { limit }).await
+    }
+
+    /// Get governance thresholds
+    pub async fn get_thresholds(&self) -> Result<ThresholdResponse, CexplorerError> {
+        self.call(ThresholdsRequest).await
+    }
+
+    /// Get drep list vote
+    pub async fn get_drep_list_vote(
+        &self,
+        request: DrepListVoteRequest,
+    ) -> Result<DrepListVoteResponse, CexplorerError> {
+        self.call(request).await
+    }
 }
 
 /// Get governance action proposal list
-pub async fn get_gov_action_proposal_list(
-    limit: Option<u32>,
-    offset: Option<u32>,
-    state: Option<&str>,
-    search: Option<&str>,
-    action_type: Option<&str>,
-) -> Result<GovernanceActionListResponse, CexplorerError> {
-    let endpoint = "/gov/gov_action_proposal_list";
-    let params = GovActionProposalListParams {
-        limit,
-        offset,
-        state: state.map(|s| s.to_string()),
-        search: search.map(|s| s.to_string()),
-        r#type: action_type.map(|s| s.to_string()),
-    };
-    fetch_with_params::<GovernanceActionListResponse, GovActionProposalListParams>(
-        endpoint,
-        Some(&params),
-    )
-    .await
+pub async fn get_gov_action_proposal_list(request: GovActionProposalListRequest) -> Result<GovernanceActionListResponse, CexplorerError> {
+    default_client()?.get_gov_action_proposal_list(request).await
 }
 
 /// Get governance action proposal detail
 pub async fn get_gov_action_proposal_detail(
-    id: &str,
+    id: &GovActionId,
 ) -> Result<GovernanceActionDetailResponse, CexplorerError> {
-    let endpoint = "/gov/gov_action_proposal_detail";
-    let params = GovActionProposalDetailParams {
-        id: id.to_string(),
-    };
-    fetch_with_params::<GovernanceActionDetailResponse, GovActionProposalDetailParams>(
-        endpoint,
-        Some(&params),
-    )
-    .await
+    default_client()?.get_gov_action_proposal_detail(id).await
 }
 
 /// Get governance votes
-pub async fn get_gov_vote(
-    limit: Option<u32>,
-    offset: Option<u32>,
-    gov_action_proposal: Option<&str>,
-    voter_role: Option<&str>,
-    order: Option<&str>,
-    sort: Option<&str>,
-    vote: Option<&str>,
-    search: Option<&str>,
-    tx: Option<&str>,
-    drep_voter: Option<&str>,
-    pool_voter: Option<&str>,
-    committee_voter: Option<&str>,
-) -> Result<GovVoteResponse, CexplorerError> {
-    let endpoint = "/gov/vote";
-    let params = GovVoteParams {
-        limit,
-        offset,
-        gov_action_proposal: gov_action_proposal.map(|s| s.to_string()),
-        voter_role: voter_role.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-        sort: sort.map(|s| s.to_string()),
-        vote: vote.map(|s| s.to_string()),
-        search: search.map(|s| s.to_string()),
-        tx: tx.map(|s| s.to_string()),
-        drep_voter: drep_voter.map(|s| s.to_string()),
-        pool_voter: pool_voter.map(|s| s.to_string()),
-        committee_voter: committee_voter.map(|s| s.to_string()),
-    };
-    fetch_with_params::<GovVoteResponse, GovVoteParams>(endpoint, Some(&params)).await
+pub async fn get_gov_vote(request: GovVoteRequest) -> Result<GovVoteResponse, CexplorerError> {
+    default_client()?.get_gov_vote(request).await
 }
 
 /// Get governance votes NOT voted
-pub async fn get_gov_vote_not(
-    limit: Option<u32>,
-    offset: Option<u32>,
-    gov_action_proposal: Option<&str>,
-    voter_role: Option<&str>,
-    order: Option<&str>,
-    sort: Option<&str>,
-    search: Option<&str>,
-) -> Result<GovVoteResponse, CexplorerError> {
-    let endpoint = "/gov/vote_not";
-    let params = GovVoteParams {
-        limit,
-        offset,
-        gov_action_proposal: gov_action_proposal.map(|s| s.to_string()),
-        voter_role: voter_role.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-        sort: sort.map(|s| s.to_string()),
-        vote: None,
-        search: search.map(|s| s.to_string()),
-        tx: None,
-        drep_voter: None,
-        pool_voter: None,
-        committee_voter: None,
-    };
-    fetch_with_params::<GovVoteResponse, GovVoteParams>(endpoint, Some(&params)).await
+pub async fn get_gov_vote_not(request: GovVoteNotRequest) -> Result<GovVoteResponse, CexplorerError> {
+    default_client()?.get_gov_vote_not(request).await
 }
 
 /// Get committee list
 pub async fn get_committee_list() -> Result<CommitteeListResponse, CexplorerError> {
-    let endpoint = "/gov/committee_list/";
-    fetch::<CommitteeListResponse>(endpoint).await
+    default_client()?.get_committee_list().await
 }
 
 /// Get committee detail
 pub async fn get_committee_detail(
     id: Option<u32>,
 ) -> Result<CommitteeDetailResponse, CexplorerError> {
-    let endpoint = "/gov/committee_detail";
-    let params = CommitteeDetailParams { id };
-    fetch_with_params::<CommitteeDetailResponse, CommitteeDetailParams>(endpoint, Some(&params))
-        .await
+    default_client()?.get_committee_detail(id).await
 }
 
 /// Get committee member detail
 pub async fn get_committee_member(
-    ident: &str,
+    ident: &CommitteeMemberId,
 ) -> Result<CCMemberDetailResponse, CexplorerError> {
-    let endpoint = "/gov/committee_member";
-    let params = CommitteeMemberParams {
-        ident: ident.to_string(),
-    };
-    fetch_with_params::<CCMemberDetailResponse, CommitteeMemberParams>(endpoint, Some(&params))
-        .await
+    default_client()?.get_committee_member(ident).await
 }
 
 /// Get constitution list
 pub async fn get_constitution_list(
     limit: Option<u32>,
 ) -> Result<ConstitutionListResponse, CexplorerError> {
-    let endpoint = "/gov/constitution_list";
-    let params = ConstitutionListParams { limit };
-    fetch_with_params::<ConstitutionListResponse, ConstitutionListParams>(endpoint, Some(&params))
-        .await
+    default_client()?.get_constitution_list(limit).await
 }
 
 /// Get governance thresholds
 pub async fn get_thresholds() -> Result<ThresholdResponse, CexplorerError> {
-    let endpoint = "/gov/thresholds";
-    fetch::<ThresholdResponse>(endpoint).await
+    default_client()?.get_thresholds().await
 }
 
 /// Get drep list vote
-pub async fn get_drep_list_vote(
-    limit: Option<u32>,
-    offset: Option<u32>,
-    gov_action: &str,
-) -> Result<DrepListVoteResponse, CexplorerError> {
-    let endpoint = "/gov/drep_list_vote";
-    let params = DrepListVoteParams {
-        limit,
-        offset,
-        gov_action: gov_action.to_string(),
-    };
-    fetch_with_params::<DrepListVoteResponse, DrepListVoteParams>(endpoint, Some(&params)).await
+pub async fn get_drep_list_vote(request: DrepListVoteRequest) -> Result<DrepListVoteResponse, CexplorerError> {
+    default_client()?.get_drep_list_vote(request).await
 }
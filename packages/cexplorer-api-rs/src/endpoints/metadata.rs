@@ -1,32 +1,41 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::metadata_types::*;
+use crate::ids::TxHash;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MetadataTxListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetadataTxListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tx: Option<String>,
+    pub tx: Option<TxHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<u64>,
 }
 
-pub async fn get_metadata_tx_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    tx: Option<&str>,
-    key: Option<u64>,
-) -> Result<MetadataTxListResponse, CexplorerError> {
-    let endpoint = "/metadata/list";
-    let params = MetadataTxListParams {
-        limit,
-        offset,
-        tx: tx.map(|s| s.to_string()),
-        key,
-    };
-    fetch_with_params::<MetadataTxListResponse, MetadataTxListParams>(endpoint, Some(&params)).await
+impl MetadataTxListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(MetadataTxListRequest { limit: u64, offset: u64, tx: TxHash, key: u64 });
+
+endpoint!(MetadataTxListRequest => MetadataTxListResponse, "/metadata/list");
+
+impl CexplorerClient {
+    pub async fn get_metadata_tx_list(
+        &self,
+        request: MetadataTxListRequest,
+    ) -> Result<MetadataTxListResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_metadata_tx_list(request: MetadataTxListRequest) -> Result<MetadataTxListResponse, CexplorerError> {
+    default_client()?.get_metadata_tx_list(request).await
 }
@@ -1,102 +1,160 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::misc_types::*;
+use crate::params::ValidateType;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MiscMarketParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MiscMarketRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub epoch_no: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MiscSearchParams {
+impl MiscMarketRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(MiscMarketRequest { epoch_no: u64, date: String });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MiscSearchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lng: Option<String>,
+    #[serde(rename = "lng", skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MiscValidateParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#type: Option<String>,
+impl MiscSearchRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(MiscSearchRequest { query: String, category: String, locale: String });
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiscValidateRequest {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub validate_type: Option<ValidateType>,
     pub ident: String,
 }
 
+impl MiscValidateRequest {
+    pub fn new(ident: impl Into<String>) -> Self {
+        MiscValidateRequest {
+            validate_type: None,
+            ident: ident.into(),
+        }
+    }
+}
+
+request_setters!(MiscValidateRequest { validate_type: ValidateType });
+
+endpoint!(MiscMarketRequest => MiscMarketResponse, "/misc/market");
+endpoint!(MiscSearchRequest => MiscSearchResponse, "/misc/search");
+endpoint!(MiscValidateRequest => MiscValidateResponse, "/misc/validate");
+endpoint!(pub struct MiscApiRequest => MiscApiResponse, "/misc/api");
+endpoint!(pub struct MiscBasicRequest => MiscBasicResponse, "/misc/basic");
+endpoint!(pub struct MiscRateRequest => MiscRateResponse, "/misc/rate");
+endpoint!(pub struct MiscConstRequest => MiscConstResponse, "/misc/const");
+endpoint!(pub struct PollListRequest => PollListResponse, "/misc/gw/gov");
+endpoint!(pub struct MiscHealthRequest => MiscHealthResponse, "/misc/health");
+endpoint!(pub struct MiscProtocolParametersRequest => MiscProtocolParametersResponse, "/misc/protocol_parameters");
+
+impl CexplorerClient {
+    pub async fn get_misc_api(&self) -> Result<MiscApiResponse, CexplorerError> {
+        self.call(MiscApiRequest).await
+    }
+
+    pub async fn get_misc_basic(&self) -> Result<MiscBasicResponse, CexplorerError> {
+        self.call(MiscBasicRequest).await
+    }
+
+    pub async fn get_misc_rate(&self) -> Result<MiscRateResponse, CexplorerError> {
+        self.call(MiscRateRequest).await
+    }
+
+    pub async fn get_misc_const(&self) -> Result<MiscConstResponse, CexplorerError> {
+        self.call(MiscConstRequest).await
+    }
+
+    pub async fn get_misc_market(
+        &self,
+        request: MiscMarketRequest,
+    ) -> Result<MiscMarketResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_misc_search(
+        &self,
+        request: MiscSearchRequest,
+    ) -> Result<MiscSearchResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_poll_list(&self) -> Result<PollListResponse, CexplorerError> {
+        self.call(PollListRequest).await
+    }
+
+    pub async fn misc_validate(
+        &self,
+        request: MiscValidateRequest,
+    ) -> Result<MiscValidateResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_misc_health(&self) -> Result<MiscHealthResponse, CexplorerError> {
+        self.call(MiscHealthRequest).await
+    }
+
+    pub async fn get_misc_protocol_parameters(&self) -> Result<MiscProtocolParametersResponse, CexplorerError> {
+        self.call(MiscProtocolParametersRequest).await
+    }
+}
+
 pub async fn get_misc_api() -> Result<MiscApiResponse, CexplorerError> {
-    let endpoint = "/misc/api";
-    fetch::<MiscApiResponse>(endpoint).await
+    default_client()?.get_misc_api().await
 }
 
 pub async fn get_misc_basic() -> Result<MiscBasicResponse, CexplorerError> {
-    let endpoint = "/misc/basic";
-    fetch::<MiscBasicResponse>(endpoint).await
+    default_client()?.get_misc_basic().await
 }
 
 pub async fn get_misc_rate() -> Result<MiscRateResponse, CexplorerError> {
-    let endpoint = "/misc/rate";
-    fetch::<MiscRateResponse>(endpoint).await
+    default_client()?.get_misc_rate().await
 }
 
 pub async fn get_misc_const() -> Result<MiscConstResponse, CexplorerError> {
-    let endpoint = "/misc/const";
-    fetch::<MiscConstResponse>(endpoint).await
-}
-
-pub async fn get_misc_market(
-    epoch_no: Option<u64>,
-    date: Option<&str>,
-) -> Result<MiscMarketResponse, CexplorerError> {
-    let endpoint = "/misc/market";
-    let params = MiscMarketParams {
-        epoch_no,
-        date: date.map(|s| s.to_string()),
-    };
-    fetch_with_params::<MiscMarketResponse, MiscMarketParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_misc_search(
-    query: Option<&str>,
-    category: Option<&str>,
-    locale: Option<&str>,
-) -> Result<MiscSearchResponse, CexplorerError> {
-    let endpoint = "/misc/search";
-    let params = MiscSearchParams {
-        query: query.map(|s| s.to_string()),
-        category: category.map(|s| s.to_string()),
-        lng: locale.map(|s| s.to_string()),
-    };
-    fetch_with_params::<MiscSearchResponse, MiscSearchParams>(endpoint, Some(&params)).await
+    default_client()?.get_misc_const().await
+}
+
+pub async fn get_misc_market(request: MiscMarketRequest) -> Result<MiscMarketResponse, CexplorerError> {
+    default_client()?.get_misc_market(request).await
+}
+
+pub async fn get_misc_search(request: MiscSearchRequest) -> Result<MiscSearchResponse, CexplorerError> {
+    default_client()?.get_misc_search(request).await
 }
 
 pub async fn get_poll_list() -> Result<PollListResponse, CexplorerError> {
-    let endpoint = "/misc/gw/gov";
-    fetch::<PollListResponse>(endpoint).await
+    default_client()?.get_poll_list().await
 }
 
-pub async fn misc_validate(
-    validate_type: Option<&str>,
-    ident: &str,
-) -> Result<MiscValidateResponse, CexplorerError> {
-    let endpoint = "/misc/validate";
-    let params = MiscValidateParams {
-        r#type: validate_type.map(|s| s.to_string()),
-        ident: ident.to_string(),
-    };
-    fetch_with_params::<MiscValidateResponse, MiscValidateParams>(endpoint, Some(&params)).await
+pub async fn misc_validate(request: MiscValidateRequest) -> Result<MiscValidateResponse, CexplorerError> {
+    default_client()?.misc_validate(request).await
 }
 
 pub async fn get_misc_health() -> Result<MiscHealthResponse, CexplorerError> {
-    let endpoint = "/misc/health";
-    fetch::<MiscHealthResponse>(endpoint).await
+    default_client()?.get_misc_health().await
 }
 
 pub async fn get_misc_protocol_parameters() -> Result<MiscProtocolParametersResponse, CexplorerError> {
-    let endpoint = "/misc/protocol_parameters";
-    fetch::<MiscProtocolParametersResponse>(endpoint).await
+    default_client()?.get_misc_protocol_parameters().await
 }
@@ -1,3 +1,106 @@
+//! Endpoint methods on [`CexplorerClient`](crate::CexplorerClient), with a free
+//! function per endpoint that calls the client set up by `init_api`.
+//!
+//! Endpoints that take more than a single identifier accept a request type
+//! built with chained setters, e.g.
+//! `PoolListRequest::new().order(PoolListOrder::LiveStake).sort(SortDirection::Desc).is_drep(true).limit(50)`.
+//! Fields the API requires are arguments to `new`, and parameters with a known
+//! set of values take the enums from [`params`](crate::params).
+//!
+//! Every request type implements [`Endpoint`], so it can also be sent with
+//! [`CexplorerClient::call`](crate::CexplorerClient::call):
+//! `client.call(TxDetailRequest::new(hash))`.
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// An API endpoint, described by the type of its requests.
+///
+/// The fields of the request are sent as the query string, followed by the
+/// endpoint's fixed [`QUERY`](Endpoint::QUERY) pairs, with every key and
+/// value URL-encoded.
+pub trait Endpoint: Serialize {
+    type Response: DeserializeOwned;
+
+    /// Path relative to the network's base URL
+    const PATH: &'static str;
+    const METHOD: Method = Method::GET;
+    /// Parameters sent with every request to this endpoint
+    const QUERY: &'static [(&'static str, &'static str)] = &[];
+}
+
+/// Implements [`Endpoint`] for a request type, declaring the type as well
+/// when it is a unit struct or only has required fields:
+///
+/// ```ignore
+/// endpoint!(TxListRequest => TxListResponse, "/tx/list");
+/// endpoint!(pub struct MiscHealthRequest => MiscHealthResponse, "/misc/health");
+/// endpoint!(pub struct TxDetailRequest { hash: TxHash } => TxDetailResponse, "/tx/detail");
+/// endpoint!(pub struct AveragePoolRequest => AveragePoolResponse, "/analytics/avg_pool", "type" = "avg_num_per_pool");
+/// ```
+macro_rules! endpoint {
+    ($(#[$meta:meta])* pub struct $request:ident => $($rest:tt)+) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        pub struct $request;
+
+        endpoint!($request => $($rest)+);
+    };
+    ($(#[$meta:meta])* pub struct $request:ident { $($field:ident: $ty:ty),+ $(,)? } => $($rest:tt)+) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        pub struct $request {
+            $(pub $field: $ty),+
+        }
+
+        impl $request {
+            pub fn new($($field: $ty),+) -> Self {
+                $request { $($field),+ }
+            }
+        }
+
+        endpoint!($request => $($rest)+);
+    };
+    ($request:ty => $response:ty, $path:literal $(, $key:literal = $value:literal)* $(,)?) => {
+        impl crate::endpoints::Endpoint for $request {
+            type Response = $response;
+            const PATH: &'static str = $path;
+            const QUERY: &'static [(&'static str, &'static str)] = &[$(($key, $value)),*];
+        }
+    };
+}
+
+/// Chainable setters for the optional query parameters of a request type.
+///
+/// `String` fields accept anything `Into<String>`, `flag` fields take a
+/// `bool` and are sent as `1`/`0`, and every other type is taken as is.
+macro_rules! request_setters {
+    ($request:ident { $($field:ident: $kind:tt),* $(,)? }) => {
+        impl $request {
+            $(request_setters!(@setter $field $kind);)*
+        }
+    };
+    (@setter $field:ident String) => {
+        pub fn $field(mut self, $field: impl Into<String>) -> Self {
+            self.$field = Some($field.into());
+            self
+        }
+    };
+    (@setter $field:ident flag) => {
+        pub fn $field(mut self, $field: bool) -> Self {
+            self.$field = Some(u64::from($field));
+            self
+        }
+    };
+    (@setter $field:ident $kind:ident) => {
+        pub fn $field(mut self, $field: $kind) -> Self {
+            self.$field = Some($field);
+            self
+        }
+    };
+}
+
 pub mod block;
 pub mod address;
 pub mod epoch;
@@ -21,72 +124,112 @@ pub mod tx;
 pub mod governance;
 pub mod tool;
 
-pub use block::{get_block_detail, get_block_list, BlockListParams};
+pub use block::{
+    get_block_detail, get_block_details, get_block_list, BlockDetailRequest, BlockListRequest
+};
 pub use address::{
-    get_address_detail, get_address_list, get_address_utxo,
-    inspect_address, AddressListParams
+    get_address_detail, get_address_details, get_address_list, get_address_utxo, inspect_address,
+    AddressDetailRequest, AddressListRequest, AddressUtxoRequest, InspectAddressRequest
 };
 pub use epoch::{
-    get_epoch_list, get_epoch_detail_param, get_epoch_detail_stats
+    get_epoch_list, get_epoch_detail_param, get_epoch_detail_stats, EpochListRequest,
+    EpochParamRequest, EpochStatsRequest
 };
 pub use pools::{
     get_pool_blocks, get_pool_delegators, get_pool_reward, get_pool_detail,
-    get_pools_list, get_pools_birthdays, get_pool_update, get_pool_awards,
+    get_pools_list, get_pool_details, get_pools_birthdays, get_pool_update, get_pool_awards,
     get_pool_delegators_stats, get_global_pool_awards, get_pool_about,
     get_top_margins_with_delegators, get_retired_pools, get_top_multi_delegators,
-    get_deleg_epoch_registered, get_stake_dreps_not_spo, get_pool_retire
+    get_deleg_epoch_registered, get_stake_dreps_not_spo, get_pool_retire, PoolListRequest, PoolDetailRequest,
+    PoolDelegatorsRequest, PoolRewardsRequest, RetiredPoolsRequest, TopMarginsRequest,
+    GlobalPoolAwardsRequest, TopMultiDelegatorsRequest, PoolBlocksRequest, PoolBirthdaysRequest,
+    PoolUpdateRequest, PoolAwardsRequest, PoolDelegatorStatsRequest, PoolAboutRequest,
+    PoolRetireRequest, DelegEpochRegisteredRequest, StakeDrepsNotSpoRequest
 };
 pub use analytics::{
     get_hardforks, get_epoch_analytics, get_analytics_rate, get_analytics_pool_block,
     get_analytics_staking_accounts, get_analytics_top_addresses, get_wealth_composition,
-    get_ada_pots, get_group_list, get_group_detail, get_average_pool, get_genesis_addr
+    get_ada_pots, get_group_list, get_group_detail, get_average_pool, get_genesis_addr,
+    TopStakingAccountsRequest, TopAddressesRequest, HardforksRequest, EpochAnalyticsRequest,
+    AnalyticsRateRequest, AnalyticsPoolBlockRequest, WealthCompositionRequest, AdaPotsRequest,
+    GroupListRequest, GroupDetailRequest, AveragePoolRequest, GenesisAddrRequest
 };
 pub use account::{
-    get_account_rewards, check_user_delegation, get_withdrawals, get_delegation_vote
+    get_account_rewards, check_user_delegation, get_withdrawals, get_delegation_vote,
+    AccountRewardsRequest, CheckDelegationRequest, WithdrawalsRequest, DelegationVoteRequest
 };
 pub use article::{
-    get_article_detail, get_article_list
+    get_article_detail, get_article_list, ArticleDetailRequest, ArticleListRequest
 };
 pub use assets::{
-    get_asset_list, get_asset_detail, get_asset_owners, get_nft_asset_owners,
-    get_asset_metadata, get_asset_mint, get_asset_stats
+    get_asset_list, get_asset_detail, get_asset_details, get_asset_owners, get_nft_asset_owners,
+    get_asset_metadata, get_asset_mint, get_asset_stats, AssetListRequest,
+    AssetDetailRequest, AssetOwnersRequest, AssetOwnerHistoryRequest, AssetMetadataRequest,
+    AssetMintRequest, AssetStatsRequest
+};
+pub use datum::{
+    get_datum_detail, DatumDetailRequest
 };
-pub use datum::get_datum_detail;
 pub use delegations::{
-    get_delegations_state, get_stake_delegations, get_delegations_to_retired
+    get_delegations_state, get_stake_delegations, get_delegations_to_retired,
+    StakeDelegationsRequest, DelegationsToRetiredRequest, DelegationsStateRequest
 };
 pub use drep::{
     get_drep_stat, get_drep_analytics, get_stake_drep_retired, get_drep_list,
-    get_drep_detail, get_drep_vote, get_drep_delegator, get_drep_delegator_stats,
+    get_drep_detail, get_drep_details, get_drep_vote, get_drep_delegator, get_drep_delegator_stats,
     get_average_drep, get_drep_spo_same_time, get_stake_is_spo_drep,
-    get_drep_not_spo_same_time, get_deleg_epoch_changes
+    get_drep_not_spo_same_time, get_deleg_epoch_changes, DrepListRequest, DrepVoteRequest,
+    DrepDelegatorRequest, DrepDelegatorStatsRequest, DrepStatRequest, DrepAnalyticsRequest,
+    StakeDrepRetiredRequest, DrepDetailRequest, AverageDrepRequest, DrepSpoSameTimeRequest,
+    StakeIsSpoDrepRequest, DrepNotSpoSameTimeRequest, DelegEpochChangesRequest
+};
+pub use metadata::{
+    get_metadata_tx_list, MetadataTxListRequest
 };
-pub use metadata::get_metadata_tx_list;
 pub use policy::{
-    get_policy_detail, get_policy_stats, get_policy_owner
+    get_policy_detail, get_policy_stats, get_policy_owner, PolicyDetailRequest,
+    PolicyStatsRequest, PolicyOwnerRequest
 };
 pub use scripts::{
-    get_script_detail, get_script_detail_redeemer, get_script_list
+    get_script_detail, get_script_detail_redeemer, get_script_list, ScriptDetailRequest,
+    ScriptRedeemerRequest, ScriptListRequest
 };
 pub use misc::{
-    get_misc_api, get_misc_basic, get_misc_rate, get_misc_const,
-    get_misc_market, get_misc_search, get_poll_list, misc_validate,
-    get_misc_health, get_misc_protocol_parameters
+    get_misc_api, get_misc_basic, get_misc_rate, get_misc_const, get_misc_market,
+    get_misc_search, get_poll_list, misc_validate, get_misc_health,
+    get_misc_protocol_parameters, MiscMarketRequest, MiscSearchRequest,
+    MiscValidateRequest, MiscApiRequest, MiscBasicRequest, MiscRateRequest, MiscConstRequest,
+    PollListRequest, MiscHealthRequest, MiscProtocolParametersRequest
 };
-pub use stake::get_stake_detail;
-pub use treasury::get_treasury_donation_stats;
+pub use stake::{get_stake_detail, StakeDetailRequest};
+pub use treasury::{get_treasury_donation_stats, TreasuryDonationStatsRequest};
 pub use token::{
-    get_defi_token_list, get_defi_token_stat, get_defi_order
+    get_defi_token_list, get_defi_token_stat, get_defi_order, DeFiTokenListRequest,
+    DeFiTokenStatRequest, DeFiOrderRequest
+};
+pub use wallet::{
+    compare_wallets, CompareWalletsRequest
 };
-pub use wallet::compare_wallets;
 pub use tx::{
-    get_tx_detail, get_tx_list, get_drep_registrations, get_drep_deregistrations,
+    get_tx_detail, get_tx_details, get_tx_list, get_drep_registrations, get_drep_deregistrations,
     get_drep_updates, get_pool_registrations, get_pool_deregistrations,
-    get_stake_registrations, get_contract_transactions
+    get_stake_registrations, get_contract_transactions, TxDetailRequest, TxListRequest,
+    TxFilterRequest, DrepRegistrationsRequest, DrepDeregistrationsRequest, DrepUpdatesRequest,
+    PoolRegistrationsRequest, PoolDeregistrationsRequest, StakeRegistrationsRequest,
+    ContractTransactionsRequest
 };
 pub use governance::{
     get_gov_action_proposal_list, get_gov_action_proposal_detail, get_gov_vote,
     get_gov_vote_not, get_committee_list, get_committee_detail, get_committee_member,
-    get_constitution_list, get_thresholds, get_drep_list_vote
+    get_constitution_list, get_thresholds, get_drep_list_vote,
+    GovActionProposalListRequest, GovActionProposalDetailRequest, GovVoteRequest,
+    GovVoteNotRequest, CommitteeListRequest, CommitteeDetailRequest, CommitteeMemberRequest,
+    ConstitutionListRequest, ThresholdsRequest, DrepListVoteRequest
+};
+pub use tool::{
+    send_tx_sent, TxSentRequest
 };
-pub use tool::send_tx_sent;
+
+// Names the request types had before they gained builders, kept for one release
+#[allow(deprecated)]
+pub use self::{address::AddressListParams, block::BlockListParams};
@@ -1,48 +1,60 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::policy_types::*;
+use crate::ids::PolicyId;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PolicyIdParams {
-    pub id: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PolicyOwnerParams {
-    pub id: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyOwnerRequest {
+    pub id: PolicyId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-pub async fn get_policy_detail(id: &str) -> Result<PolicyDetailResponse, CexplorerError> {
-    let endpoint = "/policy/detail";
-    let params = PolicyIdParams {
-        id: id.to_string(),
-    };
-    fetch_with_params::<PolicyDetailResponse, PolicyIdParams>(endpoint, Some(&params)).await
+impl PolicyOwnerRequest {
+    pub fn new(id: PolicyId) -> Self {
+        PolicyOwnerRequest {
+            id,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(PolicyOwnerRequest { limit: u64, offset: u64 });
+
+endpoint!(pub struct PolicyDetailRequest { id: PolicyId } => PolicyDetailResponse, "/policy/detail");
+endpoint!(pub struct PolicyStatsRequest { id: PolicyId } => PolicyStatsResponse, "/policy/stat");
+endpoint!(PolicyOwnerRequest => PolicyOwnerResponse, "/policy/owner");
+
+impl CexplorerClient {
+    pub async fn get_policy_detail(&self, id: &PolicyId) -> Result<PolicyDetailResponse, CexplorerError> {
+        self.call(PolicyDetailRequest::new(id.clone())).await
+    }
+
+    pub async fn get_policy_stats(&self, id: &PolicyId) -> Result<PolicyStatsResponse, CexplorerError> {
+        self.call(PolicyStatsRequest::new(id.clone())).await
+    }
+
+    pub async fn get_policy_owner(
+        &self,
+        request: PolicyOwnerRequest,
+    ) -> Result<PolicyOwnerResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_policy_detail(id: &PolicyId) -> Result<PolicyDetailResponse, CexplorerError> {
+    default_client()?.get_policy_detail(id).await
 }
 
-pub async fn get_policy_stats(id: &str) -> Result<PolicyStatsResponse, CexplorerError> {
-    let endpoint = "/policy/stat";
-    let params = PolicyIdParams {
-        id: id.to_string(),
-    };
-    fetch_with_params::<PolicyStatsResponse, PolicyIdParams>(endpoint, Some(&params)).await
+pub async fn get_policy_stats(id: &PolicyId) -> Result<PolicyStatsResponse, CexplorerError> {
+    default_client()?.get_policy_stats(id).await
 }
 
-pub async fn get_policy_owner(
-    id: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<PolicyOwnerResponse, CexplorerError> {
-    let endpoint = "/policy/owner";
-    let params = PolicyOwnerParams {
-        id: id.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<PolicyOwnerResponse, PolicyOwnerParams>(endpoint, Some(&params)).await
+pub async fn get_policy_owner(request: PolicyOwnerRequest) -> Result<PolicyOwnerResponse, CexplorerError> {
+    default_client()?.get_policy_owner(request).await
 }
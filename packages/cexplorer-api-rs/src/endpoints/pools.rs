@@ -1,27 +1,26 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::pool_types::*;
+use crate::ids::PoolId;
+use crate::params::{DelegatorOrder, PoolDelegatorType, PoolListOrder, RetiredOrder, SortDirection, StakeSnapshot, TopPoolType};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PoolIdParams {
-    pub pool_id: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PoolListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PoolListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<PoolListOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pool_id: Option<String>,
+    pub pool_id: Option<PoolId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gov_action: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,31 +29,91 @@ pub struct PoolListParams {
     pub is_not_drep: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PoolDetailParams {
+impl PoolListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(PoolListRequest {
+    limit: u64,
+    offset: u64,
+    sort: SortDirection,
+    order: PoolListOrder,
+    name: String,
+    pool_id: PoolId,
+    gov_action: String,
+    is_drep: flag,
+    is_not_drep: flag,
+});
+
+/// Looks a pool up by either its bech32 id or its raw hash
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PoolDetailRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pool_id: Option<String>,
+    pub pool_id: Option<PoolId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hash_raw: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PoolDelegatorsParams {
-    pub pool_id: String,
+impl PoolDetailRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look the pool up by its bech32 id, replacing any `hash_raw`
+    pub fn pool_id(mut self, pool_id: PoolId) -> Self {
+        self.pool_id = Some(pool_id);
+        self.hash_raw = None;
+        self
+    }
+
+    /// Look the pool up by its hex hash rather than its bech32 id, replacing
+    /// any `pool_id`
+    pub fn hash_raw(mut self, pool_id: &PoolId) -> Self {
+        self.hash_raw = Some(pool_id.hash_raw());
+        self.pool_id = None;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolDelegatorsRequest {
+    pub pool_id: PoolId,
     #[serde(rename = "type")]
-    pub delegator_type: String,
+    pub delegator_type: PoolDelegatorType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<DelegatorOrder>,
+}
+
+impl PoolDelegatorsRequest {
+    pub fn new(pool_id: PoolId, delegator_type: PoolDelegatorType) -> Self {
+        PoolDelegatorsRequest {
+            pool_id,
+            delegator_type,
+            limit: None,
+            offset: None,
+            sort: None,
+            order: None,
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct PoolRewardsParams {
+request_setters!(PoolDelegatorsRequest {
+    limit: u64,
+    offset: u64,
+    sort: SortDirection,
+    order: DelegatorOrder,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PoolRewardsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,212 +121,288 @@ pub struct PoolRewardsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pool_id: Option<String>,
+    pub pool_id: Option<PoolId>,
+}
+
+impl PoolRewardsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct RetiredPoolsParams {
+request_setters!(PoolRewardsRequest {
+    limit: u64,
+    offset: u64,
+    name: String,
+    pool_id: PoolId,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetiredPoolsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    pub retired_type: Option<String>,
+    pub retired_type: Option<StakeSnapshot>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<RetiredOrder>,
+}
+
+impl RetiredPoolsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TopPoolParams {
+request_setters!(RetiredPoolsRequest {
+    retired_type: StakeSnapshot,
+    limit: u64,
+    offset: u64,
+    order: RetiredOrder,
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopMarginsRequest {
     #[serde(rename = "type")]
-    pub pool_type: String,
+    pub pool_type: TopPoolType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct LimitOffsetParams {
+impl TopMarginsRequest {
+    pub fn new(pool_type: TopPoolType) -> Self {
+        TopMarginsRequest {
+            pool_type,
+            offset: None,
+            limit: None,
+        }
+    }
+}
+
+request_setters!(TopMarginsRequest { offset: u64, limit: u64 });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GlobalPoolAwardsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-pub async fn get_pool_blocks(pool_id: &str) -> Result<PoolBlocksResponse, CexplorerError> {
-    let endpoint = "/pool/block";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolBlocksResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_delegators(
-    pool_id: &str,
-    delegator_type: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-    sort: Option<&str>,
-    order: Option<&str>,
-) -> Result<PoolDelegatorsResponse, CexplorerError> {
-    let endpoint = "/pool/delegator";
-    let params = PoolDelegatorsParams {
-        pool_id: pool_id.to_string(),
-        delegator_type: delegator_type.to_string(),
-        limit,
-        offset,
-        sort: sort.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-    };
-    fetch_with_params::<PoolDelegatorsResponse, PoolDelegatorsParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_reward(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    name: Option<&str>,
-    pool_id: Option<&str>,
-) -> Result<PoolRewardsResponse, CexplorerError> {
-    let endpoint = "/pool/reward";
-    let params = PoolRewardsParams {
-        limit,
-        offset,
-        name: name.map(|s| s.to_string()),
-        pool_id: pool_id.map(|s| s.to_string()),
-    };
-    fetch_with_params::<PoolRewardsResponse, PoolRewardsParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_detail(
-    pool_id: Option<&str>,
-    hash_raw: Option<&str>,
-) -> Result<PoolDetailResponse, CexplorerError> {
-    let endpoint = "/pool/detail";
-    let params = PoolDetailParams {
-        pool_id: pool_id.map(|s| s.to_string()),
-        hash_raw: hash_raw.map(|s| s.to_string()),
-    };
-    fetch_with_params::<PoolDetailResponse, PoolDetailParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pools_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    sort: Option<&str>,
-    order: Option<&str>,
-    name: Option<&str>,
-    pool_id: Option<&str>,
-    gov_action: Option<&str>,
-    is_drep: Option<u64>,
-    is_not_drep: Option<u64>,
-) -> Result<PoolsListResponse, CexplorerError> {
-    let endpoint = "/pool/list";
-    let params = PoolListParams {
-        limit,
-        offset,
-        sort: sort.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-        name: name.map(|s| s.to_string()),
-        pool_id: pool_id.map(|s| s.to_string()),
-        gov_action: gov_action.map(|s| s.to_string()),
-        is_drep,
-        is_not_drep,
-    };
-    fetch_with_params::<PoolsListResponse, PoolListParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pools_birthdays(pool_id: &str) -> Result<PoolBirthdaysResponse, CexplorerError> {
-    let endpoint = "/pool/birthday";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolBirthdaysResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_update(pool_id: &str) -> Result<PoolUpdateResponse, CexplorerError> {
-    let endpoint = "/pool/update";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolUpdateResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_awards(pool_id: &str) -> Result<PoolAwardsResponse, CexplorerError> {
-    let endpoint = "/pool/award";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolAwardsResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_delegators_stats(pool_id: &str) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
-    let endpoint = "/pool/delegator_stats";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolDelegatorStatsResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_global_pool_awards(
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<PoolAwardsResponse, CexplorerError> {
-    let endpoint = "/pool/award";
-    let params = LimitOffsetParams { limit, offset };
-    fetch_with_params::<PoolAwardsResponse, LimitOffsetParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_pool_about(pool_id: &str) -> Result<PoolAboutResponse, CexplorerError> {
-    let endpoint = "/pool/about";
-    let params = PoolIdParams { pool_id: pool_id.to_string() };
-    fetch_with_params::<PoolAboutResponse, PoolIdParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_top_margins_with_delegators(
-    pool_type: &str,
-    offset: Option<u64>,
-    limit: Option<u64>,
-) -> Result<TopMarginsWithDelegatorsResponse, CexplorerError> {
-    let endpoint = "/analytics/top_pool";
-    let params = TopPoolParams {
-        pool_type: pool_type.to_string(),
-        offset,
-        limit,
-    };
-    fetch_with_params::<TopMarginsWithDelegatorsResponse, TopPoolParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_retired_pools(
-    retired_type: Option<&str>,
-    limit: Option<u64>,
-    offset: Option<u64>,
-    order: Option<&str>,
-) -> Result<RetiredPoolsResponse, CexplorerError> {
-    let endpoint = "/pool/retired";
-    let params = RetiredPoolsParams {
-        retired_type: retired_type.map(|s| s.to_string()),
-        limit,
-        offset,
-        order: order.map(|s| s.to_string()),
-    };
-    fetch_with_params::<RetiredPoolsResponse, RetiredPoolsParams>(endpoint, Some(&params)).await
-}
-
-pub async fn get_top_multi_delegators(
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<TopMultiDelegatorsResponse, CexplorerError> {
-    let endpoint = "/analytics/top_multi";
-    let params = LimitOffsetParams { limit, offset };
-    fetch_with_params::<TopMultiDelegatorsResponse, LimitOffsetParams>(endpoint, Some(&params)).await
+impl GlobalPoolAwardsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(GlobalPoolAwardsRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TopMultiDelegatorsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+impl TopMultiDelegatorsRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(TopMultiDelegatorsRequest { limit: u64, offset: u64 });
+
+endpoint!(pub struct PoolBlocksRequest { pool_id: PoolId } => PoolBlocksResponse, "/pool/block");
+endpoint!(PoolDelegatorsRequest => PoolDelegatorsResponse, "/pool/delegator");
+endpoint!(PoolRewardsRequest => PoolRewardsResponse, "/pool/reward");
+endpoint!(PoolDetailRequest => PoolDetailResponse, "/pool/detail");
+endpoint!(PoolListRequest => PoolsListResponse, "/pool/list");
+endpoint!(pub struct PoolBirthdaysRequest { pool_id: PoolId } => PoolBirthdaysResponse, "/pool/birthday");
+endpoint!(pub struct PoolUpdateRequest { pool_id: PoolId } => PoolUpdateResponse, "/pool/update");
+endpoint!(pub struct PoolAwardsRequest { pool_id: PoolId } => PoolAwardsResponse, "/pool/award");
+endpoint!(pub struct PoolDelegatorStatsRequest { pool_id: PoolId } => PoolDelegatorStatsResponse, "/pool/delegator_stats");
+endpoint!(GlobalPoolAwardsRequest => PoolAwardsResponse, "/pool/award");
+endpoint!(pub struct PoolAboutRequest { pool_id: PoolId } => PoolAboutResponse, "/pool/about");
+endpoint!(TopMarginsRequest => TopMarginsWithDelegatorsResponse, "/analytics/top_pool");
+endpoint!(RetiredPoolsRequest => RetiredPoolsResponse, "/pool/retired");
+endpoint!(TopMultiDelegatorsRequest => TopMultiDelegatorsResponse, "/analytics/top_multi");
+endpoint!(pub struct DelegEpochRegisteredRequest => DelegEpochRegisteredResponse, "/analytics/deleg", "type" = "deleg_epoch_registered");
+endpoint!(pub struct StakeDrepsNotSpoRequest => StakeDrepsNotSpoResponse, "/analytics/stake", "type" = "stake_dreps_not_spo");
+endpoint!(pub struct PoolRetireRequest { pool_id: PoolId } => PoolRetireResponse, "/pool/retire");
+
+impl CexplorerClient {
+    pub async fn get_pool_blocks(&self, pool_id: &PoolId) -> Result<PoolBlocksResponse, CexplorerError> {
+        self.call(PoolBlocksRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_pool_delegators(
+        &self,
+        request: PoolDelegatorsRequest,
+    ) -> Result<PoolDelegatorsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_pool_reward(
+        &self,
+        request: PoolRewardsRequest,
+    ) -> Result<PoolRewardsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_pool_detail(
+        &self,
+        request: PoolDetailRequest,
+    ) -> Result<PoolDetailResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    /// Details of every distinct pool in `pool_ids`; see [`call_batch`](Self::call_batch)
+    pub async fn get_pool_details(&self, pool_ids: &[PoolId]) -> BatchResults<PoolId, PoolDetailResponse> {
+        self.call_batch(pool_ids.iter().cloned(), |pool_id| PoolDetailRequest::new().pool_id(pool_id.clone())).await
+    }
+
+    pub async fn get_pools_list(
+        &self,
+        request: PoolListRequest,
+    ) -> Result<PoolsListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_pools_birthdays(&self, pool_id: &PoolId) -> Result<PoolBirthdaysResponse, CexplorerError> {
+        self.call(PoolBirthdaysRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_pool_update(&self, pool_id: &PoolId) -> Result<PoolUpdateResponse, CexplorerError> {
+        self.call(PoolUpdateRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_pool_awards(&self, pool_id: &PoolId) -> Result<PoolAwardsResponse, CexplorerError> {
+        self.call(PoolAwardsRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_pool_delegators_stats(&self, pool_id: &PoolId) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
+        self.call(PoolDelegatorStatsRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_global_pool_awards(
+        &self,
+        request: GlobalPoolAwardsRequest,
+    ) -> Result<PoolAwardsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_pool_about(&self, pool_id: &PoolId) -> Result<PoolAboutResponse, CexplorerError> {
+        self.call(PoolAboutRequest::new(pool_id.clone())).await
+    }
+
+    pub async fn get_top_margins_with_delegators(
+        &self,
+        request: TopMarginsRequest,
+    ) -> Result<TopMarginsWithDelegatorsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_retired_pools(
+        &self,
+        request: RetiredPoolsRequest,
+    ) -> Result<RetiredPoolsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_top_multi_delegators(
+        &self,
+        request: TopMultiDelegatorsRequest,
+    ) -> Result<TopMultiDelegatorsResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_deleg_epoch_registered(&self) -> Result<DelegEpochRegisteredResponse, CexplorerError> {
+        self.call(DelegEpochRegisteredRequest).await
+    }
+
+    pub async fn get_stake_dreps_not_spo(&self) -> Result<StakeDrepsNotSpoResponse, CexplorerError> {
+        self.call(StakeDrepsNotSpoRequest).await
+    }
+
+    pub async fn get_pool_retire(&self, pool_id: &PoolId) -> Result<PoolRetireResponse, CexplorerError> {
+        self.call(PoolRetireRequest::new(pool_id.clone())).await
+    }
+}
+
+pub async fn get_pool_blocks(pool_id: &PoolId) -> Result<PoolBlocksResponse, CexplorerError> {
+    default_client()?.get_pool_blocks(pool_id).await
+}
+
+pub async fn get_pool_delegators(request: PoolDelegatorsRequest) -> Result<PoolDelegatorsResponse, CexplorerError> {
+    default_client()?.get_pool_delegators(request).await
+}
+
+pub async fn get_pool_reward(request: PoolRewardsRequest) -> Result<PoolRewardsResponse, CexplorerError> {
+    default_client()?.get_pool_reward(request).await
+}
+
+pub async fn get_pool_detail(request: PoolDetailRequest) -> Result<PoolDetailResponse, CexplorerError> {
+    default_client()?.get_pool_detail(request).await
+}
+
+/// Details of every distinct pool in `pool_ids`, fetched concurrently
+pub async fn get_pool_details(pool_ids: &[PoolId]) -> Result<BatchResults<PoolId, PoolDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_pool_details(pool_ids).await)
+}
+
+pub async fn get_pools_list(request: PoolListRequest) -> Result<PoolsListResponse, CexplorerError> {
+    default_client()?.get_pools_list(request).await
+}
+
+pub async fn get_pools_birthdays(pool_id: &PoolId) -> Result<PoolBirthdaysResponse, CexplorerError> {
+    default_client()?.get_pools_birthdays(pool_id).await
+}
+
+pub async fn get_pool_update(pool_id: &PoolId) -> Result<PoolUpdateResponse, CexplorerError> {
+    default_client()?.get_pool_update(pool_id).await
+}
+
+pub async fn get_pool_awards(pool_id: &PoolId) -> Result<PoolAwardsResponse, CexplorerError> {
+    default_client()?.get_pool_awards(pool_id).await
+}
+
+pub async fn get_pool_delegators_stats(pool_id: &PoolId) -> Result<PoolDelegatorStatsResponse, CexplorerError> {
+    default_client()?.get_pool_delegators_stats(pool_id).await
+}
+
+pub async fn get_global_pool_awards(request: GlobalPoolAwardsRequest) -> Result<PoolAwardsResponse, CexplorerError> {
+    default_client()?.get_global_pool_awards(request).await
+}
+
+pub async fn get_pool_about(pool_id: &PoolId) -> Result<PoolAboutResponse, CexplorerError> {
+    default_client()?.get_pool_about(pool_id).await
+}
+
+pub async fn get_top_margins_with_delegators(request: TopMarginsRequest) -> Result<TopMarginsWithDelegatorsResponse, CexplorerError> {
+    default_client()?.get_top_margins_with_delegators(request).await
+}
+
+pub async fn get_retired_pools(request: RetiredPoolsRequest) -> Result<RetiredPoolsResponse, CexplorerError> {
+    default_client()?.get_retired_pools(request).await
+}
+
+pub async fn get_top_multi_delegators(request: TopMultiDelegatorsRequest) -> Result<TopMultiDelegatorsResponse, CexplorerError> {
+    default_client()?.get_top_multi_delegators(request).await
 }
 
 pub async fn get_deleg_epoch_registered() -> Result<DelegEpochRegisteredResponse, CexplorerError> {
-    let endpoint = "/analytics/deleg?type=deleg_epoch_registered";
-    fetch::<DelegEpochRegisteredResponse>(endpoint).await
+    default_client()?.get_deleg_epoch_registered().await
 }
 
 pub async fn get_stake_dreps_not_spo() -> Result<StakeDrepsNotSpoResponse, CexplorerError> {
-    let endpoint = "/analytics/stake?type=stake_dreps_not_spo";
-    fetch::<StakeDrepsNotSpoResponse>(endpoint).await
+    default_client()?.get_stake_dreps_not_spo().await
 }
 
-pub async fn get_pool_retire(pool_id: &str) -> Result<PoolRetireResponse, CexplorerError> {
-    let endpoint = "/pool/retire";
-    let params = PoolIdParams {
-        pool_id: pool_id.to_string(),
-    };
-    fetch_with_params::<PoolRetireResponse, PoolIdParams>(endpoint, Some(&params)).await
+pub async fn get_pool_retire(pool_id: &PoolId) -> Result<PoolRetireResponse, CexplorerError> {
+    default_client()?.get_pool_retire(pool_id).await
 }
@@ -1,68 +1,84 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::script_types::*;
+use crate::ids::ScriptHash;
+use crate::params::ScriptListOrder;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ScriptHashParams {
-    pub hash: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ScriptRedeemerParams {
-    pub hash: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptRedeemerRequest {
+    pub hash: ScriptHash,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ScriptListParams {
+impl ScriptRedeemerRequest {
+    pub fn new(hash: ScriptHash) -> Self {
+        ScriptRedeemerRequest {
+            hash,
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+request_setters!(ScriptRedeemerRequest { limit: u64, offset: u64 });
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScriptListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash: Option<String>,
+    pub hash: Option<ScriptHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<ScriptListOrder>,
+}
+
+impl ScriptListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(ScriptListRequest { limit: u64, offset: u64, hash: ScriptHash, order: ScriptListOrder });
+
+endpoint!(pub struct ScriptDetailRequest { hash: ScriptHash } => ScriptDetailResponse, "/script/detail");
+endpoint!(ScriptRedeemerRequest => ScriptDetailRedeemerResponse, "/script/detail_redeemer");
+endpoint!(ScriptListRequest => ScriptListResponse, "/script/list");
+
+impl CexplorerClient {
+    pub async fn get_script_detail(&self, hash: &ScriptHash) -> Result<ScriptDetailResponse, CexplorerError> {
+        self.call(ScriptDetailRequest::new(hash.clone())).await
+    }
+
+    pub async fn get_script_detail_redeemer(
+        &self,
+        request: ScriptRedeemerRequest,
+    ) -> Result<ScriptDetailRedeemerResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_script_list(
+        &self,
+        request: ScriptListRequest,
+    ) -> Result<ScriptListResponse, CexplorerError> {
+        self.call(request).await
+    }
 }
 
-pub async fn get_script_detail(hash: &str) -> Result<ScriptDetailResponse, CexplorerError> {
-    let endpoint = "/script/detail";
-    let params = ScriptHashParams {
-        hash: hash.to_string(),
-    };
-    fetch_with_params::<ScriptDetailResponse, ScriptHashParams>(endpoint, Some(&params)).await
+pub async fn get_script_detail(hash: &ScriptHash) -> Result<ScriptDetailResponse, CexplorerError> {
+    default_client()?.get_script_detail(hash).await
 }
 
-pub async fn get_script_detail_redeemer(
-    hash: &str,
-    limit: Option<u64>,
-    offset: Option<u64>,
-) -> Result<ScriptDetailRedeemerResponse, CexplorerError> {
-    let endpoint = "/script/detail_redeemer";
-    let params = ScriptRedeemerParams {
-        hash: hash.to_string(),
-        limit,
-        offset,
-    };
-    fetch_with_params::<ScriptDetailRedeemerResponse, ScriptRedeemerParams>(endpoint, Some(&params)).await
+pub async fn get_script_detail_redeemer(request: ScriptRedeemerRequest) -> Result<ScriptDetailRedeemerResponse, CexplorerError> {
+    default_client()?.get_script_detail_redeemer(request).await
 }
 
-pub async fn get_script_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    hash: Option<&str>,
-    order: Option<&str>,
-) -> Result<ScriptListResponse, CexplorerError> {
-    let endpoint = "/script/list";
-    let params = ScriptListParams {
-        limit,
-        offset,
-        hash: hash.map(|s| s.to_string()),
-        order: order.map(|s| s.to_string()),
-    };
-    fetch_with_params::<ScriptListResponse, ScriptListParams>(endpoint, Some(&params)).await
+pub async fn get_script_list(request: ScriptListRequest) -> Result<ScriptListResponse, CexplorerError> {
+    default_client()?.get_script_list(request).await
 }
@@ -1,8 +1,17 @@
-use crate::client::fetch;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
+use crate::ids::StakeAddress;
 use crate::types::stake_types::*;
 
-pub async fn get_stake_detail(view: &str) -> Result<StakeDetailResponse, CexplorerError> {
-    let endpoint = format!("/account/detail?view={}", view);
-    fetch::<StakeDetailResponse>(&endpoint).await
+endpoint!(pub struct StakeDetailRequest { view: StakeAddress } => StakeDetailResponse, "/account/detail");
+
+impl CexplorerClient {
+    pub async fn get_stake_detail(&self, view: &StakeAddress) -> Result<StakeDetailResponse, CexplorerError> {
+        self.call(StakeDetailRequest::new(view.clone())).await
+    }
+}
+
+pub async fn get_stake_detail(view: &StakeAddress) -> Result<StakeDetailResponse, CexplorerError> {
+    default_client()?.get_stake_detail(view).await
 }
@@ -1,38 +1,55 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::token_types::*;
+use crate::ids::{Address, StakeAddress, TxHash};
+use crate::params::{DeFiTokenOrder, SortDirection};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DeFiTokenListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeFiTokenListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<DeFiTokenOrder>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortDirection>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assetname: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct DeFiOrderParams {
+impl DeFiTokenListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(DeFiTokenListRequest {
+    limit: u64,
+    offset: u64,
+    order: DeFiTokenOrder,
+    sort: SortDirection,
+    assetname: String,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeFiOrderRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address: Option<String>,
+    pub address: Option<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stake: Option<String>,
+    pub stake: Option<StakeAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dex: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tx: Option<String>,
+    pub tx: Option<TxHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,53 +58,57 @@ pub struct DeFiOrderParams {
     pub token_out: Option<String>,
 }
 
-pub async fn get_defi_token_list(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    sort: Option<&str>,
-    order: Option<&str>,
-    assetname: Option<&str>,
-) -> Result<DeFiTokenListResponse, CexplorerError> {
-    let endpoint = "/defi/token";
-    let params = DeFiTokenListParams {
-        limit,
-        offset,
-        order: order.map(|s| s.to_string()),
-        sort: sort.map(|s| s.to_string()),
-        assetname: assetname.map(|s| s.to_string()),
-    };
-    fetch_with_params::<DeFiTokenListResponse, DeFiTokenListParams>(endpoint, Some(&params)).await
+impl DeFiOrderRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(DeFiOrderRequest {
+    limit: u64,
+    offset: u64,
+    address: Address,
+    stake: StakeAddress,
+    status: String,
+    dex: String,
+    tx: TxHash,
+    token: String,
+    token_in: String,
+    token_out: String,
+});
+
+endpoint!(DeFiTokenListRequest => DeFiTokenListResponse, "/defi/token");
+endpoint!(DeFiOrderRequest => DeFiOrderListResponse, "/defi/order");
+endpoint!(pub struct DeFiTokenStatRequest => DeFiTokenStatResponse, "/defi/stat");
+
+impl CexplorerClient {
+    pub async fn get_defi_token_list(
+        &self,
+        request: DeFiTokenListRequest,
+    ) -> Result<DeFiTokenListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_defi_token_stat(&self) -> Result<DeFiTokenStatResponse, CexplorerError> {
+        self.call(DeFiTokenStatRequest).await
+    }
+
+    pub async fn get_defi_order(
+        &self,
+        request: DeFiOrderRequest,
+    ) -> Result<DeFiOrderListResponse, CexplorerError> {
+        self.call(request).await
+    }
+}
+
+pub async fn get_defi_token_list(request: DeFiTokenListRequest) -> Result<DeFiTokenListResponse, CexplorerError> {
+    default_client()?.get_defi_token_list(request).await
 }
 
 pub async fn get_defi_token_stat() -> Result<DeFiTokenStatResponse, CexplorerError> {
-    let endpoint = "/defi/stat";
-    fetch::<DeFiTokenStatResponse>(endpoint).await
+    default_client()?.get_defi_token_stat().await
 }
 
-pub async fn get_defi_order(
-    limit: Option<u64>,
-    offset: Option<u64>,
-    address: Option<&str>,
-    stake: Option<&str>,
-    status: Option<&str>,
-    dex: Option<&str>,
-    tx: Option<&str>,
-    fingerprint: Option<&str>,
-    token_in: Option<&str>,
-    token_out: Option<&str>,
-) -> Result<DeFiOrderListResponse, CexplorerError> {
-    let endpoint = "/defi/order";
-    let params = DeFiOrderParams {
-        limit,
-        offset,
-        address: address.map(|s| s.to_string()),
-        stake: stake.map(|s| s.to_string()),
-        status: status.map(|s| s.to_string()),
-        dex: dex.map(|s| s.to_string()),
-        tx: tx.map(|s| s.to_string()),
-        token: fingerprint.map(|s| s.to_string()),
-        token_in: token_in.map(|s| s.to_string()),
-        token_out: token_out.map(|s| s.to_string()),
-    };
-    fetch_with_params::<DeFiOrderListResponse, DeFiOrderParams>(endpoint, Some(&params)).await
+pub async fn get_defi_order(request: DeFiOrderRequest) -> Result<DeFiOrderListResponse, CexplorerError> {
+    default_client()?.get_defi_order(request).await
 }
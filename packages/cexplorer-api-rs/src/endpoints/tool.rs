@@ -1,26 +1,51 @@
-use crate::client::fetch_with_params;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::tool_types::*;
+use crate::ids::TxHash;
+use crate::params::TxSentType;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TxSentParams {
-    pub id: String,
-    pub r#type: String,
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TxSentRequest {
+    #[serde(rename = "id")]
+    pub hash: TxHash,
+    #[serde(rename = "type")]
+    pub tx_type: TxSentType,
+    /// Campaign the transaction counts towards, usually the id of the pool
+    /// delegated or donated to
     pub campaign: String,
 }
 
+impl TxSentRequest {
+    pub fn new(hash: TxHash, campaign: impl Into<String>, tx_type: TxSentType) -> Self {
+        TxSentRequest {
+            hash,
+            tx_type,
+            campaign: campaign.into(),
+        }
+    }
+}
+
+endpoint!(TxSentRequest => TxSentResponse, "/tool/tx_sent");
+
+impl CexplorerClient {
+    /// Send delegation or donation transaction information
+    pub async fn send_tx_sent(
+        &self,
+        hash: &TxHash,
+        campaign: &str,
+        tx_type: TxSentType,
+    ) -> Result<TxSentResponse, CexplorerError> {
+        self.call(TxSentRequest::new(hash.clone(), campaign, tx_type)).await
+    }
+}
+
 /// Send delegation or donation transaction information
 pub async fn send_tx_sent(
-    hash: &str,
-    pool_id: &str,
-    tx_type: &str,
+    hash: &TxHash,
+    campaign: &str,
+    tx_type: TxSentType,
 ) -> Result<TxSentResponse, CexplorerError> {
-    let endpoint = "/tool/tx_sent";
-    let params = TxSentParams {
-        id: hash.to_string(),
-        r#type: tx_type.to_string(),
-        campaign: pool_id.to_string(),
-    };
-    fetch_with_params::<TxSentResponse, TxSentParams>(endpoint, Some(&params)).await
+    default_client()?.send_tx_sent(hash, campaign, tx_type).await
 }
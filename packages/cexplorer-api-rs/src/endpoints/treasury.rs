@@ -1,8 +1,15 @@
-use crate::client::fetch;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::treasury_types::*;
+endpoint!(pub struct TreasuryDonationStatsRequest => TreasuryDonationStatsResponse, "/analytics/treasury");
+
+impl CexplorerClient {
+    pub async fn get_treasury_donation_stats(&self) -> Result<TreasuryDonationStatsResponse, CexplorerError> {
+        self.call(TreasuryDonationStatsRequest).await
+    }
+}
 
 pub async fn get_treasury_donation_stats() -> Result<TreasuryDonationStatsResponse, CexplorerError> {
-    let endpoint = "/analytics/treasury";
-    fetch::<TreasuryDonationStatsResponse>(endpoint).await
+    default_client()?.get_treasury_donation_stats().await
 }
@@ -1,24 +1,27 @@
-use crate::client::{fetch, fetch_with_params};
+use crate::batch::BatchResults;
+use crate::client::CexplorerClient;
+use crate::config::default_client;
 use crate::error::CexplorerError;
 use crate::types::tx_types::*;
 use crate::types::drep_types::DrepRegistrationsResponse;
 use crate::types::pool_types::PoolRegistrationsResponse;
 use crate::types::stake_types::StakeRegistrationsResponse;
 use crate::types::contract_types::ContractInteractionsResponse;
+use crate::ids::{Address, PolicyId, StakeAddress, TxHash};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TxListParams {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TxListRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash: Option<String>,
+    pub hash: Option<TxHash>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address: Option<String>,
+    pub address: Option<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stake: Option<String>,
+    pub stake: Option<StakeAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub asset: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,137 +29,189 @@ pub struct TxListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_donation: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy: Option<String>,
+    pub policy: Option<PolicyId>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TxFilterParams {
+impl TxListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(TxListRequest {
+    hash: TxHash,
+    limit: u64,
+    offset: u64,
+    address: Address,
+    stake: StakeAddress,
+    asset: String,
+    script: String,
+    has_donation: bool,
+    policy: PolicyId,
+});
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TxFilterRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u64>,
-    #[serde(rename = "type")]
-    pub filter_type: String,
-}
-
-pub async fn get_tx_detail(hash: &str) -> Result<TxDetailResponse, CexplorerError> {
-    let endpoint = format!("/tx/detail?hash={}", hash);
-    fetch::<TxDetailResponse>(&endpoint).await
-}
-
-pub async fn get_tx_list(
-    hash: Option<&str>,
-    limit: Option<u64>,
-    offset: Option<u64>,
-    address: Option<&str>,
-    stake: Option<&str>,
-    asset: Option<&str>,
-    script: Option<&str>,
-    has_donation: Option<bool>,
-    policy: Option<&str>,
-) -> Result<TxListResponse, CexplorerError> {
-    let endpoint = "/tx/list";
-    let params = TxListParams {
-        hash: hash.map(|s| s.to_string()),
-        limit,
-        offset,
-        address: address.map(|s| s.to_string()),
-        stake: stake.map(|s| s.to_string()),
-        asset: asset.map(|s| s.to_string()),
-        script: script.map(|s| s.to_string()),
-        has_donation,
-        policy: policy.map(|s| s.to_string()),
+}
+
+impl TxFilterRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+request_setters!(TxFilterRequest { limit: u64, offset: u64 });
+
+/// Declares the request type of a `/tx/filter` listing: a [`TxFilterRequest`]
+/// sent with a fixed `type`.
+macro_rules! tx_filter {
+    ($request:ident => $response:ty, $filter:literal) => {
+        #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $request(pub TxFilterRequest);
+
+        impl From<TxFilterRequest> for $request {
+            fn from(request: TxFilterRequest) -> Self {
+                $request(request)
+            }
+        }
+
+        endpoint!($request => $response, "/tx/filter", "type" = $filter);
     };
-    fetch_with_params::<TxListResponse, TxListParams>(endpoint, Some(&params)).await
+}
+
+endpoint!(pub struct TxDetailRequest { hash: TxHash } => TxDetailResponse, "/tx/detail");
+endpoint!(TxListRequest => TxListResponse, "/tx/list");
+tx_filter!(DrepRegistrationsRequest => DrepRegistrationsResponse, "drep_registrations");
+tx_filter!(DrepDeregistrationsRequest => DrepRegistrationsResponse, "drep_deregistrations");
+tx_filter!(DrepUpdatesRequest => DrepRegistrationsResponse, "drep_updates");
+tx_filter!(PoolRegistrationsRequest => PoolRegistrationsResponse, "pool_registrations");
+tx_filter!(PoolDeregistrationsRequest => PoolRegistrationsResponse, "pool_deregistrations");
+tx_filter!(StakeRegistrationsRequest => StakeRegistrationsResponse, "stake_key_registrations");
+tx_filter!(ContractTransactionsRequest => ContractInteractionsResponse, "contract_transactions");
+
+impl CexplorerClient {
+    pub async fn get_tx_detail(&self, hash: &TxHash) -> Result<TxDetailResponse, CexplorerError> {
+        self.call(TxDetailRequest::new(hash.clone())).await
+    }
+
+    /// Details of every distinct transaction in `hashes`; see [`call_batch`](Self::call_batch)
+    pub async fn get_tx_details(&self, hashes: &[TxHash]) -> BatchResults<TxHash, TxDetailResponse> {
+        self.call_batch(hashes.iter().cloned(), |hash| TxDetailRequest::new(hash.clone())).await
+    }
+
+    pub async fn get_tx_list(
+        &self,
+        request: TxListRequest,
+    ) -> Result<TxListResponse, CexplorerError> {
+        self.call(request).await
+    }
+
+    pub async fn get_drep_registrations(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<DrepRegistrationsResponse, CexplorerError> {
+        self.call(DrepRegistrationsRequest(request)).await
+    }
+
+    pub async fn get_drep_deregistrations(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<DrepRegistrationsResponse, CexplorerError> {
+        self.call(DrepDeregistrationsRequest(request)).await
+    }
+
+    pub async fn get_drep_updates(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<DrepRegistrationsResponse, CexplorerError> {
+        self.call(DrepUpdatesRequest(request)).await
+    }
+
+    pub async fn get_pool_registrations(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<PoolRegistrationsResponse, CexplorerError> {
+        self.call(PoolRegistrationsRequest(request)).await
+    }
+
+    pub async fn get_pool_deregistrations(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<PoolRegistrationsResponse, CexplorerError> {
+        self.call(PoolDeregistrationsRequest(request)).await
+    }
+
+    pub async fn get_stake_registrations(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<StakeRegistrationsResponse, CexplorerError> {
+        self.call(StakeRegistrationsRequest(request)).await
+    }
+
+    pub async fn get_contract_transactions(
+        &self,
+        request: TxFilterRequest,
+    ) -> Result<ContractInteractionsResponse, CexplorerError> {
+        self.call(ContractTransactionsRequest(request)).await
+    }
+}
+
+pub async fn get_tx_detail(hash: &TxHash) -> Result<TxDetailResponse, CexplorerError> {
+    default_client()?.get_tx_detail(hash).await
+}
+
+/// Details of every distinct transaction in `hashes`, fetched concurrently
+pub async fn get_tx_details(hashes: &[TxHash]) -> Result<BatchResults<TxHash, TxDetailResponse>, CexplorerError> {
+    Ok(default_client()?.get_tx_details(hashes).await)
+}
+
+pub async fn get_tx_list(request: TxListRequest) -> Result<TxListResponse, CexplorerError> {
+    default_client()?.get_tx_list(request).await
 }
 
 pub async fn get_drep_registrations(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<DrepRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "drep_registrations".to_string(),
-    };
-    fetch_with_params::<DrepRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_drep_registrations(request).await
 }
 
 pub async fn get_drep_deregistrations(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<DrepRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "drep_deregistrations".to_string(),
-    };
-    fetch_with_params::<DrepRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_drep_deregistrations(request).await
 }
 
 pub async fn get_drep_updates(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<DrepRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "drep_updates".to_string(),
-    };
-    fetch_with_params::<DrepRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_drep_updates(request).await
 }
 
 pub async fn get_pool_registrations(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<PoolRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "pool_registrations".to_string(),
-    };
-    fetch_with_params::<PoolRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_pool_registrations(request).await
 }
 
 pub async fn get_pool_deregistrations(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<PoolRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "pool_deregistrations".to_string(),
-    };
-    fetch_with_params::<PoolRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_pool_deregistrations(request).await
 }
 
 pub async fn get_stake_registrations(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<StakeRegistrationsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "stake_key_registrations".to_string(),
-    };
-    fetch_with_params::<StakeRegistrationsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_stake_registrations(request).await
 }
 
 pub async fn get_contract_transactions(
-    limit: Option<u64>,
-    offset: Option<u64>,
+    request: TxFilterRequest,
 ) -> Result<ContractInteractionsResponse, CexplorerError> {
-    let endpoint = "/tx/filter";
-    let params = TxFilterParams {
-        limit,
-        offset,
-        filter_type: "contract_transactions".to_string(),
-    };
-    fetch_with_params::<ContractInteractionsResponse, TxFilterParams>(endpoint, Some(&params)).await
+    default_client()?.get_contract_transactions(request).await
 }
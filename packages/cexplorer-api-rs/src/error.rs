@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 
 
@@ -9,18 +10,87 @@ pub enum CexplorerError {
     #[error("Missing required field: {0}")]
     MissingField(String),
 
+    #[error("Unknown network: {0}")]
+    UnknownNetwork(String),
+
     #[error("Invalid API key format")]
     InvalidApiKey,
 
+    #[error("Invalid query parameters: {0}")]
+    InvalidQuery(String),
+
+    #[error("Invalid {kind} `{value}`: {reason}")]
+    InvalidIdentifier {
+        kind: &'static str,
+        value: String,
+        reason: String,
+    },
+
     #[error("Network request failed: {0}")]
     NetworkError(String),
 
     #[error("Request timeout")]
     Timeout,
 
+    #[error("Request cancelled")]
+    Cancelled,
+
     #[error("JSON parsing error: {0}")]
     JsonError(#[from] serde_json::Error),
 
     #[error("HTTP error: {0}")]
     HttpError(#[from] reqwest::Error),
-}
\ No newline at end of file
+
+    #[error("Unauthorized (401): the API key was rejected")]
+    Unauthorized { body: String },
+
+    #[error("Forbidden (403): {body}")]
+    Forbidden { body: String },
+
+    #[error("Not found (404): {endpoint}")]
+    NotFound { endpoint: String },
+
+    #[error("Rate limited (429), retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+
+    #[error("Daily {quota} quota exhausted, next allowance in {reset_in:?}")]
+    QuotaExhausted { quota: String, reset_in: Duration },
+
+    #[error("Server error ({status}): {body}")]
+    ServerError { status: u16, body: String },
+
+    #[error("Unexpected HTTP status ({status}): {body}")]
+    HttpStatus { status: u16, body: String },
+
+    #[error("API error code {code} from {endpoint}: {}", message.as_deref().unwrap_or("no message"))]
+    Api {
+        endpoint: String,
+        code: i64,
+        message: Option<String>,
+    },
+
+    #[error("Failed to deserialize response from {endpoint}: {source}")]
+    Deserialize {
+        endpoint: String,
+        /// Start of the response body, for diagnosing schema drift
+        snippet: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl CexplorerError {
+    /// HTTP status behind the error, if the server answered at all
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            CexplorerError::Unauthorized { .. } => Some(401),
+            CexplorerError::Forbidden { .. } => Some(403),
+            CexplorerError::NotFound { .. } => Some(404),
+            CexplorerError::RateLimited { .. } => Some(429),
+            CexplorerError::ServerError { status, .. } => Some(*status),
+            CexplorerError::HttpStatus { status, .. } => Some(*status),
+            CexplorerError::HttpError(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+}
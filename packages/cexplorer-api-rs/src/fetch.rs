@@ -0,0 +1,117 @@
+use crate::error::CexplorerError;
+use crate::transport::{HttpRequest, HttpResponse, Transport};
+use async_trait::async_trait;
+use gloo_timers::callback::Timeout;
+use js_sys::{Array, Promise, Uint8Array};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, Headers, Request, RequestInit, Response};
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `fetch`, available in windows and workers alike
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_request(request: &Request) -> Promise;
+}
+
+/// Default transport on `wasm32`, sending requests through the browser's `fetch`.
+///
+/// Connection pooling, proxies and TLS are left to the browser, so the
+/// `http` options of the config do not apply. `HttpRequest::timeout` aborts
+/// the request through an `AbortController` and is reported as
+/// `CexplorerError::Timeout`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchTransport;
+
+impl FetchTransport {
+    pub fn new() -> Self {
+        FetchTransport
+    }
+}
+
+#[async_trait(?Send)]
+impl Transport for FetchTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, CexplorerError> {
+        let headers = Headers::new().map_err(js_error)?;
+        for (name, value) in &request.headers {
+            let value = value
+                .to_str()
+                .map_err(|_| CexplorerError::NetworkError(format!("header {} is not valid text", name)))?;
+            headers.append(name.as_str(), value).map_err(js_error)?;
+        }
+
+        let controller = AbortController::new().map_err(js_error)?;
+        let signal = controller.signal();
+
+        let init = RequestInit::new();
+        init.set_method(request.method.as_str());
+        init.set_headers(&headers);
+        init.set_signal(Some(&signal));
+
+        // Dropping the timer cancels it, so it only fires while the request is pending
+        let _deadline = request.timeout.map(|timeout| {
+            let millis = timeout.as_millis().min(u32::MAX as u128) as u32;
+            Timeout::new(millis, move || controller.abort())
+        });
+
+        let aborted = |e: JsValue| {
+            if signal.aborted() {
+                CexplorerError::Timeout
+            } else {
+                js_error(e)
+            }
+        };
+
+        let js_request = Request::new_with_str_and_init(&request.url, &init).map_err(js_error)?;
+        let response: Response = JsFuture::from(fetch_request(&js_request))
+            .await
+            .map_err(&aborted)?
+            .unchecked_into();
+
+        let status = StatusCode::from_u16(response.status())
+            .map_err(|_| CexplorerError::NetworkError(format!("invalid status {}", response.status())))?;
+        let headers = response_headers(&response.headers())?;
+
+        let buffer = JsFuture::from(response.array_buffer().map_err(js_error)?)
+            .await
+            .map_err(&aborted)?;
+        let body = Uint8Array::new(&buffer).to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Copy the headers the browser exposes; CORS hides any the server did not list
+fn response_headers(headers: &Headers) -> Result<HeaderMap, CexplorerError> {
+    let mut map = HeaderMap::new();
+    let Some(entries) = js_sys::try_iter(headers).map_err(js_error)? else {
+        return Ok(map);
+    };
+
+    for entry in entries {
+        let entry: Array = entry.map_err(js_error)?.unchecked_into();
+        let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) else {
+            continue;
+        };
+        if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(name.as_bytes()), HeaderValue::from_str(&value)) {
+            map.append(name, value);
+        }
+    }
+
+    Ok(map)
+}
+
+fn js_error(value: JsValue) -> CexplorerError {
+    let message = match value.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => value.as_string().unwrap_or_else(|| format!("{:?}", value)),
+    };
+    CexplorerError::NetworkError(message)
+}
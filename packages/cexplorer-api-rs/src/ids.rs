@@ -0,0 +1,308 @@
+//! Validated Cardano identifiers accepted by the endpoint methods.
+//!
+//! Each type checks its value offline when parsed, so a malformed id fails
+//! with `CexplorerError::InvalidIdentifier` before a request is sent.
+//! Bech32 ids are checked for their prefix, checksum and payload length, and
+//! hashes for their hex length. Values are stored in the canonical form the
+//! API expects: lowercase, with pool ids always in bech32.
+//!
+//! ```
+//! use cexplorer_api_rs::PoolId;
+//!
+//! let pool: PoolId = "0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735".parse()?;
+//! assert_eq!(pool.as_str(), "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy");
+//! assert_eq!(pool.hash_raw(), "0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735");
+//! # Ok::<(), cexplorer_api_rs::CexplorerError>(())
+//! ```
+
+use crate::error::CexplorerError;
+use bech32::{Bech32, Hrp};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Length in bytes of key, script and policy hashes
+const CREDENTIAL_LEN: usize = 28;
+/// Length in bytes of transaction, block and datum hashes
+const HASH_LEN: usize = 32;
+
+macro_rules! identifier {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $validate:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn parse(value: &str) -> Result<Self, CexplorerError> {
+                let validate: fn(&str) -> Result<String, String> = $validate;
+                validate(value.trim()).map($name).map_err(|reason| CexplorerError::InvalidIdentifier {
+                    kind: $kind,
+                    value: value.to_string(),
+                    reason,
+                })
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = CexplorerError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::parse(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = CexplorerError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::parse(&value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = CexplorerError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $name::parse(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+identifier!(
+    /// Stake pool id, held in bech32 (`pool1…`) form.
+    ///
+    /// Parses from bech32 or from the hex pool hash the API calls `hash_raw`.
+    PoolId,
+    "pool id",
+    |value| {
+        if is_hex(value) {
+            let bytes = decode_hex(value, CREDENTIAL_LEN)?;
+            return encode_bech32("pool", &bytes);
+        }
+        decode_bech32(value, &["pool"], &[CREDENTIAL_LEN])?;
+        Ok(value.to_lowercase())
+    }
+);
+
+impl PoolId {
+    /// Build a pool id from its hex pool hash
+    pub fn from_hash_raw(hash_raw: &str) -> Result<Self, CexplorerError> {
+        PoolId::parse(hash_raw)
+    }
+
+    /// Hex pool hash, as sent in the `hash_raw` parameter
+    pub fn hash_raw(&self) -> String {
+        let (_, bytes) = bech32::decode(&self.0).expect("pool ids are validated on construction");
+        hex::encode(bytes)
+    }
+}
+
+identifier!(
+    /// Reward (stake) address, `stake1…` or `stake_test1…`
+    StakeAddress,
+    "stake address",
+    |value| {
+        let (_, bytes) = decode_bech32(value, &["stake", "stake_test"], &[CREDENTIAL_LEN + 1])?;
+        match bytes[0] >> 4 {
+            0b1110 | 0b1111 => Ok(value.to_lowercase()),
+            _ => Err("header is not a reward address header".to_string()),
+        }
+    }
+);
+
+identifier!(
+    /// Payment address: Shelley bech32 (`addr1…`, `addr_test1…`) or Byron base58.
+    ///
+    /// Byron addresses are only checked for their alphabet and length, not
+    /// decoded.
+    Address,
+    "address",
+    |value| {
+        if value.starts_with("addr") {
+            let (_, bytes) = decode_bech32(value, &["addr", "addr_test"], &[])?;
+            if bytes.len() <= CREDENTIAL_LEN || bytes[0] >> 4 > 0b0111 {
+                return Err("not a Shelley payment address".to_string());
+            }
+            return Ok(value.to_lowercase());
+        }
+        if value.len() >= 50 && value.bytes().all(is_base58) {
+            return Ok(value.to_string());
+        }
+        Err("expected a bech32 `addr` address or a base58 Byron address".to_string())
+    }
+);
+
+identifier!(
+    /// Transaction hash, 64 hex characters
+    TxHash,
+    "transaction hash",
+    |value| hex_id(value, HASH_LEN)
+);
+
+identifier!(
+    /// Block hash, 64 hex characters
+    BlockHash,
+    "block hash",
+    |value| hex_id(value, HASH_LEN)
+);
+
+identifier!(
+    /// Datum hash, 64 hex characters
+    DatumHash,
+    "datum hash",
+    |value| hex_id(value, HASH_LEN)
+);
+
+identifier!(
+    /// Minting policy id, 56 hex characters
+    PolicyId,
+    "policy id",
+    |value| hex_id(value, CREDENTIAL_LEN)
+);
+
+identifier!(
+    /// Script hash, 56 hex characters
+    ScriptHash,
+    "script hash",
+    |value| hex_id(value, CREDENTIAL_LEN)
+);
+
+identifier!(
+    /// CIP-14 asset fingerprint, `asset1…`
+    AssetFingerprint,
+    "asset fingerprint",
+    |value| {
+        decode_bech32(value, &["asset"], &[20])?;
+        Ok(value.to_lowercase())
+    }
+);
+
+identifier!(
+    /// DRep id in CIP-105 (`drep1…`, `drep_script1…`) or CIP-129 bech32, or
+    /// the 56 character hex credential hash
+    DRepId,
+    "DRep id",
+    |value| {
+        if is_hex(value) {
+            return hex_id(value, CREDENTIAL_LEN);
+        }
+        let (hrp, bytes) = decode_bech32(value, &["drep", "drep_script"], &[CREDENTIAL_LEN, CREDENTIAL_LEN + 1])?;
+        if hrp == "drep_script" && bytes.len() != CREDENTIAL_LEN {
+            return Err("drep_script ids carry a bare 28 byte hash".to_string());
+        }
+        Ok(value.to_lowercase())
+    }
+);
+
+impl DRepId {
+    /// Hex credential hash, without the CIP-129 header byte
+    pub fn credential_hash(&self) -> String {
+        if is_hex(&self.0) {
+            return self.0.clone();
+        }
+        let (_, bytes) = bech32::decode(&self.0).expect("DRep ids are validated on construction");
+        hex::encode(&bytes[bytes.len() - CREDENTIAL_LEN..])
+    }
+}
+
+identifier!(
+    /// Governance action id in CIP-129 bech32 (`gov_action1…`), or as the
+    /// proposing transaction hash and index, `<hash>#<index>`
+    GovActionId,
+    "governance action id",
+    |value| {
+        if let Some((hash, index)) = value.split_once('#') {
+            let hash = hex_id(hash, HASH_LEN)?;
+            let index: u32 = index.parse().map_err(|_| format!("invalid index `{}`", index))?;
+            return Ok(format!("{}#{}", hash, index));
+        }
+        decode_bech32(value, &["gov_action"], &[HASH_LEN + 1])?;
+        Ok(value.to_lowercase())
+    }
+);
+
+identifier!(
+    /// Constitutional committee member credential: CIP-105 or CIP-129 bech32
+    /// (`cc_cold1…`, `cc_hot1…` and their `_script` forms), or the 56
+    /// character hex credential hash
+    CommitteeMemberId,
+    "committee member id",
+    |value| {
+        if is_hex(value) {
+            return hex_id(value, CREDENTIAL_LEN);
+        }
+        let hrps = ["cc_cold", "cc_hot", "cc_cold_script", "cc_hot_script"];
+        let (hrp, bytes) = decode_bech32(value, &hrps, &[CREDENTIAL_LEN, CREDENTIAL_LEN + 1])?;
+        if hrp.ends_with("_script") && bytes.len() != CREDENTIAL_LEN {
+            return Err(format!("{} ids carry a bare 28 byte hash", hrp));
+        }
+        Ok(value.to_lowercase())
+    }
+);
+
+fn is_hex(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_base58(b: u8) -> bool {
+    b.is_ascii_alphanumeric() && !matches!(b, b'0' | b'O' | b'I' | b'l')
+}
+
+fn decode_hex(value: &str, len: usize) -> Result<Vec<u8>, String> {
+    if value.len() != len * 2 {
+        return Err(format!("expected {} hex characters, got {}", len * 2, value.len()));
+    }
+    hex::decode(value).map_err(|e| e.to_string())
+}
+
+fn hex_id(value: &str, len: usize) -> Result<String, String> {
+    decode_hex(value, len)?;
+    Ok(value.to_lowercase())
+}
+
+/// Decode `value`, checking the prefix and, unless `lens` is empty, the payload length
+fn decode_bech32(value: &str, hrps: &[&str], lens: &[usize]) -> Result<(String, Vec<u8>), String> {
+    let (hrp, bytes) = bech32::decode(value).map_err(|e| format!("invalid bech32: {}", e))?;
+    let hrp = hrp.to_lowercase();
+
+    if !hrps.contains(&hrp.as_str()) {
+        return Err(format!("expected prefix {}, got `{}`", hrps.join(" or "), hrp));
+    }
+    if !lens.is_empty() && !lens.contains(&bytes.len()) {
+        return Err(format!("unexpected payload length of {} bytes", bytes.len()));
+    }
+    Ok((hrp, bytes))
+}
+
+fn encode_bech32(hrp: &str, bytes: &[u8]) -> Result<String, String> {
+    let hrp = Hrp::parse(hrp).map_err(|e| e.to_string())?;
+    bech32::encode::<Bech32>(hrp, bytes).map_err(|e| e.to_string())
+}
@@ -1,26 +1,58 @@
 mod error;
 mod config;
 mod client;
-mod endpoints;
+mod retry;
+mod network;
+mod cache;
+mod pagination;
+mod batch;
+mod rate_limit;
+mod runtime;
+mod transport;
+#[cfg(target_arch = "wasm32")]
+mod fetch;
+mod ids;
+mod amount;
+mod time;
+pub mod endpoints;
+pub mod params;
 pub mod types;
+pub mod prelude;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
 
 pub use error::CexplorerError;
-pub use config::{init_api, get_config, CexplorerConfig};
-pub use endpoints::block::{get_block_detail, get_block_list, BlockListParams};
-pub use endpoints::address::{
-    get_address_detail, get_address_list, get_address_utxo,
-    inspect_address, AddressListParams
+pub use client::CexplorerClient;
+pub use retry::RetryPolicy;
+pub use rate_limit::{ApiPlan, RateLimit, RateLimitUsage, RateLimiter};
+pub use network::Network;
+pub use ids::{
+    Address, AssetFingerprint, BlockHash, CommitteeMemberId, DRepId, DatumHash, GovActionId, PolicyId,
+    PoolId, ScriptHash, StakeAddress, TxHash,
 };
-pub use endpoints::epoch::{
-    get_epoch_list, get_epoch_detail_param, get_epoch_detail_stats
+pub use amount::{Lovelace, TokenQuantity};
+pub use time::{SlotConfig, Timestamp};
+pub use transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+#[cfg(target_arch = "wasm32")]
+pub use fetch::FetchTransport;
+pub use reqwest::{header, Method, StatusCode};
+pub use tokio_util::sync::CancellationToken;
+pub use cache::{
+    CachePolicy, CacheRule, CacheStats, CacheStore, CachedResponse, MemoryCache, ResponseCache,
+    FINALITY_WINDOW,
 };
-pub use endpoints::pools::{
-    get_pool_blocks, get_pool_delegators, get_pool_reward, get_pool_detail,
-    get_pools_list, get_pools_birthdays, get_pool_update, get_pool_awards,
-    get_pool_delegators_stats, get_global_pool_awards, get_pool_about,
-    get_top_margins_with_delegators, get_retired_pools, get_top_multi_delegators,
-    get_deleg_epoch_registered, get_stake_dreps_not_spo
+pub use pagination::{ListPage, PageRequest, Paginator};
+pub use batch::{BatchResults, DEFAULT_BATCH_CONCURRENCY};
+pub use config::{init_api, init_api_with_config, init_api_with_client, get_config, CexplorerConfig, HttpClientOptions};
+pub use endpoints::Endpoint;
+pub use endpoints::{
+    account::*, address::*, analytics::*, article::*, assets::*, block::*, datum::*,
+    delegations::*, drep::*, epoch::*, governance::*, metadata::*, misc::*, policy::*,
+    pools::*, scripts::*, stake::*, token::*, tool::*, treasury::*, tx::*, wallet::*,
 };
+pub use params::*;
 pub use types::{
     BlockDetailResponse, BlocksListResponse,
     AddressDetailResponse, AddressDetailUTXOResponse,
@@ -31,4 +63,4 @@ pub use types::{
     PoolAwardsResponse, PoolDelegatorStatsResponse, PoolAboutResponse,
     TopMarginsWithDelegatorsResponse, RetiredPoolsResponse, PoolBirthdaysResponse,
     TopMultiDelegatorsResponse, DelegEpochRegisteredResponse, StakeDrepsNotSpoResponse
-};
\ No newline at end of file
+};
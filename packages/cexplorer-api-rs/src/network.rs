@@ -0,0 +1,98 @@
+use crate::error::CexplorerError;
+use crate::time::SlotConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Cexplorer deployment to talk to.
+///
+/// Parses from and displays as the names used by the TypeScript SDK
+/// (`"mainnet-stage"`, `"preprod"`, ...). Any `http://` or `https://` URL
+/// parses as `Custom`, which is useful for self-hosted gateways, caching
+/// proxies and local mock servers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Network {
+    Mainnet,
+    Preprod,
+    Preview,
+    MainnetStage,
+    PreprodStage,
+    PreviewStage,
+    /// Full base URL including the API version, e.g. `http://localhost:8080/v1`
+    Custom(String),
+}
+
+impl Network {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Preprod => "preprod",
+            Network::Preview => "preview",
+            Network::MainnetStage => "mainnet-stage",
+            Network::PreprodStage => "preprod-stage",
+            Network::PreviewStage => "preview-stage",
+            Network::Custom(url) => url,
+        }
+    }
+
+    /// Base API URL for the network, without a trailing slash
+    ///
+    /// `Network::PreprodStage.base_url()` → `"https://api-preprod-stage.cexplorer.io/v1"`
+    pub fn base_url(&self) -> String {
+        match self {
+            Network::Custom(url) => url.trim_end_matches('/').to_string(),
+            network => format!("https://api-{}.cexplorer.io/v1", network.as_str()),
+        }
+    }
+
+    /// Slot timing of the chain behind the network, `None` for `Custom`
+    pub fn slot_config(&self) -> Option<SlotConfig> {
+        match self {
+            Network::Mainnet | Network::MainnetStage => Some(SlotConfig::MAINNET),
+            Network::Preprod | Network::PreprodStage => Some(SlotConfig::PREPROD),
+            Network::Preview | Network::PreviewStage => Some(SlotConfig::PREVIEW),
+            Network::Custom(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Network {
+    type Err = CexplorerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(CexplorerError::MissingField("network".to_string())),
+            "mainnet" => Ok(Network::Mainnet),
+            "preprod" => Ok(Network::Preprod),
+            "preview" => Ok(Network::Preview),
+            "mainnet-stage" => Ok(Network::MainnetStage),
+            "preprod-stage" => Ok(Network::PreprodStage),
+            "preview-stage" => Ok(Network::PreviewStage),
+            url if url.starts_with("http://") || url.starts_with("https://") => {
+                Ok(Network::Custom(url.to_string()))
+            }
+            other => Err(CexplorerError::UnknownNetwork(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Network {
+    type Error = CexplorerError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Network> for String {
+    fn from(network: Network) -> Self {
+        network.as_str().to_string()
+    }
+}
@@ -0,0 +1,411 @@
+use crate::client::CexplorerClient;
+use crate::error::CexplorerError;
+use crate::types::account_types::{AccountReward, RewardItem, WithdrawalItem, WithdrawalsData};
+use crate::types::address_types::{AddressDetail, AddressDetailData, AddressList, AddressListItem, AddressUTXO, UTXO};
+use crate::types::analytics_types::{
+    AnalyticsTopAddress, AnalyticsTopStakingAccount, GenesisAddrData, GenesisAddress, TopAddresses, TopStakingAccounts,
+};
+use crate::types::article_types::{ArticleList, ArticleListData};
+use crate::types::assets_types::{AssetList, AssetListData, AssetOwnersNft, AssetOwnersNftItem};
+use crate::types::block_types::{BlocksListResponse, BlocksListResponseDataItem};
+use crate::types::common_types::ResponseCore;
+use crate::types::contract_types::{ContractInteractions, ContractInteractionsData};
+use crate::types::delegation_types::{DelegationData, DelegationToRetiredData, Delegations, DelegationsToRetired};
+use crate::types::drep_types::{
+    DelegatorData, DrepDelegators, DrepList, DrepListData, DrepRegistrations, DrepRegistrationsData, DrepVote,
+    DrepVoteItem,
+};
+use crate::types::epoch_types::{EpochList, EpochListData};
+use crate::types::metadata_types::{MetadataTxListData, MetadataTxListItem};
+use crate::types::governance_types::*;
+use crate::types::policy_types::{PolicyOwner, PolicyOwners};
+use crate::types::pool_types::*;
+use crate::types::script_types::{ScriptDetailRedeemerDataItem, ScriptDetailRedeemers, ScriptList, ScriptListData};
+use crate::types::stake_types::{StakeRegistrationsData, StakeRegistrationsDataItem};
+use crate::types::token_types::{DeFiOrder, DeFiOrderListData, DeFiTokenListData, DeFiTokenListItem};
+use crate::types::tx_types::{TxBasicInfo, TxListData};
+use futures::stream::{self, FuturesOrdered, Stream, StreamExt};
+use serde_json::Value;
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+
+/// Window of a list endpoint to fetch, passed to the paginator's fetch closure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: u64,
+    pub offset: u64,
+}
+
+/// One page of a `{ count, data }` list response
+pub trait ListPage {
+    type Item;
+
+    /// Total number of items reported by the API, if it sent one
+    fn total(&self) -> Option<u64>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl<T: ListPage> ListPage for ResponseCore<T> {
+    type Item = T::Item;
+
+    fn total(&self) -> Option<u64> {
+        self.data.total()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data.into_items()
+    }
+}
+
+impl ListPage for BlocksListResponse {
+    type Item = BlocksListResponseDataItem;
+
+    fn total(&self) -> Option<u64> {
+        Some(self.data.count)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data.data
+    }
+}
+
+/// The API reports `count` as an integer on some lists and a float on others
+trait PageCount {
+    fn as_count(&self) -> Option<u64>;
+}
+
+impl PageCount for u64 {
+    fn as_count(&self) -> Option<u64> {
+        Some(*self)
+    }
+}
+
+impl PageCount for Option<u64> {
+    fn as_count(&self) -> Option<u64> {
+        *self
+    }
+}
+
+impl PageCount for Option<f64> {
+    fn as_count(&self) -> Option<u64> {
+        self.map(|count| count as u64)
+    }
+}
+
+macro_rules! impl_list_page {
+    ($($page:ty => $item:ty),* $(,)?) => {
+        $(
+            impl ListPage for $page {
+                type Item = $item;
+
+                fn total(&self) -> Option<u64> {
+                    self.count.as_count()
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.data
+                }
+            }
+        )*
+    };
+}
+
+impl_list_page! {
+    AccountReward => RewardItem,
+    WithdrawalsData => WithdrawalItem,
+    AddressDetail => AddressDetailData,
+    AddressList => AddressListItem,
+    AddressUTXO => UTXO,
+    GenesisAddrData => GenesisAddress,
+    ArticleList => ArticleListData,
+    TopStakingAccounts => AnalyticsTopStakingAccount,
+    TopAddresses => AnalyticsTopAddress,
+    AssetListData => AssetList,
+    AssetOwnersNft => AssetOwnersNftItem,
+    ContractInteractions => ContractInteractionsData,
+    Delegations => DelegationData,
+    DelegationsToRetired => DelegationToRetiredData,
+    DrepList => DrepListData,
+    DrepRegistrations => DrepRegistrationsData,
+    DrepVote => DrepVoteItem,
+    DrepDelegators => DelegatorData,
+    EpochList => EpochListData,
+    MetadataTxListData => MetadataTxListItem,
+    GovernanceActionListData => GovernanceActionList,
+    GovernanceVoteDetailData => GovernanceVote,
+    CommitteeListData => CommitteeListItem,
+    ConstitutionListData => ConstitutionDataItem,
+    DrepListVoteData => DrepListVoteItem,
+    PoolsList => PoolData,
+    PoolRewards => PoolRewardData,
+    PoolDelegators => PoolDelegatorData,
+    PoolUpdates => PoolUpdateData,
+    PoolAwards => PoolAward,
+    PoolDelegatorStats => Value,
+    TopMarginsWithDelegators => TopMarginsData,
+    RetiredPools => RetiredPoolItem,
+    TopMultiDelegators => TopMultiDelegatorsItem,
+    PoolRegistrationsResponseData => PoolRegistrationsData,
+    PolicyOwners => PolicyOwner,
+    ScriptDetailRedeemers => ScriptDetailRedeemerDataItem,
+    ScriptList => ScriptListData,
+    StakeRegistrationsData => StakeRegistrationsDataItem,
+    DeFiTokenListData => DeFiTokenListItem,
+    DeFiOrderListData => DeFiOrder,
+    TxListData => TxBasicInfo,
+}
+
+type KeyFn<T> = Box<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Walks a `limit`/`offset` list endpoint and yields its items as a `Stream`.
+///
+/// The fetch closure receives a [`PageRequest`] and calls the endpoint with
+/// its `limit` and `offset`. Paging stops at the first short page, once the
+/// reported `count` is reached, at `max_items`, or after the first error,
+/// which is yielded as the stream's last item.
+///
+/// ```no_run
+/// use cexplorer_api_rs::prelude::*;
+/// use futures::TryStreamExt;
+///
+/// # async fn run() -> Result<(), CexplorerError> {
+/// let client = CexplorerClient::from_credentials("mainnet", "api-key")?;
+/// let pools: Vec<PoolData> = client
+///     .paginate(|client, page| async move {
+///         client
+///             .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+///             .await
+///     })
+///     .page_size(100)
+///     .max_items(1_000)
+///     .concurrency(3)
+///     .stable_cursor(|pool: &PoolData| pool.pool_id.clone())
+///     .into_stream()
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<R: ListPage, F> {
+    fetch: F,
+    page_size: u64,
+    start_offset: u64,
+    max_items: Option<u64>,
+    concurrency: usize,
+    overlap: Option<u64>,
+    key: Option<KeyFn<R::Item>>,
+}
+
+impl<R, F, Fut> Paginator<R, F>
+where
+    R: ListPage,
+    F: Fn(PageRequest) -> Fut,
+    Fut: Future<Output = Result<R, CexplorerError>>,
+{
+    pub fn new(fetch: F) -> Self {
+        Paginator {
+            fetch,
+            page_size: 100,
+            start_offset: 0,
+            max_items: None,
+            concurrency: 1,
+            overlap: None,
+            key: None,
+        }
+    }
+
+    /// Items requested per call, 100 by default
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    pub fn start_offset(mut self, offset: u64) -> Self {
+        self.start_offset = offset;
+        self
+    }
+
+    /// Stop after yielding this many items
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Pages kept in flight at once; 1 fetches strictly one page after another
+    ///
+    /// The first page is always fetched alone so the reported `count` can
+    /// bound the prefetching that follows.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Cope with items shifting between pages while the list is walked.
+    ///
+    /// Offset paging over a live list repeats items when new ones are
+    /// inserted ahead of the cursor and skips items when earlier ones drop
+    /// out. In stable-cursor mode consecutive pages overlap (by a tenth of
+    /// the page size unless [`overlap`](Self::overlap) is set) and items
+    /// whose key was already yielded are dropped.
+    pub fn stable_cursor<K>(mut self, key: K) -> Self
+    where
+        K: Fn(&R::Item) -> String + Send + Sync + 'static,
+    {
+        self.key = Some(Box::new(key));
+        self
+    }
+
+    /// Items each page re-reads from the end of the previous one in stable-cursor mode
+    pub fn overlap(mut self, overlap: u64) -> Self {
+        self.overlap = Some(overlap);
+        self
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<R::Item, CexplorerError>> {
+        let overlap = match self.key {
+            Some(_) => self
+                .overlap
+                .unwrap_or((self.page_size / 10).max(1))
+                .min(self.page_size - 1),
+            None => 0,
+        };
+
+        let state = PageState {
+            fetch: self.fetch,
+            page_size: self.page_size,
+            step: self.page_size - overlap,
+            start_offset: self.start_offset,
+            max_items: self.max_items,
+            concurrency: self.concurrency,
+            key: self.key,
+            in_flight: FuturesOrdered::new(),
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+            next_offset: self.start_offset,
+            total: None,
+            pages_received: 0,
+            yielded: 0,
+            exhausted: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            let item = state.next_item().await?;
+            Some((item, state))
+        })
+    }
+}
+
+struct PageState<R: ListPage, F, Fut: Future> {
+    fetch: F,
+    page_size: u64,
+    step: u64,
+    start_offset: u64,
+    max_items: Option<u64>,
+    concurrency: usize,
+    key: Option<KeyFn<R::Item>>,
+    in_flight: FuturesOrdered<Fut>,
+    buffer: VecDeque<R::Item>,
+    seen: HashSet<String>,
+    next_offset: u64,
+    total: Option<u64>,
+    pages_received: u64,
+    yielded: u64,
+    exhausted: bool,
+}
+
+impl<R, F, Fut> PageState<R, F, Fut>
+where
+    R: ListPage,
+    F: Fn(PageRequest) -> Fut,
+    Fut: Future<Output = Result<R, CexplorerError>>,
+{
+    async fn next_item(&mut self) -> Option<Result<R::Item, CexplorerError>> {
+        loop {
+            if self.max_items.is_some_and(|max| self.yielded >= max) {
+                return None;
+            }
+
+            if let Some(item) = self.buffer.pop_front() {
+                if let Some(key) = &self.key {
+                    if !self.seen.insert(key(&item)) {
+                        continue;
+                    }
+                }
+                self.yielded += 1;
+                return Some(Ok(item));
+            }
+
+            self.schedule();
+
+            match self.in_flight.next().await? {
+                Ok(page) => {
+                    self.pages_received += 1;
+                    if let Some(total) = page.total() {
+                        self.total = Some(total);
+                    }
+
+                    let items = page.into_items();
+                    if (items.len() as u64) < self.page_size {
+                        // Anything still in flight lies past the end of the list
+                        self.stop();
+                    }
+                    self.buffer.extend(items);
+                }
+                Err(e) => {
+                    self.stop();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    fn schedule(&mut self) {
+        if self.exhausted {
+            return;
+        }
+
+        let slots = if self.pages_received == 0 { 1 } else { self.concurrency };
+
+        while self.in_flight.len() < slots {
+            if self.total.is_some_and(|total| self.next_offset >= total) {
+                break;
+            }
+
+            // With overlapping pages some items are dropped as duplicates, so
+            // only the yield count can tell when `max_items` is reached
+            if self.key.is_none()
+                && self
+                    .max_items
+                    .is_some_and(|max| self.next_offset >= self.start_offset + max)
+            {
+                break;
+            }
+
+            let request = PageRequest {
+                limit: self.page_size,
+                offset: self.next_offset,
+            };
+            self.in_flight.push_back((self.fetch)(request));
+            self.next_offset += self.step;
+        }
+    }
+
+    fn stop(&mut self) {
+        self.exhausted = true;
+        self.in_flight = FuturesOrdered::new();
+    }
+}
+
+impl CexplorerClient {
+    /// Paginate a list endpoint, handing the fetch closure its own clone of this client
+    pub fn paginate<R, F, Fut>(&self, fetch: F) -> Paginator<R, impl Fn(PageRequest) -> Fut>
+    where
+        R: ListPage,
+        F: Fn(CexplorerClient, PageRequest) -> Fut,
+        Fut: Future<Output = Result<R, CexplorerError>>,
+    {
+        let client = self.clone();
+        Paginator::new(move |page| fetch(client.clone(), page))
+    }
+}
@@ -0,0 +1,276 @@
+//! Typed values for the `sort`, `order`, `type` and `filter` query parameters.
+//!
+//! Every enum lists the values the API is known to accept and keeps an
+//! `Other` variant for anything newer, so a value missing here can still be
+//! sent. Values serialize to the exact strings the API expects and parse
+//! back from them with `FromStr`, which never fails.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident { $($variant:ident => $value:literal),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)+
+            /// A value not known to this version of the crate, sent as is
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Other(value) => value,
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($value => $name::$variant,)+
+                    other => $name::Other(other.to_string()),
+                })
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                let Ok(value) = s.parse();
+                value
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+string_enum!(
+    /// Direction of the `sort` parameter
+    SortDirection {
+        Asc => "asc",
+        Desc => "desc",
+    }
+);
+
+string_enum!(
+    /// Column the pool list is ordered by
+    PoolListOrder {
+        Ranking => "ranking",
+        LiveStake => "live_stake",
+        ActiveStake => "active_stake",
+        Delegators => "delegators",
+        Pledge => "pledge",
+        Pledged => "pledged",
+        RoaLifetime => "roa_lifetime",
+        AverageStake => "average_stake",
+        Blocks => "blocks",
+        RoaRecent => "roa_recent",
+        BlocksEpoch => "blocks_epoch",
+        BlocksTotal => "blocks_total",
+        SlotUpdate => "slot_update",
+        New => "new",
+        Update => "update",
+        TopDelegator => "top_delegator",
+        Leverage => "leverage",
+    }
+);
+
+string_enum!(
+    /// Column pool and DRep delegator lists are ordered by
+    DelegatorOrder {
+        LiveStake => "live_stake",
+        SlotUpdate => "slot_update",
+    }
+);
+
+string_enum!(
+    /// Which delegators of a pool to list
+    PoolDelegatorType {
+        Live => "live",
+        Gone => "gone",
+    }
+);
+
+string_enum!(
+    /// Stake snapshot used for retired pools and their delegations
+    StakeSnapshot {
+        Live => "live",
+        Active => "active",
+    }
+);
+
+string_enum!(
+    /// Column retired pools and delegations to them are ordered by
+    RetiredOrder {
+        Date => "date",
+        LiveStake => "live_stake",
+    }
+);
+
+string_enum!(
+    /// Ranking used by the top pools analytics
+    TopPoolType {
+        Margin => "margin",
+        Delegators => "delegators",
+    }
+);
+
+string_enum!(
+    /// Column the address list is ordered by
+    AddressListOrder {
+        Balance => "balance",
+        Last => "last",
+    }
+);
+
+string_enum!(
+    /// Column the asset list is ordered by
+    AssetListOrder {
+        CollectionQuantity => "collection_quantity",
+        Native => "native",
+        Mint => "mint",
+    }
+);
+
+string_enum!(
+    /// Kind of asset to list
+    AssetFilter {
+        Nft => "nft",
+        Token => "token",
+    }
+);
+
+string_enum!(
+    /// Column the DRep list is ordered by
+    DrepListOrder {
+        AveragePower => "average_power",
+        Power => "power",
+        Own => "own",
+        Since => "since",
+        Delegator => "delegator",
+        AverageStake => "average_stake",
+        TopDelegator => "top_delegator",
+    }
+);
+
+string_enum!(
+    /// Which delegators of a DRep to list
+    DrepDelegatorFilter {
+        Live => "live",
+        Migrations => "migrations",
+    }
+);
+
+string_enum!(
+    /// Role of a governance voter
+    VoterRole {
+        DRep => "DRep",
+        Spo => "SPO",
+        ConstitutionalCommittee => "ConstitutionalCommittee",
+    }
+);
+
+string_enum!(
+    /// Governance vote choice
+    Vote {
+        Yes => "Yes",
+        No => "No",
+        Abstain => "Abstain",
+    }
+);
+
+string_enum!(
+    /// State of a governance action proposal
+    GovActionState {
+        All => "All",
+        Active => "Active",
+        Ratified => "Ratified",
+        Enacted => "Enacted",
+        Expired => "Expired",
+    }
+);
+
+string_enum!(
+    /// Kind of governance action
+    GovActionType {
+        ParameterChange => "ParameterChange",
+        HardForkInitiation => "HardForkInitiation",
+        TreasuryWithdrawals => "TreasuryWithdrawals",
+        NoConfidence => "NoConfidence",
+        NewCommittee => "NewCommittee",
+        NewConstitution => "NewConstitution",
+        InfoAction => "InfoAction",
+    }
+);
+
+string_enum!(
+    /// Column the script list is ordered by
+    ScriptListOrder {
+        Tx => "tx",
+        RedeemerCount => "redeemer.count",
+        PaymentOutputSum => "tx_payment_cred.out.sum",
+    }
+);
+
+string_enum!(
+    /// Column the DeFi token list is ordered by
+    DeFiTokenOrder {
+        PriceAda => "price_ada",
+        Volume => "volume",
+        LiquidityAda => "liquidity_ada",
+    }
+);
+
+string_enum!(
+    /// Kind of identifier `misc_validate` checks
+    ValidateType {
+        Pool => "pool",
+        Ident => "ident",
+        Asset => "asset",
+        Drep => "drep",
+        Collection => "collection",
+    }
+);
+
+string_enum!(
+    /// Kind of transaction reported to `send_tx_sent`
+    TxSentType {
+        Delegation => "delegation",
+        Donate => "donate",
+    }
+);
+
+string_enum!(
+    /// Kind of article content
+    ArticleType {
+        Page => "page",
+        Article => "article",
+    }
+);
@@ -0,0 +1,31 @@
+//! Everything needed to call the API in one import.
+//!
+//! ```no_run
+//! use cexplorer_api_rs::prelude::*;
+//!
+//! # async fn run() -> Result<(), CexplorerError> {
+//! let client = CexplorerClient::from_credentials("mainnet", "api-key")?;
+//! let stats: DrepStatResponse = client.get_drep_stat().await?;
+//! println!("{:?}", stats.data);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::{
+    get_config, init_api, init_api_with_client, init_api_with_config, ApiPlan, BatchResults, CachePolicy,
+    CacheRule, CancellationToken, CexplorerClient, CexplorerConfig, CexplorerError, Endpoint,
+    HttpClientOptions, ListPage, MemoryCache, Network, PageRequest, Paginator, RateLimit, RateLimiter,
+    ResponseCache, RetryPolicy, Transport,
+};
+pub use crate::{
+    Address, AssetFingerprint, BlockHash, CommitteeMemberId, DRepId, DatumHash, GovActionId, Lovelace,
+    PolicyId, PoolId, ScriptHash, StakeAddress, TokenQuantity, TxHash,
+};
+pub use crate::{SlotConfig, Timestamp};
+pub use crate::endpoints::{
+    account::*, address::*, analytics::*, article::*, assets::*, block::*, datum::*,
+    delegations::*, drep::*, epoch::*, governance::*, metadata::*, misc::*, policy::*,
+    pools::*, scripts::*, stake::*, token::*, tool::*, treasury::*, tx::*, wallet::*,
+};
+pub use crate::params::*;
+pub use crate::types::*;
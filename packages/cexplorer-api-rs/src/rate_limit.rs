@@ -0,0 +1,257 @@
+use crate::error::CexplorerError;
+use crate::types::misc_types::{MiscApiData, MiscApiTier};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use crate::runtime::{self, Instant};
+use std::time::Duration;
+
+const MINUTE: Duration = Duration::from_secs(60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Request and token allowances of an API plan
+///
+/// Limits left as `None`, and limits that are zero, negative or not finite,
+/// are not enforced. A fractional rate such as `0.5` requests per minute lets
+/// one request through every two minutes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_minute: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_day: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_per_day: Option<f64>,
+}
+
+impl RateLimit {
+    pub fn per_minute(requests: f64) -> Self {
+        RateLimit {
+            requests_per_minute: Some(requests),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_requests_per_day(mut self, requests: f64) -> Self {
+        self.requests_per_day = Some(requests);
+        self
+    }
+
+    pub fn with_tokens_per_day(mut self, tokens: f64) -> Self {
+        self.tokens_per_day = Some(tokens);
+        self
+    }
+}
+
+impl From<&MiscApiTier> for RateLimit {
+    fn from(tier: &MiscApiTier) -> Self {
+        RateLimit {
+            requests_per_minute: tier.rq_min,
+            requests_per_day: tier.rq_day,
+            tokens_per_day: tier.tok_day,
+        }
+    }
+}
+
+/// API plans listed by `/misc/api`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiPlan {
+    Starter,
+    Basic,
+    Pro,
+}
+
+impl ApiPlan {
+    pub fn tier<'a>(&self, plans: &'a MiscApiData) -> &'a MiscApiTier {
+        match self {
+            ApiPlan::Starter => &plans.starter,
+            ApiPlan::Basic => &plans.basic,
+            ApiPlan::Pro => &plans.pro,
+        }
+    }
+}
+
+/// Requests and tokens consumed through a limiter since it was created
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitUsage {
+    pub requests: u64,
+    pub tokens: f64,
+    /// Requests left in the rolling daily allowance
+    pub requests_remaining: Option<f64>,
+    /// Tokens left in the rolling daily budget
+    pub tokens_remaining: Option<f64>,
+}
+
+/// Token-bucket limiter shared by every clone of a client.
+///
+/// Callers wait for a slot in the per-minute bucket, so concurrent tasks are
+/// spread out instead of tripping the server's 429s. The daily request and
+/// token allowances refill continuously over 24 hours; once either runs out,
+/// requests fail with `CexplorerError::QuotaExhausted` rather than blocking
+/// for hours. Token costs are taken from the `tokens` field of each response.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    per_minute: Option<Bucket>,
+    per_day: Option<Bucket>,
+    tokens: Option<Bucket>,
+    requests_made: u64,
+    tokens_spent: f64,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        let now = Instant::now();
+        let state = LimiterState {
+            per_minute: Bucket::enforced(limit.requests_per_minute, MINUTE, now),
+            per_day: Bucket::enforced(limit.requests_per_day, DAY, now),
+            tokens: Bucket::enforced(limit.tokens_per_day, DAY, now),
+            requests_made: 0,
+            tokens_spent: 0.0,
+        };
+
+        RateLimiter {
+            limit,
+            state: Mutex::new(state),
+        }
+    }
+
+    pub fn limit(&self) -> &RateLimit {
+        &self.limit
+    }
+
+    /// Wait until a request may be sent and reserve it
+    pub async fn acquire(&self) -> Result<(), CexplorerError> {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+
+                if let Some(tokens) = state.tokens.as_mut() {
+                    tokens.refill(now);
+                    if tokens.available <= 0.0 {
+                        return Err(CexplorerError::QuotaExhausted {
+                            quota: "token".to_string(),
+                            reset_in: tokens.wait_for(f64::EPSILON),
+                        });
+                    }
+                }
+
+                if let Some(per_day) = state.per_day.as_mut() {
+                    per_day.refill(now);
+                    if per_day.available < 1.0 {
+                        return Err(CexplorerError::QuotaExhausted {
+                            quota: "request".to_string(),
+                            reset_in: per_day.wait_for(1.0),
+                        });
+                    }
+                }
+
+                let wait = match state.per_minute.as_mut() {
+                    Some(per_minute) => {
+                        per_minute.refill(now);
+                        per_minute.wait_for(1.0)
+                    }
+                    None => Duration::ZERO,
+                };
+
+                if wait.is_zero() {
+                    if let Some(per_minute) = state.per_minute.as_mut() {
+                        per_minute.available -= 1.0;
+                    }
+                    if let Some(per_day) = state.per_day.as_mut() {
+                        per_day.available -= 1.0;
+                    }
+                    state.requests_made += 1;
+                    return Ok(());
+                }
+
+                wait
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?wait, "waiting for rate limiter");
+            runtime::sleep(wait).await;
+        }
+    }
+
+    /// Charge the token cost reported by a response against the daily budget
+    pub fn record_tokens(&self, tokens: f64) {
+        let mut state = self.state.lock().unwrap();
+        state.tokens_spent += tokens;
+        if let Some(bucket) = state.tokens.as_mut() {
+            bucket.refill(Instant::now());
+            bucket.available -= tokens;
+        }
+    }
+
+    pub fn usage(&self) -> RateLimitUsage {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+
+        let requests_remaining = state.per_day.as_mut().map(|bucket| {
+            bucket.refill(now);
+            bucket.available.max(0.0)
+        });
+        let tokens_remaining = state.tokens.as_mut().map(|bucket| {
+            bucket.refill(now);
+            bucket.available.max(0.0)
+        });
+
+        RateLimitUsage {
+            requests: state.requests_made,
+            tokens: state.tokens_spent,
+            requests_remaining,
+            tokens_remaining,
+        }
+    }
+}
+
+/// Allowance of `rate` per `period` that refills evenly over the period
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    available: f64,
+    refill_per_sec: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    /// Bucket for a configured limit, or `None` when it is not enforced
+    fn enforced(rate: Option<f64>, period: Duration, now: Instant) -> Option<Self> {
+        rate.filter(|rate| rate.is_finite() && *rate > 0.0)
+            .map(|rate| Bucket::new(rate, period, now))
+    }
+
+    /// Holds at least one unit, so a rate below one per period still lets a
+    /// request through instead of waiting for an amount it can never reach
+    fn new(rate: f64, period: Duration, now: Instant) -> Self {
+        let capacity = rate.max(1.0);
+        Bucket {
+            capacity,
+            available: capacity,
+            refill_per_sec: rate / period.as_secs_f64(),
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.available = (self.available + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Time until `amount` is available, zero if it already is
+    fn wait_for(&self, amount: f64) -> Duration {
+        let missing = amount - self.available;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::try_from_secs_f64(missing / self.refill_per_sec).unwrap_or(Duration::MAX)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::CexplorerError;
+use crate::runtime;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// When and how often failed requests are retried.
+///
+/// Only idempotent GET requests are retried. Transient failures are
+/// connection errors, timeouts, 429 and 5xx gateway responses. Backoff grows
+/// exponentially from `initial_backoff_ms` up to `max_backoff_ms`. A
+/// `Retry-After` header on 429/503 takes precedence when present, but never
+/// holds a request for longer than `max_backoff_ms` either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub multiplier: f64,
+    /// Randomize each delay between half and the full backoff
+    pub jitter: bool,
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            multiplier: 2.0,
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that sends every request exactly once
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff_ms = initial.as_millis() as u64;
+        self.max_backoff_ms = max.as_millis() as u64;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub(crate) fn attempts_for(&self, method: &Method) -> u32 {
+        if method == Method::GET || method == Method::HEAD {
+            self.max_attempts.max(1)
+        } else {
+            1
+        }
+    }
+
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    pub(crate) fn is_retryable_error(error: &CexplorerError) -> bool {
+        match error {
+            #[cfg(not(target_arch = "wasm32"))]
+            CexplorerError::HttpError(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            // reqwest has no connection phase to report on wasm32
+            #[cfg(target_arch = "wasm32")]
+            CexplorerError::HttpError(e) => e.is_timeout() || e.is_request(),
+            CexplorerError::NetworkError(_) | CexplorerError::Timeout => true,
+            _ => false,
+        }
+    }
+
+    /// Delay before attempt number `attempt + 1`, where `attempt` starts at 1
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let millis = (self.initial_backoff_ms as f64 * self.multiplier.powi(exponent))
+            .min(self.max_backoff_ms as f64) as u64;
+
+        if self.jitter && millis > 0 {
+            Duration::from_millis(fastrand::u64(millis / 2..=millis))
+        } else {
+            Duration::from_millis(millis)
+        }
+    }
+
+    /// Delay requested by the server, honored on 429 and 503 only and capped
+    /// at `max_backoff_ms`
+    pub(crate) fn retry_after(&self, status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+        if !self.respect_retry_after {
+            return None;
+        }
+
+        if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::SERVICE_UNAVAILABLE {
+            return None;
+        }
+
+        let max = Duration::from_millis(self.max_backoff_ms);
+        parse_retry_after(headers).map(|delay| delay.min(max))
+    }
+}
+
+/// Read `Retry-After` as either delta-seconds or an HTTP date
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(runtime::now()).unwrap_or(Duration::ZERO))
+}
@@ -0,0 +1,279 @@
+//! Response timestamps, and conversions between slots, epochs and wall-clock
+//! time.
+//!
+//! Timestamp fields are [`Timestamp`]s, which keep the text the API sent
+//! whichever features are enabled. The API sends them in UTC without an
+//! offset (`2024-02-23T08:12:09`); RFC 3339 values and bare dates are
+//! understood as well. With the `chrono` feature,
+//! [`Timestamp::to_datetime`] converts them to `chrono::DateTime<Utc>`.
+//!
+//! ```
+//! use cexplorer_api_rs::{Network, SlotConfig};
+//!
+//! let mainnet = Network::Mainnet.slot_config().unwrap();
+//! assert_eq!(mainnet, SlotConfig::MAINNET);
+//! assert_eq!(mainnet.epoch_start_slot(500), Some(130_636_800));
+//! assert_eq!(mainnet.slot_to_epoch(130_636_800), Some(500));
+//! assert_eq!(mainnet.slot_to_unix(130_636_800), Some(1_722_203_091));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Range;
+
+/// A timestamp from a response, held as the text the API sent
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    pub fn new(value: impl Into<String>) -> Self {
+        Timestamp(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Unix time in seconds, `None` if the text is not a timestamp.
+    /// Fractions of a second are dropped.
+    pub fn unix(&self) -> Option<i64> {
+        unix_seconds(&self.0)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp {
+    /// Time as UTC, `None` if the text is not a timestamp
+    pub fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+        let value = self.0.trim();
+        if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+            return Some(time.with_timezone(&Utc));
+        }
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+            if let Ok(time) = NaiveDateTime::parse_from_str(value, format) {
+                return Some(time.and_utc());
+            }
+        }
+        let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+        Some(date.and_time(NaiveTime::MIN).and_utc())
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        Timestamp(value.to_string())
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+/// Slot timing of a network from its Shelley hard fork onwards.
+///
+/// `slot_length` and `epoch_length` are the Shelley genesis values; the
+/// `shelley_*` fields place the first Shelley slot on the chain. Slots before
+/// the hard fork had Byron timing and convert to `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlotConfig {
+    /// Unix time, in seconds, of the first Shelley slot
+    pub shelley_start: i64,
+    /// Absolute number of the first Shelley slot
+    pub shelley_slot: u64,
+    /// Number of the first Shelley epoch
+    pub shelley_epoch: u64,
+    /// Slot length in seconds
+    pub slot_length: u64,
+    /// Epoch length in slots
+    pub epoch_length: u64,
+}
+
+impl SlotConfig {
+    pub const MAINNET: SlotConfig = SlotConfig {
+        shelley_start: 1_596_059_091,
+        shelley_slot: 4_492_800,
+        shelley_epoch: 208,
+        slot_length: 1,
+        epoch_length: 432_000,
+    };
+
+    pub const PREPROD: SlotConfig = SlotConfig {
+        shelley_start: 1_655_769_600,
+        shelley_slot: 86_400,
+        shelley_epoch: 4,
+        slot_length: 1,
+        epoch_length: 432_000,
+    };
+
+    pub const PREVIEW: SlotConfig = SlotConfig {
+        shelley_start: 1_666_656_000,
+        shelley_slot: 0,
+        shelley_epoch: 0,
+        slot_length: 1,
+        epoch_length: 86_400,
+    };
+
+    /// Unix time, in seconds, at which `slot` starts
+    pub fn slot_to_unix(&self, slot: u64) -> Option<i64> {
+        let elapsed = slot.checked_sub(self.shelley_slot)?.checked_mul(self.slot_length)?;
+        self.shelley_start.checked_add(i64::try_from(elapsed).ok()?)
+    }
+
+    /// Slot in progress at unix time `unix`, in seconds
+    pub fn unix_to_slot(&self, unix: i64) -> Option<u64> {
+        let elapsed = u64::try_from(unix.checked_sub(self.shelley_start)?).ok()?;
+        self.shelley_slot.checked_add(elapsed / self.slot_length)
+    }
+
+    /// Epoch `slot` belongs to
+    pub fn slot_to_epoch(&self, slot: u64) -> Option<u64> {
+        let slots = slot.checked_sub(self.shelley_slot)?;
+        Some(self.shelley_epoch + slots / self.epoch_length)
+    }
+
+    /// Position of `slot` within its epoch
+    pub fn epoch_slot(&self, slot: u64) -> Option<u64> {
+        Some(slot.checked_sub(self.shelley_slot)? % self.epoch_length)
+    }
+
+    /// First slot of `epoch`
+    pub fn epoch_start_slot(&self, epoch: u64) -> Option<u64> {
+        let epochs = epoch.checked_sub(self.shelley_epoch)?;
+        self.shelley_slot.checked_add(epochs.checked_mul(self.epoch_length)?)
+    }
+
+    /// Unix time, in seconds, at which `epoch` starts
+    pub fn epoch_start_unix(&self, epoch: u64) -> Option<i64> {
+        self.slot_to_unix(self.epoch_start_slot(epoch)?)
+    }
+
+    /// Epoch in progress at unix time `unix`, in seconds
+    pub fn unix_to_epoch(&self, unix: i64) -> Option<u64> {
+        self.slot_to_epoch(self.unix_to_slot(unix)?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SlotConfig {
+    /// Time at which `slot` starts
+    pub fn slot_to_time(&self, slot: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.slot_to_unix(slot)?, 0)
+    }
+
+    /// Slot in progress at `time`
+    pub fn time_to_slot(&self, time: &chrono::DateTime<chrono::Utc>) -> Option<u64> {
+        self.unix_to_slot(time.timestamp())
+    }
+
+    /// Time at which `epoch` starts
+    pub fn epoch_start(&self, epoch: u64) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.epoch_start_unix(epoch)?, 0)
+    }
+
+    /// Epoch in progress at `time`
+    pub fn time_to_epoch(&self, time: &chrono::DateTime<chrono::Utc>) -> Option<u64> {
+        self.unix_to_epoch(time.timestamp())
+    }
+}
+
+/// Unix time, in seconds, of a timestamp in any form [`Timestamp`] understands.
+/// Fractions of a second are dropped.
+pub(crate) fn unix_seconds(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let year = digits(value, 0..4)?;
+    let month = digits(value, 5..7)?;
+    let day = digits(value, 8..10)?;
+    if value.get(4..5)? != "-" || value.get(7..8)? != "-" || !(1..=12).contains(&month) {
+        return None;
+    }
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let month_days = [31, if leap { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if !(1..=month_days[month as usize - 1]).contains(&day) {
+        return None;
+    }
+
+    // Days since 1970-01-01 in the proleptic Gregorian calendar
+    let (y, m) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * m + 2) / 5 + day - 1;
+    let days = era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468;
+
+    let time = &value[10..];
+    if time.is_empty() {
+        return Some(days * 86_400);
+    }
+    let hour = digits(time, 1..3)?;
+    let minute = digits(time, 4..6)?;
+    let second = digits(time, 7..9)?;
+    if !matches!(time.get(..1)?, "T" | "t" | " ")
+        || time.get(3..4)? != ":"
+        || time.get(6..7)? != ":"
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &time[9..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        if end == 0 {
+            return None;
+        }
+        rest = &fraction[end..];
+    }
+    let offset = match rest {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.get(..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            if rest.len() != 6 || rest.get(3..4)? != ":" {
+                return None;
+            }
+            sign * (digits(rest, 1..3)? * 3_600 + digits(rest, 4..6)? * 60)
+        }
+    };
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second - offset)
+}
+
+/// The ASCII digits at `range` of `value`, as a number
+fn digits(value: &str, range: Range<usize>) -> Option<i64> {
+    let field = value.get(range)?;
+    if !field.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    field.parse().ok()
+}
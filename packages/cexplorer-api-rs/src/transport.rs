@@ -0,0 +1,108 @@
+use crate::error::CexplorerError;
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode};
+use std::fmt;
+use std::time::Duration;
+
+/// Request built by the client, with the query string already encoded into `url`
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    /// Total timeout for this request, overriding the transport's own
+    pub timeout: Option<Duration>,
+}
+
+/// Raw response handed back by a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Body decoded as UTF-8, replacing invalid sequences
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// The HTTP layer under [`CexplorerClient`](crate::CexplorerClient).
+///
+/// [`ReqwestTransport`] is used unless another transport is set on the
+/// config or client. A transport only moves bytes: retries, rate limiting,
+/// caching and status handling stay in the client. Failures to reach the
+/// server should be reported as `CexplorerError::NetworkError` or
+/// `CexplorerError::Timeout` so the retry policy treats them as transient.
+/// The client enforces `HttpRequest::timeout` itself as well, so honoring it
+/// is an optimization rather than a requirement.
+///
+/// On `wasm32` the returned future does not need to be `Send`, since browser
+/// futures hold JavaScript values and everything runs on one thread.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Transport: fmt::Debug + Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, CexplorerError>;
+}
+
+/// Default transport backed by a pooled `reqwest::Client`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, CexplorerError> {
+        let mut builder = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().await.map_err(transport_error)?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(transport_error)?.to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+fn transport_error(e: reqwest::Error) -> CexplorerError {
+    if e.is_timeout() {
+        CexplorerError::Timeout
+    } else {
+        CexplorerError::HttpError(e)
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
+use crate::types::drep_types::DelegatorData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Meta {
@@ -14,11 +17,11 @@ pub struct Meta {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RewardAccount {
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
     #[serde(default)]
     pub script: Option<String>,
     #[serde(default)]
-    pub epoch_stake: Option<f64>,
+    pub epoch_stake: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,9 +29,9 @@ pub struct RewardSpendableEpoch {
     #[serde(default)]
     pub no: Option<f64>,
     #[serde(default)]
-    pub start_time: Option<String>,
+    pub start_time: Option<Timestamp>,
     #[serde(default)]
-    pub end_time: Option<String>,
+    pub end_time: Option<Timestamp>,
     #[serde(default)]
     pub rate: Option<f64>,
 }
@@ -42,7 +45,7 @@ pub struct RewardPool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RewardItem {
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(rename = "type")]
     pub reward_type: String,
     #[serde(default)]
@@ -67,7 +70,7 @@ pub type AccountRewardResponse = ResponseCore<AccountReward>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckDelegationData {
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
     #[serde(default)]
     pub epoch_no: Option<f64>,
     pub tx: String,
@@ -79,13 +82,13 @@ pub type CheckDelegationResponse = ResponseCore<Value>;
 pub struct WithdrawalTx {
     pub hash: String,
     #[serde(default)]
-    pub out_sum: Option<f64>,
+    pub out_sum: Option<Lovelace>,
     #[serde(default)]
-    pub treasury_donation: Option<f64>,
+    pub treasury_donation: Option<Lovelace>,
     #[serde(default)]
     pub size: Option<f64>,
     #[serde(default)]
-    pub fee: Option<f64>,
+    pub fee: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,13 +96,13 @@ pub struct WithdrawalBlock {
     #[serde(default)]
     pub epoch_no: Option<f64>,
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WithdrawalAccount {
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
     #[serde(default)]
     pub script: Option<Value>,
 }
@@ -113,7 +116,7 @@ pub struct WithdrawalPoolInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WithdrawalItem {
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     pub tx: WithdrawalTx,
     pub block: WithdrawalBlock,
     pub account: WithdrawalAccount,
@@ -129,3 +132,5 @@ pub struct WithdrawalsData {
 }
 
 pub type WithdrawalsResponse = ResponseCore<WithdrawalsData>;
+
+pub type DrepDelegationResponse = ResponseCore<Vec<DelegatorData>>;
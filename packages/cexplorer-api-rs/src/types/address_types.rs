@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::{Lovelace, TokenQuantity};
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
 use crate::types::user_types::User;
@@ -27,14 +29,14 @@ pub struct AddressStakePool {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressBalance {
-    pub live: u64,
-    pub active: u64,
+    pub live: Lovelace,
+    pub active: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressReward {
-    pub total: u64,
-    pub withdrawn: u64,
+    pub total: Lovelace,
+    pub withdrawn: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,7 +52,7 @@ pub struct AddressStake {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressAssetMarket {
-    pub quantity: f64,
+    pub quantity: TokenQuantity,
     #[serde(rename = "price")]
     pub price_ada: Option<f64>,
     #[serde(rename = "liquidity")]
@@ -59,7 +61,7 @@ pub struct AddressAssetMarket {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressAsset {
-    pub quantity: u64,
+    pub quantity: TokenQuantity,
     pub name: String,
     pub registry: Option<AssetRegistry>,
     pub market: AddressAssetMarket,
@@ -67,8 +69,8 @@ pub struct AddressAsset {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressActivity {
-    pub first: String,
-    pub recent: String,
+    pub first: Timestamp,
+    pub recent: Timestamp,
     pub count: u64,
 }
 
@@ -105,10 +107,10 @@ pub struct AddressDrepHash {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressDrep {
     pub is_active: bool,
-    pub amount: u64,
+    pub amount: Lovelace,
     pub data: Value,
     pub hash: AddressDrepHash,
-    pub since: String,
+    pub since: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,7 +123,7 @@ pub struct AddressVote {
 pub struct AddressDetailData {
     pub address: String,
     pub stake: Option<AddressStake>,
-    pub balance: u64,
+    pub balance: Lovelace,
     pub asset: Vec<AddressAsset>,
     pub activity: AddressActivity,
     pub extract: AddressExtract,
@@ -142,9 +144,9 @@ pub struct AddressListItem {
     pub asset: Vec<AddressAsset>,
     pub stake: String,
     pub payment_cred: String,
-    pub balance: Option<u64>,
-    pub first: String,
-    pub last: String,
+    pub balance: Option<Lovelace>,
+    pub first: Timestamp,
+    pub last: Timestamp,
     pub activity: u64,
 }
 
@@ -157,7 +159,7 @@ pub struct AddressList {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UTXOAsset {
     pub name: String,
-    pub quantity: i64,
+    pub quantity: TokenQuantity,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,14 +168,15 @@ pub struct UTXOSet {
     pub tx_index: u64,
     pub block_height: u64,
     pub block_time: u64,
-    pub value: u64,
+    pub value: Lovelace,
     pub datum_hash: Option<String>,
     pub asset_list: Vec<UTXOAsset>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UTXO {
-    pub sum: u64,
+    pub sum: Lovelace,
+    #[serde(rename = "bool_or")]
     pub has_script: bool,
     pub utxo_set: Vec<UTXOSet>,
 }
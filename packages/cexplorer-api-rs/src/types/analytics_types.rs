@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
-use crate::types::pool_types::{PoolData, PoolInfo, PoolMeta};
+use crate::types::pool_types::{PoolInfo, PoolMeta};
 
 pub type WealthCompositionResponse = ResponseCore<Value>;
 
@@ -16,7 +17,7 @@ pub struct HardforkInfoData {
     pub not_started: f64,
     #[serde(rename = "releaseDate")]
     #[serde(default)]
-    pub release_date: Option<String>,
+    pub release_date: Option<Timestamp>,
     pub description: String,
 }
 
@@ -24,7 +25,7 @@ pub struct HardforkInfoData {
 pub struct HardforkExchange {
     pub name: String,
     #[serde(rename = "updateOn")]
-    pub update_on: String,
+    pub update_on: Timestamp,
     #[serde(rename = "liquidityPercentage")]
     pub liquidity_percentage: f64,
     pub status: String,
@@ -42,7 +43,7 @@ pub struct HardforkDetail {
     pub not_started: f64,
     #[serde(rename = "releaseDate")]
     #[serde(default)]
-    pub release_date: Option<String>,
+    pub release_date: Option<Timestamp>,
     pub description: String,
     pub exchanges: Vec<HardforkExchange>,
 }
@@ -180,7 +181,7 @@ pub struct EpochAnalyticsData {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyticsRateData {
-    pub date: String,
+    pub date: Timestamp,
     #[serde(default)]
     pub stat: Option<EpochAnalyticsStat>,
 }
@@ -251,8 +252,8 @@ pub struct AnalyticsTopStakingAccount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyticsTopAddress {
     pub address: String,
-    pub first: String,
-    pub last: String,
+    pub first: Timestamp,
+    pub last: Timestamp,
     #[serde(default)]
     pub balance: Option<f64>,
     #[serde(default)]
@@ -261,11 +262,47 @@ pub struct AnalyticsTopAddress {
     pub drep: Option<DrepDelegator>,
 }
 
-pub type EpochAnalyticsResponse = ResponseCore<EpochAnalyticsData>;
-pub type AnalyticsRateResponse = ResponseCore<Vec<AnalyticsRateData>>;
-pub type AnalyticsPoolBlockResponse = ResponseCore<Vec<PoolBlock>>;
-pub type AnalyticsTopStakingAccountsResponse = ResponseCore<Vec<AnalyticsTopStakingAccount>>;
-pub type AnalyticsTopAddressesResponse = ResponseCore<Vec<AnalyticsTopAddress>>;
+/// The API sends `count` as a string on the epoch and rate lists
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochAnalyticsList {
+    #[serde(default)]
+    pub count: Option<String>,
+    pub data: Vec<EpochAnalyticsData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsRateList {
+    #[serde(default)]
+    pub count: Option<String>,
+    pub data: Vec<AnalyticsRateData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolBlockList {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<PoolBlock>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopStakingAccounts {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<AnalyticsTopStakingAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopAddresses {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<AnalyticsTopAddress>,
+}
+
+pub type EpochAnalyticsResponse = ResponseCore<EpochAnalyticsList>;
+pub type AnalyticsRateResponse = ResponseCore<AnalyticsRateList>;
+pub type AnalyticsPoolBlockResponse = ResponseCore<PoolBlockList>;
+pub type AnalyticsTopStakingAccountsResponse = ResponseCore<TopStakingAccounts>;
+pub type AnalyticsTopAddressesResponse = ResponseCore<TopAddresses>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdaPot {
@@ -288,7 +325,14 @@ pub struct AdaPot {
     pub deposits_proposal: Option<f64>,
 }
 
-pub type AnalyticsAdaPotsResponse = ResponseCore<Vec<AdaPot>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdaPots {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<AdaPot>,
+}
+
+pub type AnalyticsAdaPotsResponse = ResponseCore<AdaPots>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupsListDataInfo {
@@ -308,7 +352,14 @@ pub struct GroupsListData {
     pub data: GroupsListDataInfo,
 }
 
-pub type GroupsListResponse = ResponseCore<Vec<GroupsListData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupsList {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<GroupsListData>,
+}
+
+pub type GroupsListResponse = ResponseCore<GroupsList>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupDetailItem {
@@ -326,7 +377,14 @@ pub struct GroupDetailData {
     pub items: Vec<GroupDetailItem>,
 }
 
-pub type GroupDetailResponse = ResponseCore<Vec<GroupDetailData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDetail {
+    pub data: Vec<GroupDetailData>,
+    #[serde(default)]
+    pub count: Option<f64>,
+}
+
+pub type GroupDetailResponse = ResponseCore<GroupDetail>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AveragePool {
@@ -338,3 +396,30 @@ pub struct AveragePool {
 }
 
 pub type AveragePoolResponse = ResponseCore<Vec<AveragePool>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenesisAddressDetail {
+    #[serde(default)]
+    pub last: Option<Timestamp>,
+    #[serde(default)]
+    pub first: Option<Timestamp>,
+    #[serde(default)]
+    pub balance: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenesisAddress {
+    pub address: String,
+    #[serde(default)]
+    pub value: Option<f64>,
+    pub detail: GenesisAddressDetail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenesisAddrData {
+    #[serde(default)]
+    pub count: Option<f64>,
+    pub data: Vec<GenesisAddress>,
+}
+
+pub type GenesisAddrResponse = ResponseCore<GenesisAddrData>;
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
 use crate::types::common_types::ResponseCore;
 use crate::types::user_types::User;
 
@@ -11,9 +12,9 @@ pub struct ArticleDetailData {
     #[serde(default)]
     pub category: Option<String>,
     pub data: Vec<String>,
-    pub pub_date: String,
+    pub pub_date: Timestamp,
     #[serde(default)]
-    pub mod_date: Option<String>,
+    pub mod_date: Option<Timestamp>,
     #[serde(default)]
     pub keywords: Option<String>,
     pub description: String,
@@ -38,8 +39,8 @@ pub struct ArticleListData {
     #[serde(rename = "type")]
     pub article_type: String,
     pub category: Vec<String>,
-    pub pub_date: String,
-    pub mod_date: String,
+    pub pub_date: Timestamp,
+    pub mod_date: Timestamp,
     pub keywords: String,
     pub description: String,
     pub image: String,
@@ -50,4 +51,11 @@ pub struct ArticleListData {
     pub user_owner: User,
 }
 
-pub type ArticleListResponse = ResponseCore<Vec<ArticleListData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleList {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<ArticleListData>,
+}
+
+pub type ArticleListResponse = ResponseCore<ArticleList>;
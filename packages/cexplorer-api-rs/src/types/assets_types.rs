@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::{Lovelace, TokenQuantity};
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
 
@@ -19,8 +21,8 @@ pub struct PolicyJson {
     #[serde(rename = "type")]
     #[serde(default)]
     pub script_type: Option<String>,
-    #[serde(default)]
-    pub keyHash: Option<String>,
+    #[serde(default, rename = "keyHash")]
+    pub key_hash: Option<String>,
     #[serde(default)]
     pub slot: Option<f64>,
     #[serde(default)]
@@ -39,13 +41,13 @@ pub struct PolicyScript {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetPolicy {
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
     #[serde(default)]
     pub mintc: Option<f64>,
     #[serde(default)]
-    pub last_mint: Option<String>,
+    pub last_mint: Option<Timestamp>,
     #[serde(default)]
-    pub first_mint: Option<String>,
+    pub first_mint: Option<Timestamp>,
     #[serde(default)]
     pub script: Option<PolicyScript>,
 }
@@ -55,7 +57,7 @@ pub struct AssetStatInfo {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub address: Option<String>,
     #[serde(default)]
@@ -69,13 +71,13 @@ pub struct AssetStatInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetStatAsset {
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
     #[serde(default)]
     pub mintc: Option<f64>,
     #[serde(default)]
-    pub last_mint: Option<String>,
+    pub last_mint: Option<Timestamp>,
     #[serde(default)]
-    pub first_mint: Option<String>,
+    pub first_mint: Option<Timestamp>,
     #[serde(default)]
     pub script: Option<PolicyScript>,
     #[serde(default)]
@@ -103,7 +105,7 @@ pub struct AssetCore {
 pub struct TxAsset {
     pub name: String,
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
     #[serde(default)]
     pub registry: Option<AssetRegistry>,
 }
@@ -112,9 +114,9 @@ pub struct TxAsset {
 pub struct AssetDexPool {
     pub dex_name: String,
     #[serde(default)]
-    pub token_1_amount: Option<f64>,
+    pub token_1_amount: Option<TokenQuantity>,
     #[serde(default)]
-    pub token_2_amount: Option<f64>,
+    pub token_2_amount: Option<TokenQuantity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -162,7 +164,7 @@ pub struct AssetBlock {
     pub hash: String,
     #[serde(default)]
     pub no: Option<f64>,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
     pub epoch_no: Option<f64>,
 }
@@ -171,7 +173,7 @@ pub struct AssetBlock {
 pub struct AssetOwner {
     pub address: String,
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -179,11 +181,11 @@ pub struct AssetTx {
     pub hash: String,
     #[serde(default)]
     pub invalid_hereafter: Option<String>,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
-    pub treasury_donation: Option<f64>,
+    pub treasury_donation: Option<Lovelace>,
     #[serde(default)]
-    pub fee: Option<f64>,
+    pub fee: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -193,17 +195,17 @@ pub struct AssetOwnersNftItem {
     pub block: Option<AssetBlock>,
     pub owner: AssetOwner,
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetadataTx {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
     pub invalid_hereafter: Option<f64>,
     #[serde(default)]
-    pub treasury_donation: Option<f64>,
+    pub treasury_donation: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -224,7 +226,7 @@ pub struct AssetMintAsset {
 pub struct AssetMint {
     pub asset: AssetMintAsset,
     #[serde(default)]
-    pub quantity: Option<f64>,
+    pub quantity: Option<TokenQuantity>,
     pub tx: AssetTx,
 }
 
@@ -233,7 +235,7 @@ pub struct AssetStatsStatData {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub address: Option<f64>,
     #[serde(default)]
@@ -254,10 +256,50 @@ pub struct AssetStatsData {
     pub stat: Option<Vec<AssetStatsStatData>>,
 }
 
-pub type AssetListResponse = ResponseCore<Vec<AssetList>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetListData {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<AssetList>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetOwners {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<AssetOwner>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetOwnersNft {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<AssetOwnersNftItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetMetadata {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<AssetMetadataItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetMintData {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<AssetMint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetStats {
+    pub data: Vec<AssetStatsData>,
+}
+
+pub type AssetListResponse = ResponseCore<AssetListData>;
 pub type AssetDetailResponse = ResponseCore<AssetDetail>;
-pub type AssetOwnersResponse = ResponseCore<Vec<AssetOwner>>;
-pub type AssetOwnersNftResponse = ResponseCore<Vec<AssetOwnersNftItem>>;
-pub type AssetMetadataResponse = ResponseCore<Vec<AssetMetadataItem>>;
-pub type AssetMintResponse = ResponseCore<Vec<AssetMint>>;
-pub type AssetStatsResponse = ResponseCore<Vec<AssetStatsData>>;
+pub type AssetOwnersResponse = ResponseCore<AssetOwners>;
+pub type AssetOwnersNftResponse = ResponseCore<AssetOwnersNft>;
+pub type AssetMetadataResponse = ResponseCore<AssetMetadata>;
+pub type AssetMintResponse = ResponseCore<AssetMintData>;
+pub type AssetStatsResponse = ResponseCore<AssetStats>;
@@ -1,13 +1,15 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use crate::types::pool_types::{PoolInfo, PoolMeta};
+use crate::time::Timestamp;
+use crate::amount::{Lovelace, TokenQuantity};
+use crate::types::pool_types::PoolInfo;
 use crate::types::epoch_types::EpochParam;
-use crate::types::tx_types::{TxBasicInfo, TxInfo, Withdrawal, Mint, AssetRegistry};
+use crate::types::tx_types::{PlutusContract, TxBasicInfo, TxInfo, TxMetadata, TxScript, Withdrawal};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
     pub block_no: u64,
-    pub time: String,
+    pub time: Timestamp,
     pub hash: String,
     pub epoch_no: u64,
     pub slot_no: Option<Option<u64>>,
@@ -27,24 +29,47 @@ pub struct RateCurrency {
     pub low: f64,
     pub market_cap: f64,
     pub open: f64,
-    pub time_close: String,
-    pub time_open: String,
+    pub time_close: Timestamp,
+    pub time_open: Timestamp,
     pub volume: f64,
 }
 
+/// Exchange rates keyed by lowercase currency code (`"usd"`, `"eur"`, ...)
+pub type FiatRates = BTreeMap<String, FiatRate>;
+
+/// A `[currency, ada]` rate pair, as sent by the API
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(from = "(f64, f64)", into = "(f64, f64)")]
+pub struct FiatRate {
+    pub currency: f64,
+    pub ada: f64,
+}
+
+impl From<(f64, f64)> for FiatRate {
+    fn from((currency, ada): (f64, f64)) -> Self {
+        FiatRate { currency, ada }
+    }
+}
+
+impl From<FiatRate> for (f64, f64) {
+    fn from(rate: FiatRate) -> Self {
+        (rate.currency, rate.ada)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Rate {
     pub ada: Vec<RateCurrency>,
     pub btc: Vec<RateCurrency>,
-    pub date: String,
+    pub date: Timestamp,
     pub epoch_no: u64,
-    pub fiat: Value,
+    pub fiat: FiatRates,
     pub need_fix: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockDetailResponseDataTxItemMints {
-    pub quantity: i64,
+    pub quantity: TokenQuantity,
     pub policy_id: String,
     pub asset_name: String,
     pub fingerprint: String,
@@ -58,13 +83,13 @@ pub struct BlockDetailResponseDataTxsItem {
     pub collateral_inputs: Option<Vec<TxInfo>>,
     pub reference_inputs: Option<Vec<TxInfo>>,
     pub all_inputs: Option<Vec<TxInfo>>,
-    pub all_collateral_outputs: Option<Value>,
+    pub all_collateral_outputs: Option<Vec<TxInfo>>,
     pub all_outputs: Option<Vec<TxInfo>>,
     pub all_withdrawals: Option<Vec<Withdrawal>>,
     pub mints: Option<Vec<BlockDetailResponseDataTxItemMints>>,
-    pub metadata: Option<Value>,
-    pub scripts: Option<Value>,
-    pub plutus_contracts: Option<Value>,
+    pub metadata: Option<TxMetadata>,
+    pub scripts: Option<Vec<TxScript>>,
+    pub plutus_contracts: Option<Vec<PlutusContract>>,
     pub epoch_param: Option<EpochParam>,
 }
 
@@ -74,8 +99,8 @@ pub struct BlockDetailResponseData {
     pub hash: String,
     pub pool: Option<PoolInfo>,
     pub size: u64,
-    pub time: String,
-    pub rewards: u64,
+    pub time: Timestamp,
+    pub rewards: Lovelace,
     pub slot_no: u64,
     pub vrf_key: Option<String>,
     pub block_no: u64,
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::types::common_types::ResponseCore;
-use crate::types::tx_types::{TxBasicInfo, BlockBasicInfo};
+use crate::types::tx_types::{TxFilterInfo, BlockBasicInfo};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractInteractionsDataInfo {
@@ -18,9 +18,16 @@ pub struct ContractInteractionsDataInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractInteractionsData {
-    pub tx: TxBasicInfo,
+    pub tx: TxFilterInfo,
     pub data: ContractInteractionsDataInfo,
     pub block: BlockBasicInfo,
 }
 
-pub type ContractInteractionsResponse = ResponseCore<Vec<ContractInteractionsData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractInteractions {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<ContractInteractionsData>,
+}
+
+pub type ContractInteractionsResponse = ResponseCore<ContractInteractions>;
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use crate::types::tx_types::PlutusData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatumCore<T> {
@@ -21,7 +21,7 @@ pub struct DatumHash {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatumFields {
     #[serde(default)]
-    pub fields: Option<Vec<Value>>,
+    pub fields: Option<Vec<PlutusData>>,
     #[serde(default)]
     pub constructor: Option<f64>,
 }
@@ -60,7 +60,14 @@ pub struct DelegationStateData {
     pub delegation: DelegationPair,
 }
 
-pub type DelegationStateResponse = ResponseCore<Vec<DelegationStateData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationStates {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DelegationStateData>,
+}
+
+pub type DelegationStateResponse = ResponseCore<DelegationStates>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelegationTx {
@@ -103,5 +110,19 @@ pub struct DelegationToRetiredData {
     pub account: DelegationAccount,
 }
 
-pub type DelegationResponse = ResponseCore<Vec<DelegationData>>;
-pub type DelegationToRetiredResponse = ResponseCore<Vec<DelegationToRetiredData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delegations {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DelegationData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelegationsToRetired {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DelegationToRetiredData>,
+}
+
+pub type DelegationResponse = ResponseCore<Delegations>;
+pub type DelegationToRetiredResponse = ResponseCore<DelegationsToRetired>;
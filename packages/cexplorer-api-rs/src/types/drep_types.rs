@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
-use crate::types::tx_types::{TxBasicInfo, BlockBasicInfo};
+use crate::types::tx_types::{TxFilterInfo, BlockBasicInfo};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepCount {
@@ -18,13 +20,13 @@ pub struct DrepCount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepDistr {
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub delegators: Option<f64>,
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(default)]
     pub active_until: Option<f64>,
 }
@@ -36,7 +38,7 @@ pub struct StatDrep {
     #[serde(default)]
     pub distr: Option<DrepDistr>,
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,9 +82,9 @@ pub struct DrepHash {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ToplistItem {
+pub struct ToplistEntry {
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(default)]
     pub data: Option<Value>,
     #[serde(default)]
@@ -90,7 +92,12 @@ pub struct ToplistItem {
     #[serde(default)]
     pub distr: Option<DrepDistr>,
     #[serde(default)]
-    pub since: Option<String>,
+    pub since: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToplistItem {
+    pub item: ToplistEntry,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,7 +113,7 @@ pub struct DelegatorItem {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,7 +131,7 @@ pub struct DrepDelegator {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyticsDrepDistr {
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
@@ -156,7 +163,7 @@ pub struct StakeDrepRetiredDelegator {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -190,7 +197,7 @@ pub struct DrepListStat {
     #[serde(default)]
     pub total: Option<DrepTotal>,
     #[serde(default)]
-    pub recently: Option<String>,
+    pub recently: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -198,14 +205,14 @@ pub struct DrepOwner {
     pub stake: String,
     pub address: String,
     #[serde(default)]
-    pub balance: Option<f64>,
+    pub balance: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepTopDelegator {
     pub view: String,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -224,7 +231,7 @@ pub struct DrepListData {
     #[serde(default)]
     pub is_active: Option<f64>,
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(default)]
     pub data: Option<Value>,
     #[serde(default)]
@@ -234,7 +241,7 @@ pub struct DrepListData {
     #[serde(default)]
     pub stat: Option<DrepListStat>,
     #[serde(default)]
-    pub since: Option<String>,
+    pub since: Option<Timestamp>,
     #[serde(default)]
     pub owner: Option<DrepOwner>,
     #[serde(default)]
@@ -259,7 +266,7 @@ pub struct DrepTx {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepCertItem {
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
     pub tx: DrepTx,
 }
 
@@ -272,11 +279,11 @@ pub struct DrepCert {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepActionTx {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
     pub invalid_hereafter: Option<String>,
     #[serde(default)]
-    pub treasury_donation: Option<f64>,
+    pub treasury_donation: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -307,7 +314,7 @@ pub struct DrepStat {
     #[serde(default)]
     pub total: Option<DrepTotal>,
     #[serde(default)]
-    pub recently: Option<String>,
+    pub recently: Option<Timestamp>,
     #[serde(default)]
     pub gov_action: Option<Vec<DrepGovActionDetail>>,
 }
@@ -331,9 +338,9 @@ pub struct DrepDetailData {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepDetail {
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
     #[serde(default)]
-    pub amount: Option<f64>,
+    pub amount: Option<Lovelace>,
     #[serde(default)]
     pub is_active: Option<bool>,
     #[serde(default)]
@@ -349,7 +356,7 @@ pub struct DrepDetail {
     #[serde(default)]
     pub stat: Option<DrepStat>,
     #[serde(default)]
-    pub since: Option<String>,
+    pub since: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -391,7 +398,7 @@ pub struct DrepProposalIdent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepProposalTx {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -407,7 +414,7 @@ pub struct DrepProposal {
     pub proposal_type: String,
     pub anchor: DrepAnchor,
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
     #[serde(default)]
     pub expiration: Option<f64>,
     pub description: DrepProposalDescription,
@@ -441,7 +448,7 @@ pub struct DelegatorData {
     pub slot_update: Option<f64>,
     pub script: String,
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
     pub live_drep: DelegatorInfo,
     #[serde(default)]
     pub previous_drep: Option<DelegatorInfo>,
@@ -452,26 +459,54 @@ pub struct DrepRegistrationsDataInfo {
     pub raw: String,
     pub view: String,
     #[serde(default)]
-    pub deposit: Option<f64>,
+    pub deposit: Option<Lovelace>,
     #[serde(default)]
     pub has_script: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrepRegistrationsData {
-    pub tx: TxBasicInfo,
+    pub tx: TxFilterInfo,
     pub data: DrepRegistrationsDataInfo,
     pub block: BlockBasicInfo,
     pub owner: DrepOwner,
 }
 
-pub type DrepRegistrationsResponse = ResponseCore<Vec<DrepRegistrationsData>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepList {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DrepListData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepRegistrations {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DrepRegistrationsData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepVote {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DrepVoteItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepDelegators {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<DelegatorData>,
+}
+
+pub type DrepRegistrationsResponse = ResponseCore<DrepRegistrations>;
 pub type DrepStatResponse = ResponseCore<DrepStat>;
 pub type DrepAnalyticsResponse = ResponseCore<DrepAnalytics>;
-pub type DrepListResponse = ResponseCore<Vec<DrepListData>>;
+pub type DrepListResponse = ResponseCore<DrepList>;
 pub type DrepDetailResponse = ResponseCore<DrepDetail>;
-pub type DrepVoteResponse = ResponseCore<Vec<DrepVoteItem>>;
-pub type DrepDelegatorResponse = ResponseCore<Vec<DelegatorData>>;
+pub type DrepVoteResponse = ResponseCore<DrepVote>;
+pub type DrepDelegatorResponse = ResponseCore<DrepDelegators>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AverageDrep {
@@ -492,7 +527,7 @@ pub struct DrepSpoSameTime {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub delegator: Option<f64>,
 }
@@ -506,7 +541,7 @@ pub struct StakeIsSpoDrep {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub delegator: Option<f64>,
 }
@@ -518,7 +553,7 @@ pub struct DelegEpochChangesStat {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
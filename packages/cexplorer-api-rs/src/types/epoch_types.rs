@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,18 +13,18 @@ pub struct EpochParam {
     pub min_fee_b: Option<u64>,
     pub price_mem: Option<f64>,
     pub price_step: Option<f64>,
-    pub key_deposit: Option<u64>,
+    pub key_deposit: Option<Lovelace>,
     pub max_bh_size: Option<u64>,
     pub max_tx_size: Option<u64>,
     pub max_val_size: Option<u64>,
-    pub pool_deposit: Option<u64>,
+    pub pool_deposit: Option<Lovelace>,
     #[serde(default)]
     pub drep_activity: Option<u64>,
     pub extra_entropy: Option<String>,
     pub max_tx_ex_mem: Option<u64>,
-    pub min_pool_cost: Option<u64>,
+    pub min_pool_cost: Option<Lovelace>,
     pub max_block_size: Option<u64>,
-    pub min_utxo_value: Option<u64>,
+    pub min_utxo_value: Option<Lovelace>,
     pub protocol_major: Option<u64>,
     pub protocol_minor: Option<u64>,
     pub max_tx_ex_steps: Option<u64>,
@@ -81,12 +83,12 @@ use crate::types::common_types::ResponseCore;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EpochListData {
     pub no: Option<u64>,
-    pub start_time: String,
-    pub end_time: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
     pub blk_count: Option<u64>,
     pub tx_count: Option<u64>,
-    pub out_sum: Option<u64>,
-    pub fees: Option<u64>,
+    pub out_sum: Option<Lovelace>,
+    pub fees: Option<Lovelace>,
     #[serde(default)]
     pub params: Option<Vec<EpochParam>>,
     #[serde(default)]
@@ -0,0 +1,1296 @@
+use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
+use serde_json::Value;
+use std::collections::HashMap;
+use crate::types::common_types::ResponseCore;
+use crate::types::drep_types::DrepListData;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GovernanceRole {
+    DRep,
+    #[serde(rename = "SPO")]
+    Spo,
+    ConstitutionalCommittee,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnchorOffchainInfo {
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnchorInfo {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub data_hash: Option<String>,
+    #[serde(default)]
+    pub offchain: Option<AnchorOffchainInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionIdent {
+    pub id: String,
+    pub bech: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionTx {
+    pub hash: String,
+    pub time: Timestamp,
+    #[serde(default)]
+    pub invalid_hereafter: Option<u64>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+    #[serde(default)]
+    pub index: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionDescription {
+    pub tag: String,
+    #[serde(default)]
+    pub contents: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionAnchorOffchain {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, rename = "abstract")]
+    pub abstract_text: Option<String>,
+    #[serde(default)]
+    pub motivation: Option<String>,
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionAnchor {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub data_hash: Option<String>,
+    #[serde(default)]
+    pub offchain: Option<GovernanceActionAnchorOffchain>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnAddress {
+    pub view: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakePower {
+    #[serde(default)]
+    pub stake: Option<Lovelace>,
+    #[serde(default)]
+    pub represented_by: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionVotingProcedure {
+    pub vote: String,
+    pub voter_role: String,
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub stat: Option<StakePower>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionVoterTotal {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub represented_by: Option<f64>,
+    #[serde(default)]
+    pub stake: Option<Lovelace>,
+    #[serde(default)]
+    pub drep_always_no_confidence: Option<StakePower>,
+    #[serde(default)]
+    pub drep_always_abstain: Option<StakePower>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionTotal {
+    #[serde(default)]
+    pub drep: Option<GovernanceActionVoterTotal>,
+    #[serde(default)]
+    pub spo: Option<GovernanceActionVoterTotal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeQuorum {
+    #[serde(default)]
+    pub numerator: Option<u64>,
+    /// Spelled this way by the API
+    #[serde(default)]
+    pub denuminator: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeMemberIdent {
+    pub raw: String,
+    pub has_script: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeKey {
+    #[serde(default)]
+    pub hot: Option<String>,
+    #[serde(default)]
+    pub cold: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeRegistry {
+    #[serde(default)]
+    pub img: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionCommitteeMember {
+    pub ident: CommitteeMemberIdent,
+    #[serde(default)]
+    pub key: Option<CommitteeKey>,
+    #[serde(default)]
+    pub registry: Option<CommitteeRegistry>,
+    #[serde(default)]
+    pub vote: Option<String>,
+    #[serde(default)]
+    pub expiration_epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionCommittee {
+    #[serde(default)]
+    pub quorum: Option<CommitteeQuorum>,
+    #[serde(default)]
+    pub member: Vec<GovernanceActionCommitteeMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionList {
+    pub ident: GovernanceActionIdent,
+    pub tx: GovernanceActionTx,
+    #[serde(default)]
+    pub prev_gov_action_proposal_id: Option<String>,
+    #[serde(default)]
+    pub deposit: Option<Lovelace>,
+    #[serde(default)]
+    pub return_address: Option<ReturnAddress>,
+    #[serde(default)]
+    pub expiration: Option<u64>,
+    #[serde(default)]
+    pub anchor: Option<GovernanceActionAnchor>,
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub description: GovernanceActionDescription,
+    #[serde(default)]
+    pub param_proposal: Option<Value>,
+    #[serde(default)]
+    pub ratified_epoch: Option<u64>,
+    #[serde(default)]
+    pub enacted_epoch: Option<u64>,
+    #[serde(default)]
+    pub dropped_epoch: Option<u64>,
+    #[serde(default)]
+    pub expired_epoch: Option<u64>,
+    #[serde(default)]
+    pub voting_procedure: Option<Vec<GovernanceActionVotingProcedure>>,
+    #[serde(default)]
+    pub total: Option<GovernanceActionTotal>,
+    #[serde(default)]
+    pub committee: Option<GovernanceActionCommittee>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionListData {
+    pub data: Vec<GovernanceActionList>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceEpochParam {
+    #[serde(default)]
+    pub drep_activity: Option<f64>,
+    #[serde(default)]
+    pub dvt_p_p_gov_group: Option<f64>,
+    #[serde(default)]
+    pub committee_min_size: Option<f64>,
+    #[serde(default)]
+    pub gov_action_lifetime: Option<f64>,
+    #[serde(default)]
+    pub dvt_committee_normal: Option<f64>,
+    #[serde(default)]
+    pub pvt_committee_normal: Option<f64>,
+    #[serde(default)]
+    pub pvtpp_security_group: Option<f64>,
+    #[serde(default)]
+    pub dvt_p_p_network_group: Option<f64>,
+    #[serde(default)]
+    pub dvt_p_p_economic_group: Option<f64>,
+    #[serde(default)]
+    pub dvt_p_p_technical_group: Option<f64>,
+    #[serde(default)]
+    pub dvt_treasury_withdrawal: Option<f64>,
+    #[serde(default)]
+    pub dvt_hard_fork_initiation: Option<f64>,
+    #[serde(default)]
+    pub dvt_motion_no_confidence: Option<f64>,
+    #[serde(default)]
+    pub pvt_hard_fork_initiation: Option<f64>,
+    #[serde(default)]
+    pub pvt_motion_no_confidence: Option<f64>,
+    #[serde(default)]
+    pub committee_max_term_length: Option<f64>,
+    #[serde(default)]
+    pub dvt_update_to_constitution: Option<f64>,
+    #[serde(default)]
+    pub dvt_committee_no_confidence: Option<f64>,
+    #[serde(default)]
+    pub pvt_committee_no_confidence: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceActionDetail {
+    pub ident: GovernanceActionIdent,
+    pub tx: GovernanceActionTx,
+    #[serde(default)]
+    pub prev_gov_action_proposal_id: Option<String>,
+    #[serde(default)]
+    pub deposit: Option<Lovelace>,
+    #[serde(default)]
+    pub return_address: Option<ReturnAddress>,
+    #[serde(default)]
+    pub epoch_param: Vec<GovernanceEpochParam>,
+    #[serde(default)]
+    pub expiration: Option<u64>,
+    #[serde(default)]
+    pub anchor: Option<GovernanceActionAnchor>,
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub description: GovernanceActionDescription,
+    #[serde(default)]
+    pub param_proposal: Option<Value>,
+    #[serde(default)]
+    pub ratified_epoch: Option<u64>,
+    #[serde(default)]
+    pub enacted_epoch: Option<u64>,
+    #[serde(default)]
+    pub dropped_epoch: Option<u64>,
+    #[serde(default)]
+    pub expired_epoch: Option<u64>,
+    #[serde(default)]
+    pub voting_procedure: Vec<GovernanceActionVotingProcedure>,
+    #[serde(default)]
+    pub total: Option<GovernanceActionTotal>,
+    #[serde(default)]
+    pub committee: Option<GovernanceActionCommittee>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceProposalAnchorOffchain {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceProposalAnchor {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub data_hash: Option<String>,
+    #[serde(default)]
+    pub offchain: Option<GovernanceProposalAnchorOffchain>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceProposalTx {
+    pub hash: String,
+    pub time: Timestamp,
+    #[serde(default)]
+    pub invalid_hereafter: Option<String>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceProposalDescription {
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceProposal {
+    pub ident: GovernanceActionIdent,
+    #[serde(rename = "type")]
+    pub proposal_type: String,
+    #[serde(default)]
+    pub anchor: Option<GovernanceProposalAnchor>,
+    pub tx: GovernanceProposalTx,
+    #[serde(default)]
+    pub deposit: Option<Lovelace>,
+    #[serde(default)]
+    pub expiration: Option<u64>,
+    pub description: GovernanceProposalDescription,
+    #[serde(default)]
+    pub previous: Option<String>,
+    #[serde(default)]
+    pub ratified_epoch: Option<u64>,
+    #[serde(default)]
+    pub enacted_epoch: Option<u64>,
+    #[serde(default)]
+    pub dropped_epoch: Option<u64>,
+    #[serde(default)]
+    pub expired_epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceVoterMeta {
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub objectives: Option<String>,
+    #[serde(default)]
+    pub motivations: Option<String>,
+    #[serde(default)]
+    pub qualifications: Option<String>,
+    #[serde(default)]
+    pub payment_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceVoterInfo {
+    pub id: String,
+    #[serde(default)]
+    pub meta: Option<GovernanceVoterMeta>,
+    #[serde(default)]
+    pub power: Option<StakePower>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceTx {
+    pub hash: String,
+    pub time: Timestamp,
+    #[serde(default)]
+    pub invalid_hereafter: Option<String>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+    #[serde(default)]
+    pub block_no: Option<u64>,
+    #[serde(default)]
+    pub block_hash: Option<String>,
+    #[serde(default)]
+    pub epoch_no: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceVote {
+    pub voter_role: GovernanceRole,
+    pub vote: String,
+    pub proposal: GovernanceProposal,
+    pub info: GovernanceVoterInfo,
+    pub tx: GovernanceTx,
+    #[serde(default)]
+    pub anchor: Option<AnchorInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceVoteDetailData {
+    pub data: Vec<GovernanceVote>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeListItem {
+    pub id: u64,
+    #[serde(default)]
+    pub quorum_numerator: Option<u64>,
+    #[serde(default)]
+    pub quorum_denominator: Option<u64>,
+    #[serde(default)]
+    pub members: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeListData {
+    pub data: Vec<CommitteeListItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeStat {
+    #[serde(default)]
+    pub members: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeIdent {
+    pub raw: String,
+    pub has_script: bool,
+    #[serde(default)]
+    pub cold: Option<String>,
+    #[serde(default)]
+    pub hot: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeMemberRegistration {
+    pub hash: String,
+    pub time: Timestamp,
+    #[serde(default)]
+    pub index: Option<u64>,
+    #[serde(default)]
+    pub invalid_hereafter: Option<u64>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+}
+
+/// Registration certificates come back either as one object or as a list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CommitteeMemberRegistrations {
+    One(CommitteeMemberRegistration),
+    Many(Vec<CommitteeMemberRegistration>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeMember {
+    #[serde(default)]
+    pub key: Option<CommitteeKey>,
+    pub ident: CommitteeIdent,
+    #[serde(default)]
+    pub registry: Option<CommitteeRegistry>,
+    #[serde(default)]
+    pub registration: Option<CommitteeMemberRegistrations>,
+    #[serde(default)]
+    pub de_registration: Option<CommitteeMemberRegistrations>,
+    #[serde(default)]
+    pub expiration_epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeInfo {
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub quorum_numerator: Option<u64>,
+    #[serde(default)]
+    pub quorum_denominator: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeDetail {
+    pub stat: CommitteeStat,
+    pub member: Vec<CommitteeMember>,
+    pub committee: CommitteeInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstitutionAnchor {
+    pub url: String,
+    pub data_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstitutionDescriptionContent {
+    pub gov_action_ix: u64,
+    pub tx_id: String,
+    #[serde(default)]
+    pub anchor: Option<ConstitutionAnchor>,
+    #[serde(default)]
+    pub script: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstitutionDescription {
+    pub tag: String,
+    #[serde(default)]
+    pub contents: Vec<ConstitutionDescriptionContent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstitutionGovActionProposal {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub proposal_type: String,
+    pub anchor: ConstitutionAnchor,
+    #[serde(default)]
+    pub deposit: Option<Lovelace>,
+    #[serde(default)]
+    pub expiration: Option<u64>,
+    pub description: ConstitutionDescription,
+    #[serde(default)]
+    pub previous: Option<u64>,
+    #[serde(default)]
+    pub ratified_epoch: Option<u64>,
+    #[serde(default)]
+    pub enacted_epoch: Option<u64>,
+    #[serde(default)]
+    pub dropped_epoch: Option<u64>,
+    #[serde(default)]
+    pub expired_epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstitutionDataItem {
+    pub id: u64,
+    pub anchor: ConstitutionAnchor,
+    #[serde(default)]
+    pub script_hash: Option<String>,
+    #[serde(default)]
+    pub gov_action_proposal: Option<ConstitutionGovActionProposal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstitutionListData {
+    pub data: Vec<ConstitutionDataItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VotingPower {
+    #[serde(default)]
+    pub power: Option<f64>,
+    #[serde(default)]
+    pub represented_by: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepCount {
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub active: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepDistr {
+    #[serde(default)]
+    pub stake: Option<Lovelace>,
+    #[serde(default)]
+    pub delegators: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovStatDrep {
+    #[serde(default)]
+    pub count: Option<ThresholdDrepCount>,
+    #[serde(default)]
+    pub distr: Option<ThresholdDrepDistr>,
+    #[serde(default)]
+    pub deposit: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovStatEpoch {
+    #[serde(default)]
+    pub other: Option<VotingPower>,
+    #[serde(default)]
+    pub epoch_no: Option<u64>,
+    #[serde(default)]
+    pub drep_always_abstain: Option<VotingPower>,
+    #[serde(default)]
+    pub drep_always_no_confidence: Option<VotingPower>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovStatStake {
+    #[serde(default)]
+    pub total: Option<f64>,
+    #[serde(default)]
+    pub drep_inactive: Option<VotingPower>,
+    #[serde(default)]
+    pub drep_always_abstain: Option<f64>,
+    #[serde(default)]
+    pub drep_always_no_confidence: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdCommitteeCount {
+    #[serde(default)]
+    pub total: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovStatCommittee {
+    #[serde(default)]
+    pub count: Option<ThresholdCommitteeCount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovActionStat {
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub active: Option<u64>,
+    #[serde(default)]
+    pub enacted: Option<u64>,
+    #[serde(default)]
+    pub expires: Option<u64>,
+    #[serde(default)]
+    pub ratified: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdGovStat {
+    #[serde(default)]
+    pub drep: Option<ThresholdGovStatDrep>,
+    #[serde(default)]
+    pub stat: Vec<ThresholdGovStatEpoch>,
+    #[serde(default)]
+    pub stake: Option<ThresholdGovStatStake>,
+    #[serde(default)]
+    pub committee: Option<ThresholdGovStatCommittee>,
+    #[serde(default)]
+    pub gov_action: Vec<ThresholdGovActionStat>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepMeta {
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub objectives: Option<String>,
+    #[serde(default)]
+    pub motivations: Option<String>,
+    #[serde(default)]
+    pub qualifications: Option<String>,
+    #[serde(default)]
+    pub payment_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepHash {
+    pub raw: String,
+    pub view: String,
+    pub has_script: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdVoteCount {
+    pub vote: String,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepTotal {
+    #[serde(default)]
+    pub votes: Vec<ThresholdVoteCount>,
+    #[serde(default)]
+    pub opportunity: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepStat {
+    #[serde(default)]
+    pub total: Option<ThresholdDrepTotal>,
+    #[serde(default)]
+    pub recently: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepListDistr {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub amount: Option<Lovelace>,
+    #[serde(default)]
+    pub active_until: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepOwner {
+    #[serde(default)]
+    pub stake: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub balance: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopDelegator {
+    pub view: String,
+    #[serde(default)]
+    pub stake: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepListItem {
+    #[serde(default)]
+    pub data: Option<ThresholdDrepMeta>,
+    pub hash: ThresholdDrepHash,
+    #[serde(default)]
+    pub stat: Option<ThresholdDrepStat>,
+    #[serde(default)]
+    pub distr: Option<ThresholdDrepListDistr>,
+    #[serde(default)]
+    pub owner: Option<ThresholdDrepOwner>,
+    #[serde(default)]
+    pub since: Option<Timestamp>,
+    #[serde(default)]
+    pub amount: Option<Lovelace>,
+    #[serde(default)]
+    pub is_active: Option<bool>,
+    #[serde(default)]
+    pub top_delegator: Option<TopDelegator>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepList {
+    pub data: Vec<ThresholdDrepListItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolPerformance {
+    #[serde(default)]
+    pub roa: Option<f64>,
+    #[serde(default)]
+    pub luck: Option<f64>,
+    #[serde(default)]
+    pub epochs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolStats {
+    #[serde(default)]
+    pub recent: Option<ThresholdPoolPerformance>,
+    #[serde(default)]
+    pub lifetime: Option<ThresholdPoolPerformance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolBlocks {
+    #[serde(default)]
+    pub epoch: Option<u64>,
+    #[serde(default)]
+    pub total: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolEpochBlock {
+    #[serde(default)]
+    pub luck: Option<f64>,
+    #[serde(default)]
+    pub minted: Option<u64>,
+    #[serde(default)]
+    pub estimated: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolEpochReward {
+    #[serde(default)]
+    pub leader_pct: Option<f64>,
+    #[serde(default)]
+    pub member_pct: Option<f64>,
+    #[serde(default)]
+    pub leader_lovelace: Option<f64>,
+    #[serde(default)]
+    pub member_lovelace: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolEpochData {
+    #[serde(default)]
+    pub block: Option<ThresholdPoolEpochBlock>,
+    #[serde(default)]
+    pub reward: Option<ThresholdPoolEpochReward>,
+    #[serde(default)]
+    pub pledged: Option<Lovelace>,
+    #[serde(default)]
+    pub delegators: Option<u64>,
+    #[serde(default)]
+    pub epoch_stake: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolEpoch {
+    pub no: u64,
+    #[serde(default)]
+    pub data: Option<ThresholdPoolEpochData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolName {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub ticker: Option<String>,
+    #[serde(default)]
+    pub extended: Option<String>,
+    #[serde(default)]
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolLastBlock {
+    #[serde(default)]
+    pub proto: Option<f64>,
+    #[serde(default)]
+    pub slot_no: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolRetire {
+    #[serde(default)]
+    pub live: Option<Value>,
+    #[serde(default)]
+    pub active: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolUpdateTx {
+    pub hash: String,
+    pub time: Timestamp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolOwner {
+    pub view: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolUpdateData {
+    pub tx: ThresholdPoolUpdateTx,
+    #[serde(default)]
+    pub index: Option<u64>,
+    #[serde(default)]
+    pub owner: Vec<ThresholdPoolOwner>,
+    #[serde(default)]
+    pub margin: Option<f64>,
+    #[serde(default)]
+    pub pledge: Option<Lovelace>,
+    #[serde(default)]
+    pub meta_id: Option<u64>,
+    #[serde(default)]
+    pub fixed_cost: Option<Lovelace>,
+    #[serde(default)]
+    pub reward_addr: Option<String>,
+    #[serde(default)]
+    pub active_epoch_no: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolUpdate {
+    #[serde(default)]
+    pub live: Option<ThresholdPoolUpdateData>,
+    #[serde(default)]
+    pub active: Option<ThresholdPoolUpdateData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolListItem {
+    #[serde(default)]
+    pub stats: Option<ThresholdPoolStats>,
+    #[serde(default)]
+    pub blocks: Option<ThresholdPoolBlocks>,
+    #[serde(default)]
+    pub epochs: HashMap<String, ThresholdPoolEpoch>,
+    #[serde(default)]
+    pub pledged: Option<Lovelace>,
+    pub pool_id: String,
+    #[serde(default)]
+    pub pool_name: Option<ThresholdPoolName>,
+    #[serde(default)]
+    pub delegators: Option<u64>,
+    #[serde(default)]
+    pub last_block: Option<ThresholdPoolLastBlock>,
+    #[serde(default)]
+    pub live_stake: Option<Lovelace>,
+    #[serde(default)]
+    pub pool_retire: Option<ThresholdPoolRetire>,
+    #[serde(default)]
+    pub pool_update: Option<ThresholdPoolUpdate>,
+    #[serde(default)]
+    pub active_stake: Option<Lovelace>,
+    #[serde(default)]
+    pub active_epochs: Option<u64>,
+    #[serde(default)]
+    pub top_delegator: Option<TopDelegator>,
+    #[serde(default)]
+    pub pool_id_hash_raw: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolList {
+    pub data: Vec<ThresholdPoolListItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPotsDeposits {
+    #[serde(default)]
+    pub deposits_drep: Option<Lovelace>,
+    #[serde(default)]
+    pub deposits_stake: Option<Lovelace>,
+    #[serde(default)]
+    pub deposits_proposal: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPots {
+    #[serde(default)]
+    pub fees: Option<Lovelace>,
+    #[serde(default)]
+    pub utxo: Option<f64>,
+    #[serde(default)]
+    pub rewards: Option<Lovelace>,
+    #[serde(default)]
+    pub slot_no: Option<u64>,
+    #[serde(default)]
+    pub block_id: Option<u64>,
+    #[serde(default)]
+    pub deposits: Option<ThresholdPotsDeposits>,
+    #[serde(default)]
+    pub reserves: Option<Lovelace>,
+    #[serde(default)]
+    pub treasury: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepDistrStat {
+    #[serde(default)]
+    pub sum: Option<f64>,
+    #[serde(default)]
+    pub count_uniq: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolDistrStat {
+    #[serde(default)]
+    pub sum: Option<f64>,
+    #[serde(default)]
+    pub count_addr_uniq: Option<u64>,
+    #[serde(default)]
+    pub count_pool_uniq: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdBlockVersion {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub version: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolBlockVersion {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub stake: Option<Lovelace>,
+    #[serde(default)]
+    pub version: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdTxComposition {
+    #[serde(default)]
+    pub datum: Option<u64>,
+    #[serde(default)]
+    pub script: Option<u64>,
+    #[serde(default)]
+    pub ma_tx_out: Option<u64>,
+    #[serde(default)]
+    pub delegation: Option<u64>,
+    #[serde(default)]
+    pub ma_tx_mint: Option<u64>,
+    #[serde(default)]
+    pub withdrawal: Option<Lovelace>,
+    #[serde(default)]
+    pub pool_update: Option<u64>,
+    #[serde(default)]
+    pub tx_metadata: Option<u64>,
+    #[serde(default)]
+    pub redeemer_data: Option<u64>,
+    #[serde(default)]
+    pub delegation_vote: Option<u64>,
+    #[serde(default)]
+    pub drep_registration: Option<u64>,
+    #[serde(default)]
+    pub stake_registration: Option<Lovelace>,
+    #[serde(default)]
+    pub gov_action_proposal: Option<u64>,
+    #[serde(default)]
+    pub stake_deregistration: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDailyStat {
+    #[serde(default)]
+    pub sum_fee: Option<f64>,
+    #[serde(default)]
+    pub count_tx: Option<u64>,
+    #[serde(default)]
+    pub avg_tx_fee: Option<f64>,
+    #[serde(default)]
+    pub count_mint: Option<u64>,
+    #[serde(default)]
+    pub count_pool: Option<u64>,
+    #[serde(default)]
+    pub drep_distr: Option<ThresholdDrepDistrStat>,
+    #[serde(default)]
+    pub pool_distr: Option<ThresholdPoolDistrStat>,
+    #[serde(default)]
+    pub sum_tx_out: Option<f64>,
+    #[serde(default)]
+    pub avg_tx_size: Option<f64>,
+    #[serde(default)]
+    pub count_block: Option<u64>,
+    #[serde(default)]
+    pub count_datum: Option<u64>,
+    #[serde(default)]
+    pub count_tx_out: Option<u64>,
+    #[serde(default)]
+    pub block_version: Vec<ThresholdBlockVersion>,
+    #[serde(default)]
+    pub avg_block_size: Option<f64>,
+    #[serde(default)]
+    pub avg_tx_out_sum: Option<f64>,
+    #[serde(default)]
+    pub count_redeemer: Option<u64>,
+    #[serde(default)]
+    pub tx_composition: Option<ThresholdTxComposition>,
+    #[serde(default)]
+    pub block_producers: Option<u64>,
+    #[serde(default)]
+    pub count_ma_tx_out: Option<u64>,
+    #[serde(default)]
+    pub count_delegation: Option<u64>,
+    #[serde(default)]
+    pub count_pool_relay: Option<u64>,
+    #[serde(default)]
+    pub voting_procedure: Option<u64>,
+    #[serde(default)]
+    pub count_tx_metadata: Option<u64>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+    #[serde(default)]
+    pub avg_tx_script_size: Option<f64>,
+    #[serde(default)]
+    pub count_tx_out_stake: Option<u64>,
+    #[serde(default)]
+    pub max_block_tx_count: Option<u64>,
+    #[serde(default)]
+    pub pool_block_version: Vec<ThresholdPoolBlockVersion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDaily {
+    pub date: Timestamp,
+    #[serde(default)]
+    pub stat: Option<ThresholdDailyStat>,
+    #[serde(default)]
+    pub gov_delegation_vote: Option<u64>,
+    #[serde(default)]
+    pub count_tx_out_address: Option<u64>,
+    #[serde(default)]
+    pub count_pool_relay_uniq: Option<u64>,
+    #[serde(default)]
+    pub count_tx_metadata_with_721: Option<u64>,
+    #[serde(default)]
+    pub count_tx_out_stake_not_yesterday: Option<u64>,
+    #[serde(default)]
+    pub count_tx_out_address_not_yesterday: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdEpochSummary {
+    #[serde(default)]
+    pub fees: Option<Lovelace>,
+    #[serde(default)]
+    pub out_sum: Option<Lovelace>,
+    #[serde(default)]
+    pub end_time: Option<Timestamp>,
+    #[serde(default)]
+    pub tx_count: Option<u64>,
+    #[serde(default)]
+    pub block_size: Option<u64>,
+    #[serde(default)]
+    pub start_time: Option<Timestamp>,
+    #[serde(default)]
+    pub block_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdProto {
+    #[serde(default)]
+    pub max: Option<f64>,
+    #[serde(default)]
+    pub min: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdStakePools {
+    #[serde(default)]
+    pub minting: Option<u64>,
+    #[serde(default)]
+    pub registered: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdStake {
+    #[serde(default)]
+    pub epoch: Option<f64>,
+    #[serde(default)]
+    pub pools: Option<ThresholdStakePools>,
+    #[serde(default)]
+    pub active: Option<f64>,
+    #[serde(default)]
+    pub accounts: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdRewards {
+    #[serde(default)]
+    pub leader: Option<f64>,
+    #[serde(default)]
+    pub member: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdDrepStatSummary {
+    #[serde(default)]
+    pub total: Option<VotingPower>,
+    #[serde(default)]
+    pub drep_always_abstain: Option<VotingPower>,
+    #[serde(default)]
+    pub drep_always_no_confidence: Option<VotingPower>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdPoolStat {
+    #[serde(default)]
+    pub pools: Option<u64>,
+    #[serde(default)]
+    pub pct_leader: Option<f64>,
+    #[serde(default)]
+    pub pct_member: Option<f64>,
+    #[serde(default)]
+    pub epoch_stake: Option<Lovelace>,
+    #[serde(default)]
+    pub delegator_avg: Option<f64>,
+    #[serde(default)]
+    pub delegator_count: Option<u64>,
+    #[serde(default)]
+    pub delegator_avg_sw: Option<f64>,
+    #[serde(default)]
+    pub delegator_count_sw: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdEpochStats {
+    #[serde(default)]
+    pub pots: Option<ThresholdPots>,
+    #[serde(default)]
+    pub daily: Vec<ThresholdDaily>,
+    #[serde(default)]
+    pub epoch: Option<ThresholdEpochSummary>,
+    #[serde(default)]
+    pub proto: Option<ThresholdProto>,
+    #[serde(default)]
+    pub stake: Option<ThresholdStake>,
+    #[serde(default)]
+    pub rewards: Option<ThresholdRewards>,
+    #[serde(default)]
+    pub epoch_no: Option<u64>,
+    #[serde(default)]
+    pub drep_stat: Option<ThresholdDrepStatSummary>,
+    #[serde(default)]
+    pub pool_stat: Option<ThresholdPoolStat>,
+    #[serde(default)]
+    pub spendable_epoch: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdMilestoneStat {
+    #[serde(default)]
+    pub drep_distr: Option<ThresholdDrepDistrStat>,
+    #[serde(default)]
+    pub pool_distr: Option<ThresholdPoolDistrStat>,
+    #[serde(default)]
+    pub circulating_supply: Option<Lovelace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdMilestoneItem {
+    #[serde(default)]
+    pub stat: Option<ThresholdMilestoneStat>,
+    pub epoch_no: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdsMilestone {
+    pub data: Vec<ThresholdMilestoneItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Threshold {
+    #[serde(default)]
+    pub x: Option<String>,
+    #[serde(default)]
+    pub gov_stat: Option<ThresholdGovStat>,
+    #[serde(default)]
+    pub drep_list: Option<ThresholdDrepList>,
+    #[serde(default)]
+    pub pool_list: Option<ThresholdPoolList>,
+    #[serde(default)]
+    pub epoch_stats: Option<ThresholdEpochStats>,
+    #[serde(default)]
+    pub analytics_milestone: Option<ThresholdsMilestone>,
+    #[serde(default)]
+    pub gov_committee_detail: Option<CommitteeDetail>,
+}
+
+/// DRep entry annotated with its vote on the requested governance action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepListVoteItem {
+    #[serde(flatten)]
+    pub drep: DrepListData,
+    #[serde(default)]
+    pub vote: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrepListVoteData {
+    pub data: Vec<DrepListVoteItem>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+pub type GovernanceActionListResponse = ResponseCore<GovernanceActionListData>;
+pub type GovernanceActionDetailResponse = ResponseCore<GovernanceActionDetail>;
+pub type GovVoteResponse = ResponseCore<GovernanceVoteDetailData>;
+pub type CommitteeListResponse = ResponseCore<CommitteeListData>;
+pub type CommitteeDetailResponse = ResponseCore<CommitteeDetail>;
+pub type CCMemberDetailResponse = ResponseCore<Vec<CommitteeMember>>;
+pub type ConstitutionListResponse = ResponseCore<ConstitutionListData>;
+pub type ThresholdResponse = ResponseCore<Threshold>;
+pub type DrepListVoteResponse = ResponseCore<DrepListVoteData>;
@@ -19,4 +19,11 @@ pub struct MetadataTxListItem {
     pub size: Option<f64>,
 }
 
-pub type MetadataTxListResponse = ResponseCore<Vec<MetadataTxListItem>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataTxListData {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<MetadataTxListItem>,
+}
+
+pub type MetadataTxListResponse = ResponseCore<MetadataTxListData>;
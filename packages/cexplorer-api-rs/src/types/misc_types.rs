@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
 use serde_json::Value;
+use std::collections::HashMap;
 use crate::types::common_types::ResponseCore;
-use crate::types::block_types::Rate;
+use crate::types::block_types::{FiatRates, Rate};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiscBasicBlock {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
     pub epoch_no: Option<f64>,
     #[serde(default)]
@@ -39,7 +42,7 @@ pub struct MiscBasicInstance {
     pub readonly: Option<bool>,
     pub server: String,
     pub snapshot: String,
-    pub time: String,
+    pub time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,7 +71,7 @@ pub struct MiscBasicResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasicRate {
-    pub date: String,
+    pub date: Timestamp,
     #[serde(default)]
     pub adausd: Option<f64>,
     #[serde(default)]
@@ -99,7 +102,7 @@ pub struct MiscConstData {
     #[serde(default)]
     pub epoch: Option<Value>,
     #[serde(default)]
-    pub circulating_supply: Option<f64>,
+    pub circulating_supply: Option<Lovelace>,
     #[serde(default)]
     pub labels: Option<Vec<MiscConstLabel>>,
     #[serde(default)]
@@ -107,7 +110,7 @@ pub struct MiscConstData {
     #[serde(default)]
     pub epoch_stat: Option<Value>,
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
 }
 
 pub type MiscConstResponse = ResponseCore<MiscConstData>;
@@ -124,21 +127,21 @@ pub struct MiscMarketStatus {
     pub close: Option<f64>,
     #[serde(default)]
     pub volume: Option<f64>,
-    pub time_open: String,
+    pub time_open: Timestamp,
     #[serde(default)]
     pub market_cap: Option<f64>,
-    pub time_close: String,
+    pub time_close: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiscMarketData {
-    pub date: String,
+    pub date: Timestamp,
     #[serde(default)]
     pub epoch_no: Option<f64>,
     pub need_fix: String,
     pub ada: MiscMarketStatus,
     pub btc: MiscMarketStatus,
-    pub fiat: Value,
+    pub fiat: FiatRates,
 }
 
 pub type MiscMarketResponse = ResponseCore<MiscMarketData>;
@@ -170,8 +173,8 @@ pub struct Poll {
     pub url: String,
     #[serde(default)]
     pub applied: Option<String>,
-    pub date_start: String,
-    pub date_end: String,
+    pub date_start: Timestamp,
+    pub date_end: Timestamp,
     pub description: String,
     pub options: Vec<String>,
     pub state: String,
@@ -218,3 +221,78 @@ pub struct MiscApiPlans {
 }
 
 pub type MiscApiResponse = ResponseCore<MiscApiPlans>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterializedView {
+    pub name: String,
+    pub has_indexes: bool,
+    pub is_populated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCexplorerData {
+    pub now: String,
+    pub runner: String,
+    #[serde(default)]
+    pub pool_stat: Option<f64>,
+    #[serde(default)]
+    pub milestone: Option<f64>,
+    #[serde(default)]
+    pub rate: Option<String>,
+    #[serde(default)]
+    pub views: Vec<MaterializedView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthBlockchainData {
+    pub time: Timestamp,
+    #[serde(default)]
+    pub epoch_no: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthData {
+    pub blockchain: HealthBlockchainData,
+    pub cexplorer: HealthCexplorerData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiscHealth {
+    pub is_healthy: bool,
+    pub data: Vec<HealthData>,
+    #[serde(default)]
+    pub err: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiscHealthResponse {
+    #[serde(default)]
+    pub license: Option<String>,
+    pub code: u64,
+    pub data: MiscHealth,
+    pub tokens: u64,
+    pub ex: f64,
+    pub debug: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiscProtocolParameters {
+    pub min_fee_a: u64,
+    pub min_fee_b: u64,
+    pub max_tx_size: u64,
+    pub max_val_size: u64,
+    pub key_deposit: Lovelace,
+    pub pool_deposit: Lovelace,
+    pub price_mem: f64,
+    pub price_step: f64,
+    pub max_tx_ex_mem: u64,
+    pub max_tx_ex_steps: u64,
+    pub coins_per_utxo_byte: u64,
+    pub collateral_percentage: u64,
+    pub max_collateral_inputs: u64,
+    pub min_fee_ref_script_cost_per_byte: f64,
+    #[serde(default)]
+    pub cost_models: HashMap<String, Vec<i64>>,
+}
+
+pub type MiscProtocolParametersResponse = ResponseCore<MiscProtocolParameters>;
@@ -21,12 +21,14 @@ pub mod stake_types;
 pub mod treasury_types;
 pub mod token_types;
 pub mod wallet_types;
+pub mod governance_types;
+pub mod tool_types;
 
 pub use pool_types::{
-    PoolInfo, PoolMeta, PoolMetaExtended,
+    PoolInfo, PoolMeta, PoolMetaExtended, PoolData,
     PoolsListResponse, PoolDetailResponse, PoolRewardsResponse,
     PoolBlocksResponse, PoolDelegatorsResponse, PoolUpdateResponse,
-    PoolAwardsResponse, PoolDelegatorStatsResponse, PoolAboutResponse,
+    PoolAwardsResponse, PoolDelegatorStatsResponse, PoolAboutResponse, PoolRetireResponse,
     TopMarginsWithDelegatorsResponse, RetiredPoolsResponse, PoolBirthdaysResponse,
     TopMultiDelegatorsResponse, DelegEpochRegisteredResponse, StakeDrepsNotSpoResponse,
     PoolRegistrationsResponse, DrepNotSpoSameTimeResponse
@@ -35,18 +37,19 @@ pub use epoch_types::{
     EpochParam, EpochListResponse, EpochDetailParamResponse, EpochDetailStatsResponse
 };
 pub use tx_types::{
-    BlockBasicInfo, TxBasicInfo, TxInfo, Withdrawal,
+    BlockBasicInfo, TxBasicInfo, TxFilterInfo, TxInfo, Withdrawal,
     TxAsset, AssetRegistry, Mint, ReferenceScript,
-    InlineDatum, DatumValue, TxDetailParams, Label,
+    InlineDatum, DatumValue, TxDetailParams, ContractLabel,
+    TxMetadata, Metadatum, PlutusData, PlutusMapEntry, PlutusContract, TxScript, TxDelegation,
     TxDetailResponse, TxListResponse
 };
 pub use block_types::{
     Block, BlockDetailResponse, BlockDetailResponseData, BlocksListResponse,
-    BlocksListResponseData, Rate
+    BlocksListResponseData, Rate, RateCurrency, FiatRate, FiatRates
 };
 pub use common_types::ResponseCore;
 pub use user_types::{User, UserProfile, UserSocial, UserMembership};
-pub use account_types::{Meta, AccountRewardResponse, CheckDelegationResponse, WithdrawalsResponse};
+pub use account_types::{Meta, AccountRewardResponse, CheckDelegationResponse, WithdrawalsResponse, DrepDelegationResponse};
 pub use address_types::{
     AddressDetailResponse, AddressDetailUTXOResponse,
     AddressListResponse, AddressInspectorResponse,
@@ -56,19 +59,20 @@ pub use analytics_types::{
     WealthCompositionResponse, HardforkResponse, EpochAnalyticsResponse,
     AnalyticsRateResponse, AnalyticsPoolBlockResponse, AnalyticsTopStakingAccountsResponse,
     AnalyticsTopAddressesResponse, AnalyticsAdaPotsResponse, GroupsListResponse,
-    GroupDetailResponse, AveragePoolResponse
+    GroupDetailResponse, AveragePoolResponse, GenesisAddrResponse
 };
 pub use article_types::{ArticleDetailResponse, ArticleListResponse, ArticleDetailData, ArticleListData};
 pub use assets_types::{
     AssetListResponse, AssetDetailResponse, AssetOwnersResponse, AssetOwnersNftResponse,
-    AssetMetadataResponse, AssetMintResponse, AssetStatsResponse, AssetDetail, AssetList
+    AssetMetadataResponse, AssetMintResponse, AssetStatsResponse, AssetDetail, AssetList,
+    AssetListData
 };
 pub use contract_types::{ContractInteractionsResponse, ContractInteractionsData};
 pub use drep_types::{
     DrepRegistrationsResponse, DrepStatResponse, DrepAnalyticsResponse, DrepListResponse,
     DrepDetailResponse, DrepVoteResponse, DrepDelegatorResponse, AverageDrepResponse,
     DrepSpoSameTimeResponse, StakeIsSpoDrepResponse, DelegEpochChangesResponse,
-    StakeDrepRetiredResponse, DrepDetail, DrepListData, DrepProposal
+    StakeDrepRetiredResponse, DrepDetail, DrepList, DrepListData, DrepProposal
 };
 pub use datum_types::{DatumDetailResponse, DatumDetailData};
 pub use delegation_types::{
@@ -85,9 +89,18 @@ pub use policy_types::{
 };
 pub use misc_types::{
     MiscBasicResponse, MiscRateResponse, MiscConstResponse, MiscMarketResponse,
-    MiscSearchResponse, PollListResponse, MiscValidateResponse, MiscApiResponse
+    MiscSearchResponse, PollListResponse, MiscValidateResponse, MiscApiResponse,
+    MiscHealthResponse, MiscProtocolParametersResponse
 };
 pub use stake_types::{StakeDetailResponse, StakeRegistrationsResponse};
 pub use treasury_types::TreasuryDonationStatsResponse;
 pub use token_types::{DeFiTokenListResponse, DeFiTokenStatResponse, DeFiOrderListResponse};
 pub use wallet_types::CompareWalletsResponse;
+pub use governance_types::{
+    GovernanceActionListResponse, GovernanceActionDetailResponse, GovVoteResponse,
+    CommitteeListResponse, CommitteeDetailResponse, CCMemberDetailResponse,
+    ConstitutionListResponse, ThresholdResponse, DrepListVoteResponse,
+    GovernanceActionList, GovernanceActionDetail, GovernanceVote, GovernanceRole,
+    CommitteeMember, Threshold
+};
+pub use tool_types::TxSentResponse;
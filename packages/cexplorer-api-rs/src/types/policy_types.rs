@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
-use crate::types::assets_types::AssetPolicy;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyRoyalties {
@@ -56,9 +56,9 @@ pub struct Policy {
     #[serde(default)]
     pub mintc: Option<f64>,
     #[serde(default)]
-    pub last_mint: Option<String>,
+    pub last_mint: Option<Timestamp>,
     #[serde(default)]
-    pub first_mint: Option<String>,
+    pub first_mint: Option<Timestamp>,
     #[serde(default)]
     pub stats: Option<PolicyDataStats>,
     #[serde(default)]
@@ -118,6 +118,13 @@ pub struct PolicyOwner {
     pub quantity: Option<f64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyOwners {
+    pub data: Vec<PolicyOwner>,
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
 pub type PolicyDetailResponse = ResponseCore<PolicyDetail>;
 pub type PolicyStatsResponse = ResponseCore<Vec<PolicyStat>>;
-pub type PolicyOwnerResponse = ResponseCore<Vec<PolicyOwner>>;
+pub type PolicyOwnerResponse = ResponseCore<PolicyOwners>;
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
+use crate::amount::Lovelace;
 use serde_json::Value;
 
 fn deserialize_null_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
@@ -60,11 +62,11 @@ pub struct PoolEpochBlock {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolEpochReward {
     #[serde(default)]
-    pub leader_lovelace: Option<f64>,
+    pub leader_lovelace: Option<Lovelace>,
     #[serde(default)]
     pub leader_pct: Option<f64>,
     #[serde(default)]
-    pub member_lovelace: Option<f64>,
+    pub member_lovelace: Option<Lovelace>,
     #[serde(default)]
     pub member_pct: Option<f64>,
 }
@@ -72,7 +74,7 @@ pub struct PoolEpochReward {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolEpochData {
     #[serde(default)]
-    pub epoch_stake: Option<f64>,
+    pub epoch_stake: Option<Lovelace>,
     #[serde(default)]
     pub delegators: Option<f64>,
     #[serde(default)]
@@ -80,7 +82,7 @@ pub struct PoolEpochData {
     #[serde(default)]
     pub reward: Option<PoolEpochReward>,
     #[serde(default)]
-    pub pledged: Option<f64>,
+    pub pledged: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,7 +94,7 @@ pub struct PoolEpoch {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolTxBasic {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,19 +105,19 @@ pub struct PoolOwner {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolTxWithId {
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     pub id: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolUpdateRetireItem {
     pub active_epoch_no: u64,
-    pub fixed_cost: u64,
+    pub fixed_cost: Lovelace,
     pub index: u64,
     pub margin: f64,
     #[serde(default)]
     pub meta_id: Option<u64>,
-    pub pledge: u64,
+    pub pledge: Lovelace,
     pub reward_addr: String,
     pub tx: PoolTxWithId,
     pub owner: Vec<PoolOwner>,
@@ -174,9 +176,9 @@ pub struct PoolData {
     pub pool_id: String,
     pub pool_id_hash_raw: String,
     #[serde(default)]
-    pub active_stake: Option<f64>,
+    pub active_stake: Option<Lovelace>,
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
     #[serde(default)]
     pub epochs: Option<Value>,
     #[serde(default)]
@@ -190,7 +192,7 @@ pub struct PoolData {
     #[serde(default)]
     pub stats: Option<PoolStats>,
     #[serde(default)]
-    pub pledged: Option<f64>,
+    pub pledged: Option<Lovelace>,
     #[serde(default)]
     pub blocks: Option<PoolBlocks>,
 }
@@ -206,9 +208,9 @@ pub struct PoolDetailData {
     pub pool_id: String,
     pub hash_raw: String,
     #[serde(default)]
-    pub active_stake: Option<f64>,
+    pub active_stake: Option<Lovelace>,
     #[serde(default)]
-    pub live_stake: Option<f64>,
+    pub live_stake: Option<Lovelace>,
     #[serde(default)]
     pub epochs: Option<Vec<PoolEpoch>>,
     #[serde(default)]
@@ -216,7 +218,7 @@ pub struct PoolDetailData {
     #[serde(default)]
     pub delegators: Option<f64>,
     #[serde(default)]
-    pub registered: Option<String>,
+    pub registered: Option<Timestamp>,
     #[serde(default)]
     pub pool_name: Option<PoolMeta>,
     #[serde(default)]
@@ -226,7 +228,7 @@ pub struct PoolDetailData {
     #[serde(default)]
     pub stats: Option<PoolStats>,
     #[serde(default)]
-    pub pledged: Option<f64>,
+    pub pledged: Option<Lovelace>,
     #[serde(default)]
     pub blocks: Option<PoolBlocks>,
 }
@@ -235,15 +237,15 @@ pub struct PoolDetailData {
 pub struct PoolEpochCert {
     pub tx: String,
     pub margin: f64,
-    pub pledge: u64,
-    pub fixed_cost: u64,
+    pub pledge: Lovelace,
+    pub fixed_cost: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolRewardData {
     pub no: u64,
     #[serde(default)]
-    pub active_stake: Option<u64>,
+    pub active_stake: Option<Lovelace>,
     #[serde(default)]
     pub block: Option<PoolEpochBlock>,
     #[serde(default)]
@@ -251,9 +253,9 @@ pub struct PoolRewardData {
     #[serde(default)]
     pub delegator: Option<u64>,
     #[serde(default)]
-    pub epoch_stake: Option<u64>,
+    pub epoch_stake: Option<Lovelace>,
     #[serde(default)]
-    pub pledged: Option<u64>,
+    pub pledged: Option<Lovelace>,
     #[serde(default)]
     pub cert: Option<PoolEpochCert>,
 }
@@ -266,7 +268,7 @@ pub struct PoolRewards {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolBlocksData {
-    pub date: String,
+    pub date: Timestamp,
     pub block: PoolBlocksStat,
 }
 
@@ -303,7 +305,7 @@ pub struct PoolDelegatorData {
     #[serde(default)]
     pub slot_first_registered: Option<u64>,
     #[serde(default)]
-    pub live_stake: Option<u64>,
+    pub live_stake: Option<Lovelace>,
     pub live_pool: PoolInfoWithDelegation,
     pub active_pool: PoolInfoWithDelegation,
     pub previous_pool: PoolInfoWithDelegation,
@@ -318,8 +320,8 @@ pub struct PoolDelegators {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolAccountItem {
     pub view: String,
-    pub active_stake: u64,
-    pub live_stake: u64,
+    pub active_stake: Lovelace,
+    pub live_stake: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -338,12 +340,12 @@ pub struct PoolUpdateMeta {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolUpdateData {
     pub tx_hash: String,
-    pub time: String,
+    pub time: Timestamp,
     pub active_epoch_no: u64,
     pub vrf_key_hash: String,
-    pub pledge: u64,
+    pub pledge: Lovelace,
     pub margin: String,
-    pub fixed_cost: u64,
+    pub fixed_cost: Lovelace,
     pub account: PoolAccount,
     pub meta: PoolUpdateMeta,
 }
@@ -356,12 +358,12 @@ pub struct PoolUpdates {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolAwardDetail {
-    pub time: String,
+    pub time: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolAward {
-    pub time: String,
+    pub time: Timestamp,
     pub category: String,
     #[serde(rename = "type")]
     pub award_type: String,
@@ -441,7 +443,7 @@ pub struct TopMarginsStats {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopDelegator {
     pub view: String,
-    pub stake: u64,
+    pub stake: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -454,14 +456,14 @@ pub struct LastBlock {
 pub struct TopMarginsData {
     pub active_epochs: u64,
     #[serde(default)]
-    pub active_stake: Option<u64>,
+    pub active_stake: Option<Lovelace>,
     pub blocks: PoolBlocks,
     pub delegators: u64,
     pub epochs: Value,
     pub last_block: LastBlock,
     #[serde(default)]
-    pub live_stake: Option<u64>,
-    pub pledged: u64,
+    pub live_stake: Option<Lovelace>,
+    pub pledged: Lovelace,
     pub pool_id: String,
     pub pool_id_hash_raw: String,
     pub pool_name: TopMarginsPoolName,
@@ -480,15 +482,15 @@ pub struct TopMarginsWithDelegators {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetiredPoolStat {
     pub count: u64,
-    pub stake: u64,
+    pub stake: Lovelace,
     pub accounts: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetiredPoolItemStat {
-    pub live: u64,
+    pub live: Lovelace,
     #[serde(default)]
-    pub active: Option<u64>,
+    pub active: Option<Lovelace>,
     pub epochs: u64,
     pub accounts: u64,
 }
@@ -527,20 +529,26 @@ pub struct RetiredPools {
     pub data: Vec<RetiredPoolItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolBirthdayPool {
+    pub pool_id: String,
+    pub meta: PoolMeta,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolBirthday {
-    pub live_stake: u64,
+    pub live_stake: Lovelace,
     pub delegators: u64,
-    pub pledged: u64,
+    pub pledged: Lovelace,
     pub active_epochs: u64,
     pub anniversary: String,
     pub stats: PoolStats,
-    pub pool: PoolAboutPool,
+    pub pool: PoolBirthdayPool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopMultiDelegatorsStake {
-    pub balance: u64,
+    pub balance: Lovelace,
     pub count: u64,
 }
 
@@ -559,7 +567,7 @@ pub struct TopMultiDelegators {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelegEpochRegisteredStat {
     pub count: u64,
-    pub stake: u64,
+    pub stake: Lovelace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -574,7 +582,7 @@ pub struct DelegEpochRegisteredItem {
 pub struct StakeDrepsNotSpoItem {
     pub epoch_no: u64,
     pub count: u64,
-    pub stake: u64,
+    pub stake: Lovelace,
     pub delegator: u64,
 }
 
@@ -618,7 +626,7 @@ pub struct DrepNotSpoSameTime {
     #[serde(default)]
     pub count: Option<f64>,
     #[serde(default)]
-    pub stake: Option<f64>,
+    pub stake: Option<Lovelace>,
     #[serde(default)]
     pub delegator: Option<f64>,
     #[serde(default)]
@@ -626,3 +634,13 @@ pub struct DrepNotSpoSameTime {
 }
 
 pub type DrepNotSpoSameTimeResponse = ResponseCore<Vec<DrepNotSpoSameTime>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolRetireCert {
+    pub tx_hash: String,
+    pub cert_index: u64,
+    pub time: Timestamp,
+    pub retiring_epoch: u64,
+}
+
+pub type PoolRetireResponse = ResponseCore<Vec<PoolRetireCert>>;
@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
 use crate::types::assets_types::MetadataTx;
-use crate::types::tx_types::Label;
+use crate::types::tx_types::{ContractLabel, DatumValue};
+use crate::amount::Lovelace;
+use crate::time::Timestamp;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScriptStatRedeemer {
@@ -96,7 +98,7 @@ pub struct ScriptDetailData {
     #[serde(rename = "type")]
     pub script_type: String,
     #[serde(default)]
-    pub label: Option<Label>,
+    pub label: Option<ContractLabel>,
     pub bytecode: String,
     #[serde(default)]
     pub serialised_size: Option<f64>,
@@ -114,8 +116,14 @@ pub type ScriptDetailResponse = ResponseCore<ScriptDetailData>;
 pub struct ScriptDetailRedeemerTx {
     pub hash: String,
     #[serde(default)]
+    pub time: Option<Timestamp>,
+    #[serde(default)]
     pub slot_no: Option<f64>,
     #[serde(default)]
+    pub invalid_hereafter: Option<f64>,
+    #[serde(default)]
+    pub treasury_donation: Option<Lovelace>,
+    #[serde(default)]
     pub out_sum: Option<f64>,
 }
 
@@ -130,6 +138,8 @@ pub struct ScriptDetailRedeemerData {
     #[serde(default)]
     pub fields: Option<Vec<Value>>,
     #[serde(default)]
+    pub value: Option<DatumValue>,
+    #[serde(default)]
     pub hash: Option<String>,
 }
 
@@ -156,7 +166,14 @@ pub struct ScriptDetailRedeemerDataItem {
     pub epoch_param: Option<EpochParam>,
 }
 
-pub type ScriptDetailRedeemerResponse = ResponseCore<Vec<ScriptDetailRedeemerDataItem>>;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptDetailRedeemers {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<ScriptDetailRedeemerDataItem>,
+}
+
+pub type ScriptDetailRedeemerResponse = ResponseCore<ScriptDetailRedeemers>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScriptListStatItem {
@@ -188,7 +205,14 @@ pub struct ScriptListData {
     #[serde(default)]
     pub stat: Option<ScriptStat>,
     #[serde(default)]
-    pub label: Option<Label>,
+    pub label: Option<ContractLabel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptList {
+    #[serde(default)]
+    pub count: Option<u64>,
+    pub data: Vec<ScriptListData>,
 }
 
-pub type ScriptListResponse = ResponseCore<Vec<ScriptListData>>;
+pub type ScriptListResponse = ResponseCore<ScriptList>;
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
 
@@ -36,7 +37,7 @@ pub struct DeFiTokenListItem {
     pub is_verified: Option<bool>,
     #[serde(default)]
     pub price_ada: Option<f64>,
-    pub updated: String,
+    pub updated: Timestamp,
     #[serde(default)]
     pub liquidity_ada: Option<f64>,
 }
@@ -65,7 +66,7 @@ pub type DeFiTokenListResponse = ResponseCore<DeFiTokenListData>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeFiTokenStatDaily {
-    pub date: String,
+    pub date: Timestamp,
     #[serde(default)]
     pub tokens: Option<f64>,
     #[serde(default)]
@@ -76,7 +77,7 @@ pub struct DeFiTokenStatDaily {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeFiTokenStatDataItem {
-    pub update_date: String,
+    pub update_date: Timestamp,
     #[serde(default)]
     pub details: Option<Vec<Value>>,
 }
@@ -111,7 +112,7 @@ pub struct DeFiOrderBlock {
     #[serde(default)]
     pub no: Option<f64>,
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     #[serde(default)]
     pub epoch_no: Option<f64>,
 }
@@ -129,8 +130,8 @@ pub struct DeFiOrder {
     pub expected_out_amount: Option<f64>,
     #[serde(default)]
     pub actual_out_amount: Option<f64>,
-    pub submission_time: String,
-    pub last_update: String,
+    pub submission_time: Timestamp,
+    pub last_update: Timestamp,
     pub tx_hash: String,
     pub update_tx_hash: String,
     #[serde(default)]
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use crate::types::common_types::ResponseCore;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxSentData {
+    pub state: String,
+}
+
+pub type TxSentResponse = ResponseCore<TxSentData>;
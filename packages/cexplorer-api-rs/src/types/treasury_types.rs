@@ -1,15 +1,24 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use crate::types::block_types::{FiatRates, RateCurrency};
+use crate::amount::Lovelace;
 use crate::types::common_types::ResponseCore;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreasuryDonationRate {
+    #[serde(default)]
+    pub ada: Vec<RateCurrency>,
+    #[serde(default)]
+    pub fiat: FiatRates,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreasuryDonationStatsEpoch {
     #[serde(default)]
     pub epoch_no: Option<f64>,
     #[serde(default)]
-    pub treasury_donation: Option<f64>,
+    pub treasury_donation: Option<Lovelace>,
     #[serde(default)]
-    pub rate: Option<Value>,
+    pub rate: Option<TreasuryDonationRate>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
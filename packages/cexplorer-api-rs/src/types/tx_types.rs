@@ -1,14 +1,21 @@
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use crate::time::Timestamp;
+use crate::amount::{Lovelace, TokenQuantity};
 use serde_json::Value;
 use crate::types::epoch_types::EpochParam;
 use crate::types::common_types::ResponseCore;
+use crate::types::block_types::Rate;
+use crate::types::pool_types::PoolInfo;
+use std::collections::BTreeMap;
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockBasicInfo {
     pub no: Option<u64>,
     pub hash: String,
-    pub time: String,
+    pub time: Timestamp,
     pub epoch_no: u64,
     pub slot_no: Option<Option<u64>>,
 }
@@ -16,21 +23,36 @@ pub struct BlockBasicInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxBasicInfo {
     pub block: BlockBasicInfo,
-    pub fee: Option<u64>,
+    pub fee: Option<Lovelace>,
     pub hash: String,
     pub size: Option<u64>,
-    pub deposit: i64,
-    pub out_sum: Option<u64>,
+    pub deposit: Lovelace,
+    pub out_sum: Option<Lovelace>,
     pub script_size: Option<u64>,
     pub invalid_before: Option<Option<u64>>,
     pub invalid_hereafter: Option<u64>,
-    pub valid_contract: Value,
-    pub treasury_donation: Option<u64>,
+    pub valid_contract: Option<bool>,
+    pub treasury_donation: Option<Lovelace>,
+}
+
+/// A [`TxBasicInfo`] without its block, as listed by `/tx/filter`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxFilterInfo {
+    pub fee: Option<Lovelace>,
+    pub hash: String,
+    pub size: Option<u64>,
+    pub deposit: Lovelace,
+    pub out_sum: Option<Lovelace>,
+    pub script_size: Option<u64>,
+    pub invalid_before: Option<Option<u64>>,
+    pub invalid_hereafter: Option<u64>,
+    pub valid_contract: Option<bool>,
+    pub treasury_donation: Option<Lovelace>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Withdrawal {
-    pub amount: Option<u64>,
+    pub amount: Option<Lovelace>,
     pub stake_addr: String,
 }
 
@@ -49,13 +71,124 @@ pub struct AssetRegistry {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxAsset {
     pub name: String,
-    pub quantity: i64,
+    pub quantity: TokenQuantity,
     pub registry: AssetRegistry,
 }
 
+/// Transaction metadata, keyed by label (`"674"`, `"721"`, ...)
+pub type TxMetadata = BTreeMap<String, Metadatum>;
+
+/// A transaction metadata value, in the JSON form the API renders it in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Metadatum {
+    Int(#[serde(deserialize_with = "deserialize_int")] i128),
+    Text(String),
+    List(Vec<Metadatum>),
+    Map(BTreeMap<String, Metadatum>),
+    /// Anything outside the on-chain metadata model, such as a float
+    Other(Value),
+}
+
+impl Metadatum {
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Metadatum::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Metadatum::Int(int) => Some(*int),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Metadatum]> {
+        match self {
+            Metadatum::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Value under `key` when this is a map
+    pub fn get(&self, key: &str) -> Option<&Metadatum> {
+        match self {
+            Metadatum::Map(map) => map.get(key),
+            _ => None,
+        }
+    }
+}
+
+/// Plutus data in the detailed JSON schema used by cardano-cli and db-sync.
+///
+/// Plutus integers are unbounded, while `Int` holds what fits in an `i128`
+/// and serde_json parses as an integer, which stops at `u64::MAX`. Larger
+/// integers land in `Other` as a `Value`, exact only with serde_json's
+/// `arbitrary_precision` feature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PlutusData {
+    Constr {
+        constructor: u64,
+        fields: Vec<PlutusData>,
+    },
+    Map {
+        map: Vec<PlutusMapEntry>,
+    },
+    List {
+        list: Vec<PlutusData>,
+    },
+    Int {
+        #[serde(deserialize_with = "deserialize_int")]
+        int: i128,
+    },
+    Bytes {
+        bytes: String,
+    },
+    /// Anything the variants above don't cover, such as an integer too large for `Int`
+    Other(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlutusMapEntry {
+    pub k: PlutusData,
+    pub v: PlutusData,
+}
+
+/// Integer from a JSON number. Untagged enums buffer their input, and the
+/// buffer only hands integers to `deserialize_any`, so `i128` can't be
+/// derived there.
+fn deserialize_int<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+    struct IntVisitor;
+
+    impl<'de> Visitor<'de> for IntVisitor {
+        type Value = i128;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an integer")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<i128, E> {
+            Ok(v.into())
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<i128, E> {
+            Ok(v.into())
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<i128, E> {
+            Ok(v)
+        }
+    }
+
+    deserializer.deserialize_any(IntVisitor)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatumValue {
-    pub fields: Vec<Value>,
+    pub fields: Vec<PlutusData>,
     pub constructor: Option<Option<u64>>
 }
 
@@ -77,7 +210,7 @@ pub struct ReferenceScript {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxInfo {
-    pub value: Option<u64>,
+    pub value: Option<Lovelace>,
     pub tx_id: Option<u64>,
     pub tx_hash: String,
     pub tx_index: Option<u64>,
@@ -92,11 +225,135 @@ pub struct TxInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Mint {
-    pub quantity: i64,
+    pub quantity: TokenQuantity,
     pub name: String,
     pub registry: AssetRegistry
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Datum {
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(default)]
+    pub value: Option<DatumValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExUnits {
+    pub mem: u64,
+    pub steps: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redeemer {
+    #[serde(default)]
+    pub fee: Option<Lovelace>,
+    #[serde(default)]
+    pub unit: Option<ExUnits>,
+    #[serde(default)]
+    pub datum: Option<Datum>,
+    #[serde(default)]
+    pub purpose: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlutusInput {
+    #[serde(default)]
+    pub datum: Option<Datum>,
+    #[serde(default)]
+    pub redeemer: Option<Redeemer>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractLabelData {
+    #[serde(default)]
+    pub script_hash: Option<String>,
+    #[serde(default)]
+    pub contract_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractLabelExtra {
+    #[serde(default)]
+    pub bg: Option<String>,
+    #[serde(default)]
+    pub fw: Option<u64>,
+    #[serde(default)]
+    pub link: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+/// Known dApp a contract belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractLabel {
+    pub label: String,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub category: Vec<String>,
+    #[serde(default)]
+    pub data: Option<ContractLabelData>,
+    #[serde(default)]
+    pub extra: Option<ContractLabelExtra>,
+}
+
+/// A Plutus script run by the transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlutusContract {
+    pub script_hash: String,
+    #[serde(rename = "type")]
+    pub script_type: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub bytecode: Option<String>,
+    #[serde(default)]
+    pub input: Option<PlutusInput>,
+    #[serde(default)]
+    pub output: Option<Datum>,
+    /// Not described by the API schema
+    #[serde(default)]
+    pub valid_contract: Option<Value>,
+    #[serde(default)]
+    pub label: Option<ContractLabel>,
+}
+
+/// A script included in or referenced by a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxScript {
+    pub hash: String,
+    #[serde(rename = "type")]
+    pub script_type: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub bytes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxDelegationStake {
+    #[serde(default)]
+    pub live: Option<Lovelace>,
+    #[serde(default)]
+    pub active: Option<Lovelace>,
+}
+
+/// A stake delegation certificate in a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxDelegation {
+    #[serde(rename = "type")]
+    pub delegation_type: String,
+    pub view: String,
+    #[serde(default)]
+    pub detail: Option<PoolInfo>,
+    #[serde(default)]
+    pub stake: Option<TxDelegationStake>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxDetailParams {
     block_no: Option<i64>,
@@ -107,16 +364,16 @@ pub struct TxDetailParams {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxDetailData {
     pub block: BlockBasicInfo,
-    pub fee: Option<u64>,
+    pub fee: Option<Lovelace>,
     pub hash: String,
     pub size: Option<u64>,
-    pub deposit: i64,
-    pub out_sum: Option<u64>,
+    pub deposit: Lovelace,
+    pub out_sum: Option<Lovelace>,
     pub script_size: Option<u64>,
     pub invalid_before: Option<Option<u64>>,
     pub invalid_hereafter: Option<u64>,
-    pub valid_contract: Value,
-    pub treasury_donation: Option<u64>,
+    pub valid_contract: Option<bool>,
+    pub treasury_donation: Option<Lovelace>,
     pub epoch_param: EpochParam,
     pub all_inputs: Option<Vec<TxInfo>>,
     pub all_outputs: Option<Vec<TxInfo>>,
@@ -124,13 +381,13 @@ pub struct TxDetailData {
     pub all_withdrawals: Option<Vec<Withdrawal>>,
     pub collateral_inputs: Option<Vec<TxInfo>>,
     pub reference_inputs: Option<Vec<TxInfo>>,
-    pub metadata: Option<Value>,
+    pub metadata: Option<TxMetadata>,
     pub mints: Option<Vec<Mint>>,
     pub defi: Option<Vec<Value>>,
-    pub plutus_contracts: Option<Vec<Value>>,
-    pub pool: Option<Value>,
-    pub rate: Option<Value>,
-    pub delegation: Option<Vec<Value>>,
+    pub plutus_contracts: Option<Vec<PlutusContract>>,
+    pub pool: Option<PoolInfo>,
+    pub rate: Option<Rate>,
+    pub delegation: Option<Vec<TxDelegation>>,
     pub governance: Option<Governance>,
 }
 
@@ -158,17 +415,10 @@ pub struct VotingInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Power {
-    pub stake: f64,
+    pub stake: Lovelace,
     pub represented_by: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Label {
-    #[serde(default)]
-    pub name: Option<String>,
-    #[serde(default)]
-    pub version: Option<String>,
-}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxDetailResponse {
     #[serde(default)]
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::time::Timestamp;
 use serde_json::Value;
 use crate::types::common_types::ResponseCore;
 
@@ -26,8 +27,8 @@ pub struct WalletPartner {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossChainCompatibility {
     pub enabled: Value,
-    #[serde(default)]
-    pub supportedChains: Option<Vec<String>>,
+    #[serde(default, rename = "supportedChains")]
+    pub supported_chains: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,9 +81,9 @@ pub struct CompareWallets {
     pub wallet_type: String,
     #[serde(default)]
     pub category: Option<String>,
-    pub pub_date: String,
+    pub pub_date: Timestamp,
     #[serde(default)]
-    pub mod_date: Option<String>,
+    pub mod_date: Option<Timestamp>,
     pub keywords: String,
     pub description: String,
     #[serde(default)]
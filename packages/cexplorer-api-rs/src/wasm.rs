@@ -0,0 +1,191 @@
+//! JavaScript bindings for `wasm32-unknown-unknown` builds.
+//!
+//! The manifest only builds an rlib, so native builds skip the cdylib.
+//! Build the module and its JavaScript glue with:
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/cexplorer_api_rs.wasm
+//! ```
+//!
+//! Every method returns a `Promise` that resolves to the decoded response as
+//! plain JSON, or rejects with the error message.
+//!
+//! ```js
+//! import init, { CexplorerClient } from "./pkg/cexplorer_api_rs.js";
+//!
+//! await init();
+//! const client = new CexplorerClient("mainnet", apiKey);
+//! const tx = await client.getTxDetail(hash);
+//! const pools = await client.getPoolList(20, 0);
+//! ```
+
+use crate::client::CexplorerClient;
+use crate::endpoints::{
+    BlockListRequest, DrepListRequest, GovActionProposalListRequest, PoolDetailRequest,
+    PoolListRequest, TxListRequest,
+};
+use crate::error::CexplorerError;
+use js_sys::{Promise, JSON};
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+/// [`CexplorerClient`] exported to JavaScript under the same name
+#[wasm_bindgen(js_name = CexplorerClient)]
+#[derive(Debug, Clone)]
+pub struct WasmClient {
+    inner: CexplorerClient,
+}
+
+#[wasm_bindgen(js_class = CexplorerClient)]
+impl WasmClient {
+    #[wasm_bindgen(constructor)]
+    pub fn new(network: &str, api_key: &str) -> Result<WasmClient, JsError> {
+        Ok(WasmClient {
+            inner: CexplorerClient::from_credentials(network, api_key)?,
+        })
+    }
+
+    /// Copy of the client that gives up on requests after `millis` milliseconds
+    #[wasm_bindgen(js_name = withTimeout)]
+    pub fn with_timeout(&self, millis: u32) -> WasmClient {
+        WasmClient {
+            inner: self.inner.with_timeout(Duration::from_millis(millis.into())),
+        }
+    }
+
+    #[wasm_bindgen(js_name = getTxDetail)]
+    pub fn get_tx_detail(&self, hash: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_tx_detail(&hash.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getTxList)]
+    pub fn get_tx_list(&self, limit: Option<u32>, offset: Option<u32>) -> Promise {
+        let client = self.inner.clone();
+        let request = TxListRequest {
+            limit: limit.map(u64::from),
+            offset: offset.map(u64::from),
+            ..Default::default()
+        };
+        respond(async move { client.get_tx_list(request).await })
+    }
+
+    #[wasm_bindgen(js_name = getBlockDetail)]
+    pub fn get_block_detail(&self, hash: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_block_detail(&hash.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getBlockList)]
+    pub fn get_block_list(&self, limit: Option<u32>, offset: Option<u32>) -> Promise {
+        let client = self.inner.clone();
+        let request = BlockListRequest {
+            limit: limit.map(u64::from),
+            offset: offset.map(u64::from),
+            ..Default::default()
+        };
+        respond(async move { client.get_block_list(request).await })
+    }
+
+    #[wasm_bindgen(js_name = getAddressDetail)]
+    pub fn get_address_detail(&self, address: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_address_detail(&address.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getStakeDetail)]
+    pub fn get_stake_detail(&self, stake_address: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_stake_detail(&stake_address.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getPoolDetail)]
+    pub fn get_pool_detail(&self, pool_id: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move {
+            client
+                .get_pool_detail(PoolDetailRequest::new().pool_id(pool_id.parse()?))
+                .await
+        })
+    }
+
+    #[wasm_bindgen(js_name = getPoolList)]
+    pub fn get_pool_list(&self, limit: Option<u32>, offset: Option<u32>) -> Promise {
+        let client = self.inner.clone();
+        let request = PoolListRequest {
+            limit: limit.map(u64::from),
+            offset: offset.map(u64::from),
+            ..Default::default()
+        };
+        respond(async move { client.get_pools_list(request).await })
+    }
+
+    #[wasm_bindgen(js_name = getEpochList)]
+    pub fn get_epoch_list(&self) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_epoch_list().await })
+    }
+
+    #[wasm_bindgen(js_name = getEpochDetailParam)]
+    pub fn get_epoch_detail_param(&self, no: u32) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_epoch_detail_param(no.into()).await })
+    }
+
+    #[wasm_bindgen(js_name = getAssetDetail)]
+    pub fn get_asset_detail(&self, fingerprint: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_asset_detail(&fingerprint.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getDrepDetail)]
+    pub fn get_drep_detail(&self, drep_id: String) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_drep_detail(&drep_id.parse()?).await })
+    }
+
+    #[wasm_bindgen(js_name = getDrepList)]
+    pub fn get_drep_list(&self, limit: Option<u32>, offset: Option<u32>) -> Promise {
+        let client = self.inner.clone();
+        let request = DrepListRequest {
+            limit: limit.map(u64::from),
+            offset: offset.map(u64::from),
+            ..Default::default()
+        };
+        respond(async move { client.get_drep_list(request).await })
+    }
+
+    #[wasm_bindgen(js_name = getGovActionProposalList)]
+    pub fn get_gov_action_proposal_list(&self, limit: Option<u32>, offset: Option<u32>) -> Promise {
+        let client = self.inner.clone();
+        let request = GovActionProposalListRequest {
+            limit,
+            offset,
+            ..Default::default()
+        };
+        respond(async move { client.get_gov_action_proposal_list(request).await })
+    }
+
+    #[wasm_bindgen(js_name = getMiscBasic)]
+    pub fn get_misc_basic(&self) -> Promise {
+        let client = self.inner.clone();
+        respond(async move { client.get_misc_basic().await })
+    }
+}
+
+/// Resolve the promise with the response as JSON, or reject it with the error
+fn respond<T, F>(future: F) -> Promise
+where
+    T: Serialize,
+    F: Future<Output = Result<T, CexplorerError>> + 'static,
+{
+    future_to_promise(async move {
+        let response = future.await.map_err(|e| JsValue::from(JsError::from(e)))?;
+        let json = serde_json::to_string(&response).map_err(|e| JsValue::from(JsError::from(e)))?;
+        JSON::parse(&json)
+    })
+}
@@ -0,0 +1,52 @@
+use cexplorer_api_rs::*;
+
+#[test]
+fn amounts_accept_numbers_and_strings() {
+    let fee: Lovelace = serde_json::from_str("174257").unwrap();
+    let refund: Lovelace = serde_json::from_str("\"-2000000\"").unwrap();
+    assert_eq!(fee.lovelace(), 174_257);
+    assert_eq!(refund, Lovelace::from_ada(-2));
+
+    let burn: TokenQuantity = serde_json::from_str("-1").unwrap();
+    assert!(burn.is_negative());
+
+    // Past u64::MAX a quantity is only exact when it arrives as a string
+    let supply: TokenQuantity = serde_json::from_str("\"36893488147419103232\"").unwrap();
+    assert_eq!(supply.get(), 1i128 << 65);
+    assert_eq!(serde_json::to_string(&supply).unwrap(), "\"36893488147419103232\"");
+    assert_eq!(serde_json::to_string(&TokenQuantity::from(7u64)).unwrap(), "7");
+}
+
+#[test]
+fn fractional_and_out_of_range_amounts_are_rejected() {
+    assert!(serde_json::from_str::<Lovelace>("1.5").is_err());
+    assert!(serde_json::from_str::<Lovelace>("\"10 ADA\"").is_err());
+    assert!(serde_json::from_str::<Lovelace>("\"9223372036854775808\"").is_err());
+    assert!(serde_json::from_str::<TokenQuantity>("true").is_err());
+}
+
+#[test]
+fn numbers_past_u64_are_rejected_instead_of_rounded() {
+    // 2^64 + 1 reaches the deserializer as the f64 2^64
+    let err = serde_json::from_str::<TokenQuantity>("18446744073709551617").unwrap_err();
+    assert!(err.to_string().contains("send it as a string"), "{}", err);
+    assert!(serde_json::from_str::<TokenQuantity>("-18446744073709551617").is_err());
+    assert!(serde_json::from_str::<TokenQuantity>("1e30").is_err());
+
+    let exact: TokenQuantity = serde_json::from_str("\"18446744073709551617\"").unwrap();
+    assert_eq!(exact.get(), (1i128 << 64) + 1);
+    let small: TokenQuantity = serde_json::from_str("2.0").unwrap();
+    assert_eq!(small.get(), 2);
+}
+
+#[test]
+fn lovelace_formats_as_ada_and_adds_up() {
+    let outputs = [Lovelace::new(10_234_891_002), Lovelace::new(174_257)];
+    let total: Lovelace = outputs.iter().sum();
+
+    assert_eq!(total.to_string(), "10235.065259 ADA");
+    assert_eq!((-Lovelace::new(5)).to_string(), "-0.000005 ADA");
+    assert_eq!(Lovelace::new(i64::MAX).checked_add(Lovelace::new(1)), None);
+    assert_eq!(TokenQuantity::new(1_500).to_decimal_string(2), "15.00");
+    assert_eq!(TokenQuantity::new(42).to_decimal_string(0), "42");
+}
@@ -0,0 +1,79 @@
+//! Drives the blocking client against the mock server from a plain thread.
+
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use cexplorer_api_rs::blocking;
+use cexplorer_api_rs::prelude::*;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn blocking_client_mirrors_the_async_one() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(common::mock_server());
+    let client = blocking::CexplorerClient::from_async(common::client_for(&server)).unwrap();
+
+    let tx = client.get_tx_detail(&common::tx_hash()).unwrap();
+    assert_eq!(tx.data.hash, common::TX_HASH);
+
+    let pools = client
+        .call(PoolListRequest::new().limit(2).offset(0))
+        .unwrap();
+    assert_eq!(pools.data.data.len(), 2);
+
+    let missing: TxHash = "00".repeat(32).parse().unwrap();
+    let err = client.get_tx_detail(&missing).unwrap_err();
+    assert!(matches!(err, CexplorerError::NotFound { .. }));
+}
+
+#[test]
+fn paginator_iterates_every_page() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        let fixture: Value = serde_json::from_str(&common::load("pool_list")).unwrap();
+        let pools = fixture["data"]["data"].as_array().unwrap().clone();
+
+        for (offset, items) in [("0", pools.clone()), ("2", pools[..1].to_vec())] {
+            let mut page = fixture.clone();
+            page["data"]["count"] = json!(3);
+            page["data"]["data"] = Value::Array(items);
+            Mock::given(method("GET"))
+                .and(path("/pool/list"))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page))
+                .mount(&server)
+                .await;
+        }
+        server
+    });
+    let client = blocking::CexplorerClient::from_async(common::client_for(&server)).unwrap();
+
+    let pools = client
+        .paginate(|client, page| async move {
+            client
+                .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+                .await
+        })
+        .page_size(2);
+    let walked: Vec<PoolData> = pools.into_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(walked.len(), 3);
+
+    let mut first = client
+        .paginate(|client, page| async move {
+            client
+                .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+                .await
+        })
+        .page_size(2)
+        .max_items(1)
+        .into_iter();
+    assert!(first.next().unwrap().is_ok());
+    assert!(first.next().is_none());
+
+    // Both pages for the full walk, then only the first one
+    assert_eq!(runtime.block_on(server.received_requests()).unwrap().len(), 3);
+}
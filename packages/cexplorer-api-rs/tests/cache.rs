@@ -0,0 +1,70 @@
+//! Cache rules, checked against response bodies without a server.
+
+use cexplorer_api_rs::*;
+use serde_json::json;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn blocks_are_final_once_their_time_is_old_enough() {
+    let CacheRule::ImmutableWhen { finalized, .. } =
+        CacheRule::finalized_after(Duration::from_secs(60), "/data/time")
+    else {
+        panic!("finalized_after builds an ImmutableWhen rule");
+    };
+    let final_at = |time: &str| finalized(&json!({ "data": { "time": time } }));
+
+    for time in [
+        "2024-02-23T08:12:09",
+        "2024-02-23 08:12:09.250",
+        "2024-02-23T08:12:09Z",
+        "2024-02-23T10:12:09+02:00",
+        "2024-02-23",
+    ] {
+        assert!(final_at(time), "{}", time);
+    }
+    assert!(!final_at("2999-01-01T00:00:00"));
+
+    // Malformed times, including multi-byte characters where digits belong,
+    // leave the response mutable instead of panicking
+    for time in [
+        "2024-02-23T0é:12:0",
+        "2024-02-2é08:12:09",
+        "2024-02-30T08:12:09",
+        "2024-13-01T08:12:09",
+        "2024-02-23T24:00:00",
+        "2024-02-23T+8:12:09",
+        "2024-02-23T08:12:09+2",
+        "1969-12-31T23:59:59",
+        "",
+    ] {
+        assert!(!final_at(time), "{}", time);
+    }
+    assert!(!finalized(&json!({ "data": { "time": 1_708_675_929 } })));
+}
+
+#[test]
+fn epoch_params_are_final_once_their_epoch_is_over() {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+    for (policy, slots) in [
+        (CachePolicy::for_network(&Network::Mainnet), SlotConfig::MAINNET),
+        (CachePolicy::for_network(&Network::Preview), SlotConfig::PREVIEW),
+        (CachePolicy::default(), SlotConfig::PREPROD),
+    ] {
+        let CacheRule::ImmutableWhen { finalized, .. } = policy.rule_for("/epoch/param?no=1") else {
+            panic!("epoch parameters are cached as an ImmutableWhen rule");
+        };
+        let final_at = |epoch: u64| finalized(&json!({ "data": { "epoch_no": epoch } }));
+
+        let current = slots.unix_to_epoch(now).unwrap();
+        assert!(!final_at(current + 1));
+        assert!(!final_at(current));
+        assert!(final_at(current - 1));
+        assert!(final_at(slots.shelley_epoch));
+    }
+
+    assert!(matches!(
+        CachePolicy::for_network(&Network::Custom("http://localhost".into())).rule_for("/epoch/param"),
+        CacheRule::Ttl(_)
+    ));
+}
@@ -0,0 +1,143 @@
+//! Runs the `cexplorer` binary against the mock server.
+
+#![cfg(feature = "cli")]
+
+mod common;
+
+use serde_json::{json, Value};
+use std::process::{Command, Output};
+use tokio::runtime::Runtime;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn cexplorer(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cexplorer"))
+        .args(args)
+        .env_remove("CEXPLORER_API_KEY")
+        .env_remove("CEXPLORER_NETWORK")
+        .env_remove("CEXPLORER_BASE_URL")
+        .env_remove("CEXPLORER_PROFILE")
+        .env_remove("CEXPLORER_CONFIG")
+        .env("XDG_CONFIG_HOME", "/nonexistent")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn commands_print_responses_in_each_format() {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(common::mock_server());
+    let base = ["--api-key", "test-key", "--base-url", &server.uri()];
+
+    let detail = cexplorer(&[&base[..], &["block", "detail", common::BLOCK_HASH]].concat());
+    let detail: Value = serde_json::from_str(&stdout(&detail)).unwrap();
+    assert_eq!(detail["data"]["hash"], common::BLOCK_HASH);
+
+    let list = [&base[..], &["block", "list", "--limit", "2", "--offset", "0"]].concat();
+    let ndjson = stdout(&cexplorer(&[&list[..], &["-o", "ndjson"]].concat()));
+    assert_eq!(ndjson.lines().count(), 2);
+    for line in ndjson.lines() {
+        assert!(serde_json::from_str::<Value>(line).unwrap().is_object());
+    }
+
+    let csv = stdout(&cexplorer(&[&list[..], &["-o", "csv"]].concat()));
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.lines().next().unwrap().split(',').any(|column| column == "hash"));
+
+    let table = stdout(&cexplorer(&[&list[..], &["-o", "table"]].concat()));
+    assert_eq!(table.lines().count(), 3);
+    assert!(table.starts_with("BLOCK_NO  "));
+
+    let unknown_hash = "00".repeat(32);
+    let missing = cexplorer(&[&base[..], &["tx", "detail", &unknown_hash]].concat());
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("/tx/detail"));
+}
+
+#[test]
+fn all_walks_every_page() {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        let fixture: Value = serde_json::from_str(&common::load("pool_list")).unwrap();
+        let pools = fixture["data"]["data"].as_array().unwrap().clone();
+
+        for (offset, items) in [("0", pools.clone()), ("2", pools[..1].to_vec())] {
+            let mut page = fixture.clone();
+            page["data"]["count"] = json!(3);
+            page["data"]["data"] = Value::Array(items);
+            Mock::given(method("GET"))
+                .and(path("/pool/list"))
+                .and(query_param("offset", offset))
+                .and(query_param("limit", "2"))
+                .and(query_param("order", "live_stake"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        server
+    });
+
+    let base = ["--api-key", "test-key", "--base-url", &server.uri()];
+    let list = ["pool", "list", "--order", "live_stake", "--limit", "2", "--all", "-o", "ndjson"];
+    let output = cexplorer(&[&base[..], &list].concat());
+    assert_eq!(stdout(&output).lines().count(), 3);
+
+    let single = cexplorer(&[&base[..], &["tx", "detail", common::TX_HASH, "--all"]].concat());
+    assert!(!single.status.success());
+    assert!(String::from_utf8_lossy(&single.stderr).contains("--all"));
+}
+
+#[test]
+fn credentials_come_from_the_profile_unless_overridden() {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(common::mock_server());
+
+    let path = std::env::temp_dir().join(format!("cexplorer-cli-{}.toml", std::process::id()));
+    let profile = format!(
+        "[ci]\nnetwork = \"mainnet\"\napi_key = \"profile-key\"\nbase_url = \"{}\"\n",
+        server.uri()
+    );
+    std::fs::write(&path, profile).unwrap();
+    let config = ["--config", path.to_str().unwrap()];
+
+    stdout(&cexplorer(&[&config[..], &["--profile", "ci", "misc", "health"]].concat()));
+    stdout(&cexplorer(
+        &[&config[..], &["--profile", "ci", "--api-key", "flag-key", "misc", "health"]].concat(),
+    ));
+
+    let unknown = cexplorer(&[&config[..], &["--profile", "other", "misc", "health"]].concat());
+    assert!(String::from_utf8_lossy(&unknown.stderr).contains("no profile `other`"));
+
+    let keys: Vec<_> = runtime
+        .block_on(server.received_requests())
+        .unwrap()
+        .iter()
+        .map(|request| request.headers.get("api-key").unwrap().to_str().unwrap().to_string())
+        .collect();
+    assert_eq!(keys, ["profile-key", "flag-key"]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn invalid_values_name_their_argument() {
+    // Rejected before any request, so no server or API key is needed
+    let limit = cexplorer(&["block", "list", "--limit", "ten"]);
+    assert!(!limit.status.success());
+    let stderr = String::from_utf8_lossy(&limit.stderr);
+    assert!(stderr.contains("--limit: "), "{}", stderr);
+
+    let hash = cexplorer(&["tx", "detail", "not-a-hash"]);
+    let stderr = String::from_utf8_lossy(&hash.stderr);
+    assert!(stderr.contains("HASH: Invalid transaction hash"), "{}", stderr);
+
+    let help = stdout(&cexplorer(&["block", "list", "--help"]));
+    assert!(help.contains("Endpoint: GET /block/list"));
+}
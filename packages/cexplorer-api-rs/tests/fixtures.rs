@@ -0,0 +1,227 @@
+//! Deserializes the fixture of every endpoint into its response type and fails
+//! on fields the type drops. The committed fixtures are synthetic (see
+//! `tests/common`), so this checks the models against the documented response
+//! shapes; after re-recording with the `record` test it checks them against
+//! the live API.
+
+mod common;
+
+use cexplorer_api_rs::prelude::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+macro_rules! fixture_tests {
+    ($($name:ident => $request:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_fixture::<$request>(stringify!($name));
+            }
+        )*
+
+        /// Fixture names and the request types they answer
+        const TESTED: &[(&str, &str)] = &[$((stringify!($name), stringify!($request))),*];
+    };
+}
+
+fixture_tests! {
+    account_reward => AccountRewardsRequest,
+    ada_pots => AdaPotsRequest,
+    address_detail => AddressDetailRequest,
+    address_list => AddressListRequest,
+    address_utxo => AddressUtxoRequest,
+    analytics_hardforks => HardforksRequest,
+    analytics_pool_block => AnalyticsPoolBlockRequest,
+    analytics_rate => AnalyticsRateRequest,
+    article_detail => ArticleDetailRequest,
+    article_list => ArticleListRequest,
+    asset_detail => AssetDetailRequest,
+    asset_list => AssetListRequest,
+    asset_metadata => AssetMetadataRequest,
+    asset_mint => AssetMintRequest,
+    asset_owner_history => AssetOwnerHistoryRequest,
+    asset_owners => AssetOwnersRequest,
+    asset_stats => AssetStatsRequest,
+    average_drep => AverageDrepRequest,
+    average_pool => AveragePoolRequest,
+    block_detail => BlockDetailRequest,
+    block_list => BlockListRequest,
+    check_delegation => CheckDelegationRequest,
+    committee_detail => CommitteeDetailRequest,
+    committee_list => CommitteeListRequest,
+    committee_member => CommitteeMemberRequest,
+    constitution_list => ConstitutionListRequest,
+    contract_transactions => ContractTransactionsRequest,
+    datum_detail => DatumDetailRequest,
+    defi_order_list => DeFiOrderRequest,
+    defi_stat => DeFiTokenStatRequest,
+    defi_token_list => DeFiTokenListRequest,
+    deleg_epoch_changes => DelegEpochChangesRequest,
+    deleg_epoch_registered => DelegEpochRegisteredRequest,
+    delegation_state => DelegationsStateRequest,
+    delegation_vote => DelegationVoteRequest,
+    delegations_to_retired => DelegationsToRetiredRequest,
+    drep_analytics => DrepAnalyticsRequest,
+    drep_delegator => DrepDelegatorRequest,
+    drep_delegator_stats => DrepDelegatorStatsRequest,
+    drep_deregistrations => DrepDeregistrationsRequest,
+    drep_detail => DrepDetailRequest,
+    drep_list => DrepListRequest,
+    drep_list_vote => DrepListVoteRequest,
+    drep_not_spo_same_time => DrepNotSpoSameTimeRequest,
+    drep_registrations => DrepRegistrationsRequest,
+    drep_spo_same_time => DrepSpoSameTimeRequest,
+    drep_stat => DrepStatRequest,
+    drep_updates => DrepUpdatesRequest,
+    drep_vote => DrepVoteRequest,
+    epoch_analytics => EpochAnalyticsRequest,
+    epoch_list => EpochListRequest,
+    epoch_param => EpochParamRequest,
+    epoch_stats => EpochStatsRequest,
+    genesis_addr => GenesisAddrRequest,
+    global_pool_awards => GlobalPoolAwardsRequest,
+    gov_action_detail => GovActionProposalDetailRequest,
+    gov_action_list => GovActionProposalListRequest,
+    gov_vote => GovVoteRequest,
+    gov_vote_not => GovVoteNotRequest,
+    group_detail => GroupDetailRequest,
+    group_list => GroupListRequest,
+    inspect_address => InspectAddressRequest,
+    metadata_list => MetadataTxListRequest,
+    misc_api => MiscApiRequest,
+    misc_basic => MiscBasicRequest,
+    misc_const => MiscConstRequest,
+    misc_health => MiscHealthRequest,
+    misc_market => MiscMarketRequest,
+    misc_protocol_parameters => MiscProtocolParametersRequest,
+    misc_rate => MiscRateRequest,
+    misc_search => MiscSearchRequest,
+    misc_validate => MiscValidateRequest,
+    policy_detail => PolicyDetailRequest,
+    policy_owner => PolicyOwnerRequest,
+    policy_stats => PolicyStatsRequest,
+    poll_list => PollListRequest,
+    pool_about => PoolAboutRequest,
+    pool_awards => PoolAwardsRequest,
+    pool_birthdays => PoolBirthdaysRequest,
+    pool_blocks => PoolBlocksRequest,
+    pool_delegator_stats => PoolDelegatorStatsRequest,
+    pool_delegators => PoolDelegatorsRequest,
+    pool_deregistrations => PoolDeregistrationsRequest,
+    pool_detail => PoolDetailRequest,
+    pool_list => PoolListRequest,
+    pool_registrations => PoolRegistrationsRequest,
+    pool_retire => PoolRetireRequest,
+    pool_rewards => PoolRewardsRequest,
+    pool_update => PoolUpdateRequest,
+    retired_pools => RetiredPoolsRequest,
+    script_detail => ScriptDetailRequest,
+    script_list => ScriptListRequest,
+    script_redeemer => ScriptRedeemerRequest,
+    stake_delegations => StakeDelegationsRequest,
+    stake_detail => StakeDetailRequest,
+    stake_drep_retired => StakeDrepRetiredRequest,
+    stake_dreps_not_spo => StakeDrepsNotSpoRequest,
+    stake_is_spo_drep => StakeIsSpoDrepRequest,
+    stake_registrations => StakeRegistrationsRequest,
+    thresholds => ThresholdsRequest,
+    tool_tx_sent => TxSentRequest,
+    top_addresses => TopAddressesRequest,
+    top_margins => TopMarginsRequest,
+    top_multi_delegators => TopMultiDelegatorsRequest,
+    top_staking_accounts => TopStakingAccountsRequest,
+    treasury_stats => TreasuryDonationStatsRequest,
+    tx_detail => TxDetailRequest,
+    tx_list => TxListRequest,
+    wallet_compare => CompareWalletsRequest,
+    wealth_composition => WealthCompositionRequest,
+    withdrawals => WithdrawalsRequest,
+}
+
+/// Parse the fixture of endpoint `E` and fail on fields the model silently drops
+fn check_fixture<E: Endpoint>(name: &str)
+where
+    E::Response: Serialize,
+{
+    let fixture = common::FIXTURES
+        .iter()
+        .find(|fixture| fixture.name == name)
+        .unwrap_or_else(|| panic!("{name} is missing from tests/common/mod.rs FIXTURES"));
+    assert_eq!(
+        (fixture.path, fixture.fixed),
+        (E::PATH, E::QUERY),
+        "{name} is registered for another endpoint in FIXTURES"
+    );
+
+    let body = common::load(name);
+    let parsed: E::Response = serde_json::from_str(&body)
+        .unwrap_or_else(|e| panic!("{name}.json no longer matches its response type: {e}"));
+
+    let original: Value = serde_json::from_str(&body).unwrap();
+    let round_trip = serde_json::to_value(&parsed).unwrap();
+
+    let mut dropped = Vec::new();
+    unmodelled_fields(&original, &round_trip, String::new(), &mut dropped);
+    assert!(
+        dropped.is_empty(),
+        "{name}.json has fields missing from its response type: {dropped:?}"
+    );
+}
+
+fn unmodelled_fields(original: &Value, parsed: &Value, at: String, dropped: &mut Vec<String>) {
+    match (original, parsed) {
+        (Value::Object(original), Value::Object(parsed)) => {
+            for (key, value) in original {
+                let field = format!("{at}/{key}");
+                match parsed.get(key) {
+                    Some(parsed) => unmodelled_fields(value, parsed, field, dropped),
+                    None if value.is_null() => {}
+                    None => dropped.push(field),
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(parsed)) => {
+            for (i, (value, parsed)) in original.iter().zip(parsed).enumerate() {
+                unmodelled_fields(value, parsed, format!("{at}/{i}"), dropped);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn every_fixture_is_registered_and_tested() {
+    let on_disk: BTreeSet<String> = std::fs::read_dir(common::fixture_dir())
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            (path.extension()? == "json").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+    let registered: BTreeSet<String> = common::FIXTURES.iter().map(|f| f.name.to_string()).collect();
+    let tested: BTreeSet<String> = TESTED.iter().map(|(name, _)| name.to_string()).collect();
+
+    assert_eq!(on_disk, registered, "tests/common/mod.rs FIXTURES is out of sync with tests/fixtures/");
+    assert_eq!(on_disk, tested, "fixture_tests! is out of sync with tests/fixtures/");
+}
+
+#[test]
+fn every_endpoint_has_a_fixture() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("endpoints");
+    let mut endpoints = BTreeSet::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let source = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+        // Invocations start a line; the ones inside macro bodies are indented
+        let endpoints_in = |macro_start| source.split(macro_start).skip(1);
+        for invocation in endpoints_in("\nendpoint!(").chain(endpoints_in("\ntx_filter!(")) {
+            let request = invocation.trim_start().trim_start_matches("pub struct ");
+            let end = request.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(request.len());
+            endpoints.insert(request[..end].to_string());
+        }
+    }
+    let tested: BTreeSet<String> = TESTED.iter().map(|(_, request)| request.to_string()).collect();
+
+    let missing: Vec<_> = endpoints.difference(&tested).collect();
+    assert!(missing.is_empty(), "endpoints without a fixture_tests! entry: {missing:?}");
+}
@@ -0,0 +1,70 @@
+use cexplorer_api_rs::*;
+
+const POOL_ID: &str = "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy";
+const POOL_HASH: &str = "0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735";
+
+#[test]
+fn pool_ids_convert_between_bech32_and_hash_raw() {
+    let pool: PoolId = POOL_ID.parse().unwrap();
+    assert_eq!(pool.hash_raw(), POOL_HASH);
+
+    let from_hash = PoolId::from_hash_raw(&POOL_HASH.to_uppercase()).unwrap();
+    assert_eq!(from_hash, pool);
+
+    let request = PoolDetailRequest::new().hash_raw(&pool);
+    assert_eq!(request.hash_raw.as_deref(), Some(POOL_HASH));
+}
+
+#[test]
+fn bech32_ids_are_validated() {
+    "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw".parse::<StakeAddress>().unwrap();
+    "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x"
+        .parse::<Address>()
+        .unwrap();
+    "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3".parse::<AssetFingerprint>().unwrap();
+
+    // Wrong prefix, bad checksum and a payment address where a stake address is expected
+    assert!(POOL_ID.parse::<StakeAddress>().is_err());
+    assert!("pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdz".parse::<PoolId>().is_err());
+    let err = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x"
+        .parse::<StakeAddress>()
+        .unwrap_err();
+    assert!(matches!(err, CexplorerError::InvalidIdentifier { kind: "stake address", .. }));
+}
+
+#[test]
+fn hashes_are_checked_and_lowercased() {
+    let hash = "A951EEE85659818A54A34A66E9E53E6C658A28A5156DF1B6702C44128DC1D15B";
+    assert_eq!(hash.parse::<BlockHash>().unwrap().as_str(), hash.to_lowercase());
+    assert!(hash[..62].parse::<TxHash>().is_err());
+    assert!(POOL_HASH.parse::<DatumHash>().is_err());
+    assert!(POOL_HASH.parse::<PolicyId>().is_ok());
+
+    let drep: DRepId = POOL_HASH.parse().unwrap();
+    assert_eq!(drep.credential_hash(), POOL_HASH);
+}
+
+#[test]
+fn governance_and_script_ids_are_validated() {
+    let script: ScriptHash = POOL_HASH.to_uppercase().parse().unwrap();
+    assert_eq!(script.as_str(), POOL_HASH);
+    assert!("8ad3d454f3496a35cb0d07b0fd32f687f66338b7d60e787fc0a22939e5d8833e".parse::<ScriptHash>().is_err());
+
+    "gov_action13tfag48nf94rtjcdq7c06vhkslmxxw9h6c88sl7q5g5nnewcsvlqqfgyy3v".parse::<GovActionId>().unwrap();
+    let action: GovActionId = "8AD3D454F3496A35CB0D07B0FD32F687F66338B7D60E787FC0A22939E5D8833E#0".parse().unwrap();
+    assert_eq!(action.as_str(), "8ad3d454f3496a35cb0d07b0fd32f687f66338b7d60e787fc0a22939e5d8833e#0");
+    assert!("8ad3d454f3496a35cb0d07b0fd32f687f66338b7d60e787fc0a22939e5d8833e#x".parse::<GovActionId>().is_err());
+    assert!(POOL_ID.parse::<GovActionId>().is_err());
+
+    let member: CommitteeMemberId = POOL_HASH.parse().unwrap();
+    assert_eq!(member.as_str(), POOL_HASH);
+    let err = POOL_ID.parse::<CommitteeMemberId>().unwrap_err();
+    assert!(matches!(err, CexplorerError::InvalidIdentifier { kind: "committee member id", .. }));
+}
+
+#[test]
+fn ids_deserialize_with_validation() {
+    let pool: PoolId = serde_json::from_str(&format!("\"{}\"", POOL_HASH)).unwrap();
+    assert_eq!(serde_json::to_string(&pool).unwrap(), format!("\"{}\"", POOL_ID));
+    assert!(serde_json::from_str::<TxHash>("\"not-a-hash\"").is_err());
+}
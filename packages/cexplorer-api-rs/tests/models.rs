@@ -0,0 +1,92 @@
+//! Deserializes the nested parts of the schema that the fixtures only ever
+//! carry as `null`.
+
+use cexplorer_api_rs::types::*;
+use cexplorer_api_rs::Lovelace;
+use serde_json::json;
+
+#[test]
+fn tx_metadata_keeps_its_shape() {
+    let metadata: TxMetadata = serde_json::from_value(json!({
+        "674": { "msg": ["Invoice 42", "paid"] },
+        "721": { "policy": { "Token1": { "name": "Token 1", "files": [], "edition": 1 } } },
+        "1967": 18446744073709551615u64,
+    }))
+    .unwrap();
+
+    let msg = metadata["674"].get("msg").and_then(Metadatum::as_list).unwrap();
+    assert_eq!(msg[0].as_text(), Some("Invoice 42"));
+    let token = metadata["721"].get("policy").and_then(|p| p.get("Token1")).unwrap();
+    assert_eq!(token.get("edition").and_then(Metadatum::as_int), Some(1));
+    assert_eq!(metadata["1967"].as_int(), Some(u64::MAX as i128));
+}
+
+#[test]
+fn plutus_contracts_and_datums_are_typed() {
+    let contract: PlutusContract = serde_json::from_value(json!({
+        "size": 4012,
+        "type": "plutusV2",
+        "address": "addr1w9qzpelu9hn45pefc0xr4ac4kdxeswq7pndul2vuj59u8tqaxdznu",
+        "bytecode": "5909a8",
+        "script_hash": "4020e7fc2de75a0729c3cc3af715b34d98381e0cdbcfa99c950bc3ac",
+        "valid_contract": [],
+        "input": {
+            "datum": {
+                "hash": "923918e403bf43c34b4ef6b48eb2ee04babed17320d8d1b9ff9ad086e86f44ec",
+                "value": { "constructor": 0, "fields": [{ "bytes": "ab" }, { "int": -3 }] },
+            },
+            "redeemer": {
+                "fee": 120000,
+                "unit": { "mem": 1700, "steps": 476468 },
+                "purpose": "spend",
+                "datum": { "value": { "constructor": 1, "fields": [{ "list": [{ "int": 1 }] }] } },
+            },
+        },
+        "label": {
+            "label": "Minswap",
+            "source": "crfa",
+            "category": ["DEX"],
+            "data": { "scriptHash": "4020e7fc", "contractAddress": "addr1w9" },
+            "extra": { "bg": null, "fw": null, "link": null, "color": null },
+        },
+    }))
+    .unwrap();
+
+    let input = contract.input.unwrap();
+    let datum = input.datum.unwrap().value.unwrap();
+    assert_eq!(datum.fields[1], PlutusData::Int { int: -3 });
+    let redeemer = input.redeemer.unwrap();
+    assert_eq!(redeemer.fee, Some(Lovelace::new(120_000)));
+    assert_eq!(redeemer.unit.unwrap().steps, 476_468);
+    assert_eq!(contract.label.unwrap().category, ["DEX"]);
+
+    let data: PlutusData = serde_json::from_value(json!({
+        "map": [{ "k": { "bytes": "00" }, "v": { "constructor": 2, "fields": [] } }]
+    }))
+    .unwrap();
+    assert!(matches!(data, PlutusData::Map { ref map } if map.len() == 1));
+}
+
+#[test]
+fn plutus_integers_past_u64_do_not_reject_the_datum() {
+    let value: DatumValue = serde_json::from_str(
+        r#"{ "constructor": 0, "fields": [{ "int": 18446744073709551615 }, { "int": 18446744073709551616 }, { "int": -1 }] }"#,
+    )
+    .unwrap();
+
+    assert_eq!(value.fields[0], PlutusData::Int { int: u64::MAX as i128 });
+    assert!(matches!(value.fields[1], PlutusData::Other(ref other) if other["int"].is_f64()));
+    assert_eq!(value.fields[2], PlutusData::Int { int: -1 });
+}
+
+#[test]
+fn fiat_rates_are_pairs_per_currency() {
+    let rates: FiatRates = serde_json::from_value(json!({
+        "usd": [1.0, 0.45],
+        "eur": [1.08, 0.41],
+    }))
+    .unwrap();
+
+    assert_eq!(rates["eur"], FiatRate { currency: 1.08, ada: 0.41 });
+    assert_eq!(serde_json::to_value(&rates).unwrap()["usd"], json!([1.0, 0.45]));
+}
@@ -0,0 +1,54 @@
+use cexplorer_api_rs::params::*;
+use cexplorer_api_rs::{BlockListRequest, PoolDelegatorsRequest, PoolDetailRequest};
+
+const POOL_ID: &str = "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy";
+
+#[test]
+fn known_values_round_trip() {
+    assert_eq!(serde_json::to_string(&VoterRole::Spo).unwrap(), "\"SPO\"");
+    assert_eq!("redeemer.count".parse::<ScriptListOrder>().unwrap(), ScriptListOrder::RedeemerCount);
+
+    let state: GovActionState = serde_json::from_str("\"Ratified\"").unwrap();
+    assert_eq!(state, GovActionState::Ratified);
+    assert_eq!(state.to_string(), "Ratified");
+}
+
+#[test]
+fn unknown_values_are_kept() {
+    let order = PoolListOrder::from("saturation");
+    assert_eq!(order, PoolListOrder::Other("saturation".to_string()));
+    assert_eq!(serde_json::to_string(&order).unwrap(), "\"saturation\"");
+
+    let query = serde_urlencoded::to_string(
+        PoolDelegatorsRequest::new(POOL_ID.parse().unwrap(), PoolDelegatorType::Gone)
+            .order(DelegatorOrder::Other("since".to_string())),
+    )
+    .unwrap();
+    assert_eq!(query, format!("pool_id={}&type=gone&order=since", POOL_ID));
+}
+
+#[test]
+fn pool_detail_looks_up_by_one_id_only() {
+    let pool: cexplorer_api_rs::PoolId = POOL_ID.parse().unwrap();
+
+    let request = PoolDetailRequest::new().pool_id(pool.clone()).hash_raw(&pool);
+    let query = serde_urlencoded::to_string(request).unwrap();
+    assert_eq!(query, "hash_raw=0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735");
+
+    let request = PoolDetailRequest::new().hash_raw(&pool).pool_id(pool);
+    let query = serde_urlencoded::to_string(request).unwrap();
+    assert_eq!(query, format!("pool_id={}", POOL_ID));
+}
+
+#[test]
+#[allow(deprecated)]
+fn former_request_names_still_build_requests() {
+    let params = cexplorer_api_rs::BlockListParams {
+        limit: Some(2),
+        ..Default::default()
+    };
+    let request: BlockListRequest = params;
+    assert_eq!(serde_urlencoded::to_string(request).unwrap(), "limit=2");
+
+    let _: cexplorer_api_rs::endpoints::AddressListParams = cexplorer_api_rs::AddressListRequest::new();
+}
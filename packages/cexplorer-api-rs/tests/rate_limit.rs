@@ -0,0 +1,82 @@
+//! `RateLimiter` buckets, measured against the wall clock.
+
+use cexplorer_api_rs::{CexplorerError, RateLimit, RateLimiter};
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+
+/// Long enough for an uncontended `acquire`, far shorter than any refill here
+const PROMPTLY: Duration = Duration::from_millis(200);
+
+#[tokio::test]
+async fn per_minute_bucket_allows_a_burst_then_waits() {
+    let limiter = RateLimiter::new(RateLimit::per_minute(600.0));
+    for _ in 0..600 {
+        timeout(PROMPTLY, limiter.acquire()).await.unwrap().unwrap();
+    }
+
+    // 600 per minute refills one request every 100ms
+    let started = Instant::now();
+    limiter.acquire().await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(80), "{:?}", started.elapsed());
+    assert_eq!(limiter.usage().requests, 601);
+}
+
+#[tokio::test]
+async fn rates_below_one_still_let_requests_through() {
+    let limiter = RateLimiter::new(RateLimit::per_minute(0.5));
+    timeout(PROMPTLY, limiter.acquire()).await.unwrap().unwrap();
+    // The next one is two minutes away
+    assert!(timeout(PROMPTLY, limiter.acquire()).await.is_err());
+
+    let limiter = RateLimiter::new(RateLimit::default().with_requests_per_day(0.5));
+    limiter.acquire().await.unwrap();
+    let err = limiter.acquire().await.unwrap_err();
+    assert!(
+        matches!(err, CexplorerError::QuotaExhausted { ref quota, reset_in } if quota == "request"
+            && reset_in > Duration::from_secs(24 * 60 * 60)),
+        "{:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn zero_negative_and_infinite_limits_are_not_enforced() {
+    for rate in [0.0, -5.0, f64::NAN, f64::INFINITY] {
+        let limit = RateLimit::per_minute(rate)
+            .with_requests_per_day(rate)
+            .with_tokens_per_day(rate);
+        let limiter = RateLimiter::new(limit);
+        for _ in 0..10 {
+            timeout(PROMPTLY, limiter.acquire()).await.unwrap().unwrap();
+        }
+        limiter.record_tokens(100.0);
+        limiter.acquire().await.unwrap();
+
+        let usage = limiter.usage();
+        assert_eq!(usage.requests_remaining, None);
+        assert_eq!(usage.tokens_remaining, None);
+    }
+}
+
+#[tokio::test]
+async fn daily_allowances_fail_fast_once_spent() {
+    let limiter = RateLimiter::new(RateLimit::default().with_requests_per_day(2.0));
+    limiter.acquire().await.unwrap();
+    limiter.acquire().await.unwrap();
+    let err = limiter.acquire().await.unwrap_err();
+    assert!(matches!(err, CexplorerError::QuotaExhausted { ref quota, .. } if quota == "request"));
+    assert!(limiter.usage().requests_remaining.unwrap() < 1.0);
+
+    let limiter = RateLimiter::new(RateLimit::default().with_tokens_per_day(10.0));
+    limiter.acquire().await.unwrap();
+    limiter.record_tokens(4.0);
+    limiter.acquire().await.unwrap();
+    // A response may cost more than what was left
+    limiter.record_tokens(7.0);
+    let err = limiter.acquire().await.unwrap_err();
+    assert!(matches!(err, CexplorerError::QuotaExhausted { ref quota, .. } if quota == "token"));
+
+    let usage = limiter.usage();
+    assert_eq!(usage.requests, 2);
+    assert_eq!(usage.tokens, 11.0);
+}
@@ -0,0 +1,60 @@
+//! Re-records the fixtures in `tests/fixtures/` from the live API.
+//!
+//! Ignored by default since it needs network access and a key:
+//!
+//! ```text
+//! CEXPLORER_API_KEY=... cargo test --test record -- --ignored
+//! ```
+//!
+//! Every endpoint's fixture is recorded, unless `CEXPLORER_FIXTURES` names
+//! some of them, comma separated. `CEXPLORER_NETWORK` picks the network
+//! (mainnet by default) and `CEXPLORER_BASE_URL` overrides its URL. Run the
+//! `fixtures` tests afterwards to see whether the recorded responses still
+//! match the response types.
+
+mod common;
+
+use cexplorer_api_rs::CexplorerConfig;
+use serde_json::Value;
+
+#[tokio::test]
+#[ignore = "records fixtures from the live API"]
+async fn record_fixtures() {
+    let api_key = std::env::var("CEXPLORER_API_KEY").expect("CEXPLORER_API_KEY must be set");
+    let network = std::env::var("CEXPLORER_NETWORK").unwrap_or_else(|_| "mainnet".to_string());
+
+    let mut config = CexplorerConfig::new(&network, &api_key).unwrap();
+    if let Ok(base_url) = std::env::var("CEXPLORER_BASE_URL") {
+        config = config.with_base_url(&base_url);
+    }
+    let http = config.http_client().unwrap();
+    let only = std::env::var("CEXPLORER_FIXTURES").ok();
+    let selected = |name: &str| only.as_ref().is_none_or(|only| only.split(',').any(|n| n.trim() == name));
+
+    let mut failed = Vec::new();
+    for fixture in common::FIXTURES.iter().filter(|fixture| selected(fixture.name)) {
+        let query: Vec<_> = fixture.pairs().collect();
+        let response = http
+            .get(format!("{}{}", config.base_url(), fixture.path))
+            .query(&query)
+            .header("api-key", &config.api_key)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        let body = match response {
+            Ok(response) => response.json::<Value>().await,
+            Err(e) => Err(e),
+        };
+
+        match body {
+            Ok(body) => {
+                let pretty = serde_json::to_string_pretty(&body).unwrap() + "\n";
+                std::fs::write(common::fixture_path(fixture.name), pretty).unwrap();
+                println!("recorded {}", fixture.name);
+            }
+            Err(e) => failed.push(format!("{}: {e}", fixture.name)),
+        }
+    }
+
+    assert!(failed.is_empty(), "fixtures not recorded:\n{}", failed.join("\n"));
+}
@@ -0,0 +1,352 @@
+//! Replays fixture responses through a local mock server, exercising the
+//! client end to end without network access.
+
+mod common;
+
+use cexplorer_api_rs::prelude::*;
+use futures::TryStreamExt;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn pools_replay() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    let list = client
+        .get_pools_list(PoolListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert!(list.data.count >= 2);
+    assert_eq!(list.data.data.len(), 2);
+
+    let detail = client
+        .get_pool_detail(PoolDetailRequest::new().pool_id(common::pool_id()))
+        .await
+        .unwrap();
+    assert_eq!(detail.data.pool_id, common::POOL_ID);
+}
+
+#[tokio::test]
+async fn chain_replay() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    let blocks = client
+        .get_block_list(BlockListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(blocks.data.data.len(), 2);
+
+    let block = client.get_block_detail(&common::block_hash()).await.unwrap();
+    assert_eq!(block.data.hash, common::BLOCK_HASH);
+
+    let tx = client.get_tx_detail(&common::tx_hash()).await.unwrap();
+    assert_eq!(tx.data.hash, common::TX_HASH);
+
+    let txs = client
+        .get_tx_list(TxListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(txs.data.data.len(), 2);
+
+    let epochs = client.get_epoch_list().await.unwrap();
+    assert!(!epochs.data.data.is_empty());
+    client.get_epoch_detail_param(500).await.unwrap();
+}
+
+#[tokio::test]
+async fn governance_and_assets_replay() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    let dreps = client
+        .get_drep_list(DrepListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert!(dreps.data.count.is_some());
+    assert_eq!(dreps.data.data.len(), 2);
+
+    client.get_drep_stat().await.unwrap();
+
+    let actions = client
+        .get_gov_action_proposal_list(GovActionProposalListRequest::new().limit(1).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(actions.data.data.len(), 1);
+
+    let assets = client
+        .get_asset_list(AssetListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(assets.data.data.len(), 2);
+}
+
+#[tokio::test]
+async fn misc_replay() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    client.get_misc_api().await.unwrap();
+    client.get_misc_const().await.unwrap();
+    client.get_misc_health().await.unwrap();
+    client.get_misc_protocol_parameters().await.unwrap();
+}
+
+#[tokio::test]
+async fn requests_carry_api_key() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    client.get_misc_const().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests[0].headers.get("api-key").unwrap(), "test-key");
+}
+
+#[tokio::test]
+async fn request_builders_encode_only_set_fields() {
+    let server = MockServer::start().await;
+    let client = common::client_for(&server);
+
+    let request = PoolListRequest::new()
+        .order(PoolListOrder::LiveStake)
+        .sort(SortDirection::Desc)
+        .is_drep(true)
+        .limit(50);
+    client.get_pools_list(request).await.unwrap_err();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(
+        requests[0].url.query(),
+        Some("limit=50&sort=desc&order=live_stake&is_drep=1")
+    );
+}
+
+#[tokio::test]
+async fn call_encodes_request_and_fixed_parameters() {
+    let server = MockServer::start().await;
+    let client = common::client_for(&server);
+
+    client.inspect_address("addr1 q&view=x").await.unwrap_err();
+    client
+        .call(DrepRegistrationsRequest(TxFilterRequest::new().limit(5)))
+        .await
+        .unwrap_err();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.path(), "/address/extract");
+    assert_eq!(requests[0].url.query(), Some("view=addr1+q%26view%3Dx"));
+    assert_eq!(requests[1].url.path(), "/tx/filter");
+    assert_eq!(requests[1].url.query(), Some("limit=5&type=drep_registrations"));
+}
+
+#[tokio::test]
+async fn batches_skip_duplicates_and_fail_per_item() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server).with_batch_concurrency(2);
+
+    let missing: TxHash = "00".repeat(32).parse().unwrap();
+    let hashes = [common::tx_hash(), missing.clone(), common::tx_hash()];
+    let results = client.get_tx_details(&hashes).await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, common::tx_hash());
+    assert_eq!(results[0].1.as_ref().unwrap().data.hash, common::TX_HASH);
+    assert_eq!(results[1].0, missing);
+    assert!(matches!(results[1].1, Err(CexplorerError::NotFound { .. })));
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn unmatched_request_is_not_found() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+
+    let missing: TxHash = "00".repeat(32).parse().unwrap();
+    let err = client.get_tx_detail(&missing).await.unwrap_err();
+    assert!(matches!(err, CexplorerError::NotFound { ref endpoint } if endpoint == "/tx/detail"));
+}
+
+#[tokio::test]
+async fn envelope_error_code_is_api_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/misc/const"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "code": 401,
+            "msg": "Invalid api key",
+        })))
+        .mount(&server)
+        .await;
+
+    let err = common::client_for(&server).get_misc_const().await.unwrap_err();
+    assert!(matches!(err, CexplorerError::Api { code: 401, .. }));
+}
+
+#[tokio::test]
+async fn too_many_requests_is_rate_limited() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .mount(&server)
+        .await;
+
+    let err = common::client_for(&server).get_misc_const().await.unwrap_err();
+    assert!(matches!(
+        err,
+        CexplorerError::RateLimited { retry_after: Some(delay) } if delay == Duration::from_secs(7)
+    ));
+}
+
+#[tokio::test]
+async fn drifted_schema_is_deserialize_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/pool/detail"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "code": 200,
+            "data": { "pool": "renamed" },
+            "tokens": 1,
+            "ex": 0.01,
+            "debug": false,
+        })))
+        .mount(&server)
+        .await;
+
+    let err = common::client_for(&server)
+        .get_pool_detail(PoolDetailRequest::new().pool_id(common::pool_id()))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CexplorerError::Deserialize { ref endpoint, .. } if endpoint == "/pool/detail"));
+}
+
+#[tokio::test]
+async fn cached_responses_skip_the_server() {
+    let server = common::mock_server().await;
+    let cache = Arc::new(ResponseCache::in_memory(16));
+    let client = common::client_for(&server).with_cache(cache.clone());
+
+    client.get_misc_const().await.unwrap();
+    client.get_misc_const().await.unwrap();
+
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    assert_eq!(cache.stats().hits, 1);
+}
+
+#[tokio::test]
+async fn paginator_walks_fixture_pages() {
+    let server = MockServer::start().await;
+    let fixture: Value = serde_json::from_str(&common::load("pool_list")).unwrap();
+    let pools = fixture["data"]["data"].as_array().unwrap().clone();
+
+    for (offset, items) in [("0", pools.clone()), ("2", pools[..1].to_vec())] {
+        let mut page = fixture.clone();
+        page["data"]["count"] = json!(3);
+        page["data"]["data"] = Value::Array(items);
+        Mock::given(method("GET"))
+            .and(path("/pool/list"))
+            .and(query_param("offset", offset))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let walked: Vec<PoolData> = common::client_for(&server)
+        .paginate(|client, page| async move {
+            client
+                .get_pools_list(PoolListRequest::new().limit(page.limit).offset(page.offset))
+                .await
+        })
+        .page_size(2)
+        .into_stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(walked.len(), 3);
+}
+
+#[tokio::test]
+async fn per_call_timeout_is_timeout_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .mount(&server)
+        .await;
+
+    let err = common::client_for(&server)
+        .with_timeout(Duration::from_millis(100))
+        .get_epoch_analytics()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CexplorerError::Timeout));
+}
+
+#[tokio::test]
+async fn cancellation_aborts_in_flight_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .mount(&server)
+        .await;
+
+    let token = CancellationToken::new();
+    let client = common::client_for(&server).with_cancellation(token.clone());
+
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        token.cancel();
+    });
+
+    let err = client.get_misc_const().await.unwrap_err();
+    assert!(matches!(err, CexplorerError::Cancelled));
+    canceller.await.unwrap();
+}
+
+#[tokio::test]
+async fn every_module_replays() {
+    let server = common::mock_server().await;
+    let client = common::client_for(&server);
+    let stake: StakeAddress = common::STAKE_ADDRESS.parse().unwrap();
+
+    let rewards = client
+        .get_account_rewards(AccountRewardsRequest::new(stake.clone()).limit(2).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(rewards.data.data.len(), 2);
+    let address = client.get_address_detail(&common::ADDRESS.parse().unwrap()).await.unwrap();
+    assert_eq!(address.data.data[0].address, common::ADDRESS);
+    client.get_hardforks().await.unwrap();
+    let articles = client.get_article_list(ArticleListRequest::new("en", 2, 0)).await.unwrap();
+    assert_eq!(articles.data.data.len(), 2);
+    let datum = client.get_datum_detail(&common::DATUM_HASH.parse().unwrap()).await.unwrap();
+    assert_eq!(datum.data.hash, common::DATUM_HASH);
+    client.get_defi_token_stat().await.unwrap();
+    let state = client.get_delegations_state(&stake).await.unwrap();
+    assert_eq!(state.data.data[0].view, common::STAKE_ADDRESS);
+    client
+        .get_metadata_tx_list(MetadataTxListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    let policy = client.get_policy_detail(&common::POLICY_ID.parse().unwrap()).await.unwrap();
+    assert_eq!(policy.data.id, common::POLICY_ID);
+    client
+        .get_script_list(ScriptListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    let detail = client.get_stake_detail(&stake).await.unwrap();
+    assert_eq!(detail.data.view, common::STAKE_ADDRESS);
+    let sent = client
+        .send_tx_sent(&common::tx_hash(), common::POOL_ID, TxSentType::Delegation)
+        .await
+        .unwrap();
+    assert_eq!(sent.data.state, "ok");
+    client.get_treasury_donation_stats().await.unwrap();
+    let wallets = client.compare_wallets().await.unwrap();
+    assert_eq!(wallets.data.data[0][0].internal_name, "eternl");
+}
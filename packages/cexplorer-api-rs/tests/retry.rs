@@ -0,0 +1,98 @@
+//! Retries against a mock server that fails before it succeeds.
+
+mod common;
+
+use cexplorer_api_rs::prelude::*;
+use cexplorer_api_rs::Method;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use wiremock::matchers::path;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Server that answers `/misc/const` with `failure` `times` times, then the fixture
+async fn flaky_server(failure: ResponseTemplate, times: u64) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(path("/misc/const"))
+        .respond_with(failure)
+        .up_to_n_times(times)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/misc/const"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(common::load("misc_const")))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn client_with(server: &MockServer, retry: RetryPolicy) -> CexplorerClient {
+    common::client_for(server).with_retry_policy(retry.with_jitter(false))
+}
+
+#[tokio::test]
+async fn too_many_requests_waits_for_retry_after() {
+    let server = flaky_server(ResponseTemplate::new(429).insert_header("Retry-After", "1"), 1).await;
+    let retry = RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_secs(5));
+
+    let started = Instant::now();
+    client_with(&server, retry).get_misc_const().await.unwrap();
+
+    assert!(started.elapsed() >= Duration::from_secs(1), "{:?}", started.elapsed());
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_at_the_maximum_backoff() {
+    let server = flaky_server(ResponseTemplate::new(429).insert_header("Retry-After", "3600"), 2).await;
+    let retry = RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(20));
+
+    let started = Instant::now();
+    client_with(&server, retry).get_misc_const().await.unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(5), "{:?}", started.elapsed());
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn unavailable_is_retried_until_it_succeeds() {
+    let server = flaky_server(ResponseTemplate::new(503), 1).await;
+    let retry = RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(1));
+
+    let response = client_with(&server, retry).get_misc_const().await.unwrap();
+    assert_eq!(response.code, 200);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn attempts_stop_at_the_policy_maximum() {
+    let server = flaky_server(ResponseTemplate::new(503), 5).await;
+    let retry = RetryPolicy::default()
+        .with_max_attempts(2)
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(1));
+
+    let err = client_with(&server, retry).get_misc_const().await.unwrap_err();
+    assert_eq!(err.status(), Some(503));
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+/// A request sent with POST, which is not safe to repeat
+#[derive(Serialize)]
+struct SubmitRequest;
+
+impl Endpoint for SubmitRequest {
+    type Response = serde_json::Value;
+    const PATH: &'static str = "/misc/const";
+    const METHOD: Method = Method::POST;
+}
+
+#[tokio::test]
+async fn post_requests_are_not_retried() {
+    let server = flaky_server(ResponseTemplate::new(503), 1).await;
+    let retry = RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(1));
+
+    client_with(&server, retry).call(SubmitRequest).await.unwrap_err();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, wiremock::http::Method::POST);
+}
@@ -0,0 +1,86 @@
+use cexplorer_api_rs::*;
+
+#[test]
+fn slots_and_epochs_follow_the_shelley_genesis() {
+    let mainnet = Network::MainnetStage.slot_config().unwrap();
+    assert_eq!(mainnet, SlotConfig::MAINNET);
+
+    // Epoch 208 is the first Shelley epoch, started 2020-07-29T21:44:51Z
+    assert_eq!(mainnet.epoch_start_unix(208), Some(1_596_059_091));
+    assert_eq!(mainnet.unix_to_slot(1_596_059_091 + 432_000), Some(4_924_800));
+    assert_eq!(mainnet.slot_to_epoch(4_924_800), Some(209));
+    assert_eq!(mainnet.epoch_slot(4_924_801), Some(1));
+
+    // Byron slots and custom networks have no Shelley timing
+    assert_eq!(mainnet.slot_to_unix(4_492_799), None);
+    assert_eq!(mainnet.epoch_start_slot(207), None);
+    assert!(Network::Custom("http://localhost".into()).slot_config().is_none());
+
+    let preprod = Network::Preprod.slot_config().unwrap();
+    assert_eq!(preprod.slot_to_epoch(preprod.epoch_start_slot(100).unwrap()), Some(100));
+    let preview = Network::Preview.slot_config().unwrap();
+    assert_eq!(preview.unix_to_epoch(1_666_656_000 + 86_400), Some(1));
+}
+
+#[test]
+fn timestamps_keep_the_text_the_api_sent() {
+    let block: types::tx_types::BlockBasicInfo = serde_json::from_value(serde_json::json!({
+        "no": 1,
+        "hash": "h",
+        "time": "2024-02-23T08:12:09",
+        "epoch_no": 469,
+    }))
+    .unwrap();
+    assert_eq!(block.time.as_str(), "2024-02-23T08:12:09");
+    assert_eq!(block.time.unix(), Some(1_708_675_929));
+    assert_eq!(serde_json::to_value(&block).unwrap()["time"], "2024-02-23T08:12:09");
+
+    // Text that is not a timestamp still decodes, it just has no time
+    assert_eq!(Timestamp::from("soon").unix(), None);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn timestamps_convert_to_utc() {
+    use chrono::{TimeZone, Utc};
+
+    let block: types::tx_types::BlockBasicInfo = serde_json::from_value(serde_json::json!({
+        "no": 1,
+        "hash": "h",
+        "time": "2024-02-23T08:12:09",
+        "epoch_no": 469,
+    }))
+    .unwrap();
+    assert_eq!(block.time.to_datetime(), Some(Utc.with_ymd_and_hms(2024, 2, 23, 8, 12, 9).unwrap()));
+
+    // Daily statistics and announcements carry bare dates
+    let exchange: types::analytics_types::HardforkExchange = serde_json::from_value(serde_json::json!({
+        "name": "Binance",
+        "updateOn": "2025-01-27",
+        "liquidityPercentage": 30.5,
+        "status": "ready",
+        "logo": "binance.png",
+    }))
+    .unwrap();
+    assert_eq!(exchange.update_on.to_datetime(), Some(Utc.with_ymd_and_hms(2025, 1, 27, 0, 0, 0).unwrap()));
+
+    let poll: types::misc_types::Poll = serde_json::from_value(serde_json::json!({
+        "name": "poll",
+        "url": "poll",
+        "date_start": "2025-01-20 09:00:00",
+        "date_end": "2025-02-20T09:00:00.500Z",
+        "description": "",
+        "options": [],
+        "state": "open",
+    }))
+    .unwrap();
+    let date_start = poll.date_start.to_datetime().unwrap();
+    assert_eq!(date_start, Utc.with_ymd_and_hms(2025, 1, 20, 9, 0, 0).unwrap());
+    assert!(poll.date_end.to_datetime().unwrap() > date_start);
+    assert_eq!(Timestamp::from("soon").to_datetime(), None);
+
+    let mainnet = SlotConfig::MAINNET;
+    let start = mainnet.epoch_start(500).unwrap();
+    assert_eq!(start, Utc.with_ymd_and_hms(2024, 7, 28, 21, 44, 51).unwrap());
+    assert_eq!(mainnet.time_to_epoch(&start), Some(500));
+}
@@ -0,0 +1,116 @@
+//! Runs the client over an in-memory transport instead of HTTP.
+
+mod common;
+
+use async_trait::async_trait;
+use cexplorer_api_rs::prelude::*;
+use cexplorer_api_rs::{HttpRequest, HttpResponse, StatusCode};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Answers each request with the fixture for its path
+#[derive(Debug, Default)]
+struct FixtureTransport {
+    requests: Mutex<Vec<HttpRequest>>,
+    /// Number of leading requests to fail with a network error
+    fail_first: AtomicU32,
+}
+
+#[async_trait]
+impl Transport for FixtureTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, CexplorerError> {
+        self.requests.lock().unwrap().push(request.clone());
+
+        if self
+            .fail_first
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            return Err(CexplorerError::NetworkError("connection reset".to_string()));
+        }
+
+        let path = request.url.split('?').next().unwrap();
+        let response = match common::FIXTURES.iter().find(|f| path.ends_with(f.path)) {
+            Some(fixture) => HttpResponse::new(StatusCode::OK, common::load(fixture.name)),
+            None => HttpResponse::new(StatusCode::NOT_FOUND, ""),
+        };
+        Ok(response)
+    }
+}
+
+fn client_with(transport: Arc<FixtureTransport>) -> CexplorerClient {
+    let config = CexplorerConfig::new("mainnet", "test-key").unwrap();
+    CexplorerClient::new(config).with_transport(transport)
+}
+
+#[tokio::test]
+async fn requests_go_through_the_transport() {
+    let transport = Arc::new(FixtureTransport::default());
+    let client = client_with(transport.clone());
+
+    let pools = client
+        .get_pools_list(PoolListRequest::new().limit(2).offset(0))
+        .await
+        .unwrap();
+    assert_eq!(pools.data.data.len(), 2);
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].url.ends_with("/pool/list?limit=2&offset=0"));
+    assert_eq!(requests[0].headers.get("api-key").unwrap(), "test-key");
+}
+
+#[tokio::test]
+async fn params_extend_an_inline_query() {
+    let transport = Arc::new(FixtureTransport::default());
+    let client = client_with(transport.clone());
+
+    client.get_block_detail(&common::block_hash()).await.unwrap();
+
+    let requests = transport.requests.lock().unwrap();
+    assert!(requests[0].url.ends_with(&format!("/block/detail?hash={}", common::BLOCK_HASH)));
+}
+
+#[tokio::test]
+async fn transport_errors_are_retried() {
+    let transport = Arc::new(FixtureTransport {
+        fail_first: AtomicU32::new(2),
+        ..Default::default()
+    });
+    let retry = RetryPolicy::default()
+        .with_max_attempts(3)
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(1));
+    let client = client_with(transport.clone()).with_retry_policy(retry);
+
+    client.get_misc_const().await.unwrap();
+    assert_eq!(transport.requests.lock().unwrap().len(), 3);
+}
+
+/// A request to a path no fixture answers
+#[derive(Serialize)]
+struct UnknownRequest;
+
+impl Endpoint for UnknownRequest {
+    type Response = serde_json::Value;
+    const PATH: &'static str = "/misc/unknown";
+}
+
+#[tokio::test]
+async fn status_errors_are_mapped_from_transport_responses() {
+    let client = client_with(Arc::new(FixtureTransport::default()));
+
+    let err = client.call(UnknownRequest).await.unwrap_err();
+    assert!(matches!(err, CexplorerError::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn config_transport_is_used_by_new_clients() {
+    let config = CexplorerConfig::new("mainnet", "test-key")
+        .unwrap()
+        .with_transport(FixtureTransport::default());
+    let client = CexplorerClient::new(config);
+
+    client.get_misc_health().await.unwrap();
+}
@@ -0,0 +1,88 @@
+//! `FetchTransport` and the JavaScript bindings, run with
+//! `wasm-pack test --node` or `cargo test --target wasm32-unknown-unknown --test wasm`
+//! under `wasm-bindgen-test-runner`.
+//!
+//! Responses come from `data:` URLs, which `fetch` resolves without a server.
+
+#![cfg(target_arch = "wasm32")]
+
+use async_trait::async_trait;
+use cexplorer_api_rs::header::HeaderMap;
+use cexplorer_api_rs::wasm::WasmClient;
+use cexplorer_api_rs::{
+    CexplorerClient, CexplorerConfig, CexplorerError, FetchTransport, HttpRequest, HttpResponse,
+    Method, StatusCode, Transport,
+};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn data_url(body: &str) -> String {
+    format!("data:application/json,{}", js_sys::encode_uri_component(body))
+}
+
+fn get(url: String) -> HttpRequest {
+    HttpRequest {
+        method: Method::GET,
+        url,
+        headers: HeaderMap::new(),
+        timeout: None,
+    }
+}
+
+/// Sends every request through `fetch` to a `data:` URL holding `body`
+#[derive(Debug)]
+struct DataTransport {
+    body: &'static str,
+}
+
+#[async_trait(?Send)]
+impl Transport for DataTransport {
+    async fn send(&self, mut request: HttpRequest) -> Result<HttpResponse, CexplorerError> {
+        request.url = data_url(self.body);
+        FetchTransport::new().send(request).await
+    }
+}
+
+#[wasm_bindgen_test]
+async fn fetch_transport_returns_status_headers_and_body() {
+    let response = FetchTransport::new()
+        .send(get(data_url(r#"{"ok":true}"#)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.headers["content-type"], "application/json");
+    assert_eq!(response.text(), r#"{"ok":true}"#);
+}
+
+#[wasm_bindgen_test]
+async fn fetch_transport_reports_unreachable_servers_as_network_errors() {
+    let error = FetchTransport::new()
+        .send(get("http://127.0.0.1:1/misc/health".to_string()))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CexplorerError::NetworkError(_)), "{:?}", error);
+}
+
+#[wasm_bindgen_test]
+async fn client_decodes_responses_fetched_in_the_browser() {
+    let config = CexplorerConfig::new("mainnet", "test-key")
+        .unwrap()
+        .with_transport(DataTransport {
+            body: include_str!("fixtures/misc_health.json"),
+        });
+    let client = CexplorerClient::new(config);
+
+    let health = client.get_misc_health().await.unwrap();
+    assert!(health.data.is_healthy);
+}
+
+#[wasm_bindgen_test]
+async fn wasm_client_rejects_invalid_arguments() {
+    assert!(WasmClient::new("moonnet", "test-key").is_err());
+
+    let client = WasmClient::new("mainnet", "test-key").unwrap();
+    let rejected = JsFuture::from(client.get_tx_detail("not-a-hash".to_string())).await;
+    assert!(rejected.is_err());
+}